@@ -48,6 +48,14 @@ pub struct AppConfig {
     )]
     pub storage_read_past_height_limit: u32,
 
+    #[clap(
+        long,
+        env,
+        help = "Path to a JSON token registry with the symbol, decimals, name \
+                and logo of the tokens"
+    )]
+    pub token_registry_path: Option<String>,
+
     #[clap(flatten)]
     pub log: LogConfig,
 
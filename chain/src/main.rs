@@ -11,6 +11,7 @@ use chain::services::namada::{
     query_bonds, query_checksums, query_last_block_height, query_redelegations,
     query_tokens,
 };
+use chain::services::utils::load_token_registry;
 use chain::services::{
     db as db_service, namada as namada_service,
     tendermint as tendermint_service,
@@ -24,7 +25,7 @@ use namada_sdk::time::DateTimeUtc;
 use orm::migrations::CustomMigrationSource;
 use orm::schema::{bonds, unbonds};
 use repository::pgf as namada_pgf_repository;
-use shared::balance::TokenSupply;
+use shared::balance::{Amount, TokenSupply};
 use shared::block::Block;
 use shared::block_result::BlockResult;
 use shared::checksums::Checksums;
@@ -35,6 +36,7 @@ use shared::error::{
 };
 use shared::futures::AwaitContainer;
 use shared::id::Id;
+use shared::pgf::{PGF_PAYMENTS_EVENT_DESCRIPTOR, PgfDisbursement};
 use shared::token::Token;
 use shared::utils::BalanceChange;
 use shared::validator::ValidatorSet;
@@ -80,6 +82,31 @@ async fn main() -> Result<(), MainError> {
         .await
         .expect("Should be able to run migrations");
 
+    if let Some(path) = config.token_registry_path.as_deref() {
+        let registry = load_token_registry(path)
+            .expect("Should be able to load the token registry");
+
+        tracing::info!(
+            "Loaded {} tokens from the token registry",
+            registry.len()
+        );
+
+        conn.interact(move |conn| {
+            conn.build_transaction()
+                .read_write()
+                .run(|transaction_conn| {
+                    repository::balance::replace_token_metadata(
+                        transaction_conn,
+                        registry,
+                    )
+                })
+        })
+        .await
+        .context_db_interact_error()
+        .and_then(identity)
+        .into_db_error()?;
+    }
+
     rlimit::increase_nofile_limit(10240).unwrap();
     rlimit::increase_nofile_limit(u64::MAX).unwrap();
 
@@ -282,7 +309,7 @@ async fn crawling_fn(
     let native_token_address: namada_sdk::address::Address =
         native_token.clone().into();
 
-    let (block, tm_block_response, epoch) =
+    let (block, tm_block_response, block_results, epoch) =
         get_block(block_height, &client, &checksums, &native_token_address)
             .await?;
 
@@ -320,14 +347,23 @@ async fn crawling_fn(
             native_token.clone(),
         ));
     let addresses = block.addresses_with_balance_change(&native_token);
-    let all_changed_tokens_supply = addresses
+    let mut all_changed_tokens_supply = addresses
         .iter()
         .map(|bc| bc.token.clone())
         .collect::<HashSet<_>>();
+    // The first block of an epoch writes the supplies of the epoch, so the
+    // native token is always included to record its bonded supply
+    if new_epoch {
+        all_changed_tokens_supply.insert(Token::Native(native_token.clone()));
+    }
 
-    let token_supplies =
-        query_token_supplies(&client, &all_changed_tokens_supply, epoch)
-            .await?;
+    let token_supplies = query_token_supplies(
+        &client,
+        &all_changed_tokens_supply,
+        epoch,
+        new_epoch,
+    )
+    .await?;
 
     let validators_addresses = if first_block_in_epoch.eq(&block_height) {
         let previous_epoch = epoch.saturating_sub(1);
@@ -352,6 +388,7 @@ async fn crawling_fn(
         });
 
     let pgf_receipient_addresses = if first_block_in_epoch.eq(&block_height) {
+        let native_token = native_token.clone();
         conn.interact(move |conn| {
             namada_pgf_repository::get_pgf_receipients_balance_changes(
                 conn,
@@ -390,6 +427,46 @@ async fn crawling_fn(
         all_balance_changed_addresses.len()
     );
 
+    // Pgf payments are executed in the first block of the epoch
+    let pgf_disbursements =
+        if first_block_in_epoch.eq(&block_height) && block_height > 1 {
+            let continuous_payments = conn
+                .interact(|conn| {
+                    namada_pgf_repository::get_pgf_continuous_payments(conn)
+                })
+                .await
+                .context_db_interact_error()
+                .and_then(identity)
+                .into_db_error()?;
+
+            let pgf_address = Id::from(namada_sdk::address::PGF);
+            let treasury_balance = balances
+                .iter()
+                .find(|balance| {
+                    balance.owner == pgf_address
+                        && balance.token == Token::Native(native_token.clone())
+                })
+                .map(|balance| balance.amount.clone())
+                .unwrap_or_else(Amount::zero);
+
+            PgfDisbursement::from_payments(
+                &block_results.token_transfers(PGF_PAYMENTS_EVENT_DESCRIPTOR),
+                &continuous_payments,
+                &pgf_address,
+                &treasury_balance,
+                &native_token,
+                epoch,
+                block_height,
+            )
+        } else {
+            vec![]
+        };
+    tracing::debug!(
+        block = block_height,
+        "Creating {} pgf disbursements",
+        pgf_disbursements.len()
+    );
+
     let next_governance_proposal_id =
         namada_service::query_next_governance_id(&client, block_height)
             .await
@@ -414,6 +491,8 @@ async fn crawling_fn(
         proposals_votes.len()
     );
 
+    let vote_casts = block.governance_vote_casts();
+
     let validators = block.new_validators();
     let validator_set = ValidatorSet {
         validators: validators.clone(),
@@ -471,6 +550,20 @@ async fn crawling_fn(
 
     let metadata_change = block.validator_metadata();
 
+    let validator_events = block.validator_events();
+    tracing::debug!(
+        block = block_height,
+        "Creating {} validator events",
+        validator_events.len()
+    );
+
+    let pgf_steward_events = block.pgf_steward_events();
+    tracing::debug!(
+        block = block_height,
+        "Creating {} pgf steward events",
+        pgf_steward_events.len()
+    );
+
     let reward_claimers = block.pos_rewards();
 
     let timestamp_in_sec = DateTimeUtc::now().0.timestamp();
@@ -545,6 +638,15 @@ async fn crawling_fn(
                     balances,
                 )?;
 
+                // Snapshot the number of holders of each token at the start
+                // of every epoch
+                if new_epoch {
+                    repository::balance::insert_token_holders(
+                        transaction_conn,
+                        epoch,
+                    )?;
+                }
+
                 repository::gov::insert_proposals(
                     transaction_conn,
                     proposals_with_tally,
@@ -554,6 +656,11 @@ async fn crawling_fn(
                     proposals_votes,
                 )?;
 
+                repository::gov::insert_vote_history(
+                    transaction_conn,
+                    vote_casts,
+                )?;
+
                 repository::pos::upsert_validators(
                     transaction_conn,
                     validator_set,
@@ -592,6 +699,21 @@ async fn crawling_fn(
                     metadata_change,
                 )?;
 
+                repository::pos::insert_validator_events(
+                    transaction_conn,
+                    validator_events,
+                )?;
+
+                namada_pgf_repository::insert_pgf_steward_events(
+                    transaction_conn,
+                    pgf_steward_events,
+                )?;
+
+                namada_pgf_repository::insert_pgf_disbursements(
+                    transaction_conn,
+                    pgf_disbursements,
+                )?;
+
                 repository::revealed_pk::insert_revealed_pks(
                     transaction_conn,
                     revealed_pks,
@@ -659,7 +781,7 @@ async fn try_initial_query(
             .await
             .into_rpc_error()?
             .into();
-    let (block, tm_block_response, epoch) =
+    let (block, tm_block_response, _block_results, epoch) =
         get_block(block_height, client, &checksums, &native_token).await?;
 
     let tokens = query_tokens(client).await.into_rpc_error()?;
@@ -678,6 +800,7 @@ async fn try_initial_query(
             client,
             &tokens.iter().cloned().collect::<HashSet<_>>(),
             epoch,
+            true,
         )
         .await
     };
@@ -779,6 +902,11 @@ async fn try_initial_query(
                     balances,
                 )?;
 
+                repository::balance::insert_token_holders(
+                    transaction_conn,
+                    epoch,
+                )?;
+
                 repository::gov::insert_proposals(
                     transaction_conn,
                     proposals_with_tally,
@@ -855,7 +983,7 @@ async fn get_block(
     client: &HttpClient,
     checksums: &Checksums,
     native_token: &namada_sdk::address::Address,
-) -> Result<(Block, TendermintBlockResponse, u32), MainError> {
+) -> Result<(Block, TendermintBlockResponse, BlockResult, u32), MainError> {
     tracing::debug!(block = block_height, "Query block...");
     let tm_block_response =
         tendermint_service::query_raw_block_at_height(client, block_height)
@@ -906,13 +1034,14 @@ async fn get_block(
         native_token,
     );
 
-    Ok((block, tm_block_response, epoch))
+    Ok((block, tm_block_response, block_results, epoch))
 }
 
 async fn query_token_supplies(
     client: &HttpClient,
     tokens: &HashSet<Token>,
     epoch: u32,
+    with_bonded: bool,
 ) -> Result<Vec<TokenSupply>, MainError> {
     let mut buffer = Vec::with_capacity(tokens.len());
 
@@ -928,7 +1057,10 @@ async fn query_token_supplies(
                 .into_rpc_error(),
                 Token::Native(address) => {
                     namada_service::get_native_token_supply(
-                        client, address, epoch,
+                        client,
+                        address,
+                        epoch,
+                        with_bonded,
                     )
                     .await
                     .into_rpc_error()
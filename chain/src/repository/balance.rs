@@ -1,15 +1,20 @@
 use anyhow::Context;
-use diesel::{PgConnection, RunQueryDsl};
+use bigdecimal::{BigDecimal, Zero};
+use diesel::dsl::count_star;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
 use orm::balances::BalanceChangesInsertDb;
 use orm::ibc::IbcRateLimitsInsertDb;
 use orm::schema::{
     balance_changes, ibc_rate_limits, ibc_token, token,
-    token_supplies_per_epoch,
+    token_holders_per_epoch, token_metadata, token_supplies_per_epoch,
 };
-use orm::token::{IbcTokenInsertDb, TokenInsertDb};
+use orm::token::{IbcTokenInsertDb, TokenInsertDb, TokenMetadataInsertDb};
+use orm::token_holders_per_epoch::TokenHoldersInsertDb;
 use orm::token_supplies_per_epoch::TokenSuppliesInsertDb;
+use orm::views::balances;
 use shared::balance::{Balances, TokenSupply};
-use shared::token::{IbcRateLimit, Token};
+use shared::block::Epoch;
+use shared::token::{IbcRateLimit, Token, TokenMetadata};
 use shared::tuple_len::TupleLen;
 
 use super::utils::MAX_PARAM_SIZE;
@@ -75,6 +80,29 @@ pub fn insert_tokens(
     anyhow::Ok(())
 }
 
+pub fn replace_token_metadata(
+    transaction_conn: &mut PgConnection,
+    metadata: Vec<TokenMetadata>,
+) -> anyhow::Result<()> {
+    // The registry file is the source of truth, so tokens removed from it
+    // fall back to their default metadata
+    diesel::delete(token_metadata::table)
+        .execute(transaction_conn)
+        .context("Failed to delete token metadata from db")?;
+
+    diesel::insert_into(token_metadata::table)
+        .values(
+            metadata
+                .into_iter()
+                .map(TokenMetadataInsertDb::from)
+                .collect::<Vec<_>>(),
+        )
+        .execute(transaction_conn)
+        .context("Failed to insert token metadata in db")?;
+
+    anyhow::Ok(())
+}
+
 pub fn insert_token_supplies<S>(
     transaction_conn: &mut PgConnection,
     supplies: S,
@@ -102,6 +130,35 @@ where
     anyhow::Ok(())
 }
 
+/// Counts the holders of every token from the latest balances. This scans
+/// all balances, so it is only meant to run once per epoch.
+pub fn insert_token_holders(
+    transaction_conn: &mut PgConnection,
+    epoch: Epoch,
+) -> anyhow::Result<()> {
+    let holders = balances::table
+        .filter(balances::dsl::raw_amount.gt(BigDecimal::zero()))
+        .group_by(balances::dsl::token)
+        .select((balances::dsl::token, count_star()))
+        .load::<(String, i64)>(transaction_conn)
+        .context("Failed to count token holders in db")?
+        .into_iter()
+        .map(|(address, holders)| TokenHoldersInsertDb {
+            address,
+            epoch: epoch as i32,
+            holders: holders as i32,
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(token_holders_per_epoch::table)
+        .values(holders)
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert token holders in db")?;
+
+    anyhow::Ok(())
+}
+
 pub fn insert_ibc_rate_limits<S>(
     transaction_conn: &mut PgConnection,
     supplies: S,
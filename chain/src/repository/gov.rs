@@ -4,11 +4,15 @@ use anyhow::Context;
 use diesel::upsert::excluded;
 use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
 use orm::governance_proposal::GovernanceProposalInsertDb;
-use orm::governance_votes::GovernanceProposalVoteInsertDb;
-use orm::schema::{governance_proposals, governance_votes};
+use orm::governance_votes::{
+    GovernanceProposalVoteInsertDb, GovernanceVoteHistoryInsertDb,
+};
+use orm::schema::{
+    governance_proposals, governance_vote_history, governance_votes,
+};
 use shared::proposal::{GovernanceProposal, TallyType};
 use shared::tuple_len::TupleLen;
-use shared::vote::GovernanceVote;
+use shared::vote::{GovernanceVote, GovernanceVoteCast};
 
 use super::utils::MAX_PARAM_SIZE;
 
@@ -86,3 +90,22 @@ fn insert_votes_chunk(
 
     anyhow::Ok(())
 }
+
+pub fn insert_vote_history(
+    transaction_conn: &mut PgConnection,
+    vote_casts: Vec<GovernanceVoteCast>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(governance_vote_history::table)
+        .values::<&Vec<GovernanceVoteHistoryInsertDb>>(
+            &vote_casts
+                .into_iter()
+                .map(GovernanceVoteHistoryInsertDb::from)
+                .collect::<Vec<_>>(),
+        )
+        // If the block was already processed, do nothing
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert governance vote history in db")?;
+
+    anyhow::Ok(())
+}
@@ -1,10 +1,17 @@
 use std::collections::HashSet;
 
 use anyhow::Context;
-use diesel::{PgConnection, QueryDsl, RunQueryDsl, SelectableHelper};
-use orm::pgf::PublicGoodFundingPaymentDb;
-use orm::schema::public_good_funding;
+use diesel::{
+    ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+};
+use orm::pgf::{
+    PaymentKindDb, PaymentRecurrenceDb, PgfDisbursementInsertDb,
+    PgfStewardEventInsertDb, PublicGoodFundingPaymentDb,
+};
+use orm::schema::{pgf_disbursements, pgf_steward_events, public_good_funding};
+use shared::balance::Amount;
 use shared::id::Id;
+use shared::pgf::{PgfDisbursement, PgfStewardEvent};
 use shared::token::Token;
 use shared::utils::BalanceChange;
 
@@ -25,3 +32,70 @@ pub fn get_pgf_receipients_balance_changes(
         })
         .context("Failed to update governance votes in db")
 }
+
+pub fn insert_pgf_steward_events(
+    transaction_conn: &mut PgConnection,
+    steward_events: Vec<PgfStewardEvent>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(pgf_steward_events::table)
+        .values::<Vec<PgfStewardEventInsertDb>>(
+            steward_events
+                .into_iter()
+                .map(PgfStewardEventInsertDb::from)
+                .collect(),
+        )
+        // If the block was already processed, do nothing
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert pgf steward events in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Returns the native continuous payments ordered by proposal id, the order
+/// in which the protocol executes them.
+pub fn get_pgf_continuous_payments(
+    transaction_conn: &mut PgConnection,
+) -> anyhow::Result<Vec<(Id, Amount)>> {
+    let payments = public_good_funding::table
+        .filter(
+            public_good_funding::dsl::payment_kind.eq(PaymentKindDb::Native),
+        )
+        .filter(
+            public_good_funding::dsl::payment_recurrence
+                .eq(PaymentRecurrenceDb::Continuous),
+        )
+        .order(public_good_funding::dsl::proposal_id.asc())
+        .select(PublicGoodFundingPaymentDb::as_select())
+        .load(transaction_conn)
+        .context("Failed to query pgf continuous payments from db")?;
+
+    Ok(payments
+        .into_iter()
+        .map(|payment| {
+            (
+                Id::Account(payment.receipient),
+                Amount::from(payment.amount),
+            )
+        })
+        .collect())
+}
+
+pub fn insert_pgf_disbursements(
+    transaction_conn: &mut PgConnection,
+    disbursements: Vec<PgfDisbursement>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(pgf_disbursements::table)
+        .values::<Vec<PgfDisbursementInsertDb>>(
+            disbursements
+                .into_iter()
+                .map(PgfDisbursementInsertDb::from)
+                .collect(),
+        )
+        // If the epoch was already processed, do nothing
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert pgf disbursements in db")?;
+
+    anyhow::Ok(())
+}
@@ -8,8 +8,11 @@ use diesel::{
 };
 use orm::bond::BondInsertDb;
 use orm::redelegation::RedelegationInsertDb;
-use orm::schema::{bonds, pos_rewards, redelegation, unbonds, validators};
+use orm::schema::{
+    bonds, pos_rewards, redelegation, unbonds, validator_events, validators,
+};
 use orm::unbond::UnbondInsertDb;
+use orm::validator_events::ValidatorEventInsertDb;
 use orm::validators::{
     ValidatorDb, ValidatorStateDb, ValidatorUpdateMetadataDb,
     ValidatorWithMetaInsertDb,
@@ -19,7 +22,7 @@ use shared::id::Id;
 use shared::pos::{Bonds, Redelegations, UnbondAddresses, Unbonds};
 use shared::tuple_len::TupleLen;
 use shared::validator::{
-    ValidatorMetadataChange, ValidatorSet, ValidatorStateChange,
+    ValidatorEvent, ValidatorMetadataChange, ValidatorSet, ValidatorStateChange,
 };
 
 use super::utils::MAX_PARAM_SIZE;
@@ -317,6 +320,25 @@ pub fn upsert_validator_state(
     Ok(())
 }
 
+pub fn insert_validator_events(
+    transaction_conn: &mut PgConnection,
+    events: Vec<ValidatorEvent>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(validator_events::table)
+        .values::<&Vec<ValidatorEventInsertDb>>(
+            &events
+                .into_iter()
+                .map(ValidatorEventInsertDb::from)
+                .collect::<Vec<_>>(),
+        )
+        // If the block was already processed, do nothing
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert validator events in db")?;
+
+    anyhow::Ok(())
+}
+
 pub fn upsert_validators(
     transaction_conn: &mut PgConnection,
     validators_set: ValidatorSet,
@@ -358,13 +380,16 @@ pub fn upsert_validators(
 
 #[cfg(test)]
 mod tests {
+    use orm::blocks::BlockInsertDb;
     use orm::bond::BondDb;
     use orm::redelegation::RedelegationDb;
+    use orm::schema::blocks;
     use orm::unbond::UnbondDb;
+    use orm::validator_events::ValidatorEventDb;
     use orm::validators::ValidatorInsertDb;
     use shared::balance::Amount;
     use shared::pos::{Bond, Redelegation, Unbond};
-    use shared::validator::Validator;
+    use shared::validator::{Validator, ValidatorEventKind};
     use test_helpers::db::TestDb;
 
     use super::*;
@@ -883,6 +908,57 @@ mod tests {
         .expect("Failed to run test");
     }
 
+    /// Test that inserting the same validator events twice does not duplicate
+    /// them.
+    #[tokio::test]
+    async fn test_insert_validator_events_is_idempotent() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let validator = Validator::fake();
+            diesel::insert_into(blocks::table)
+                .values::<&BlockInsertDb>(&BlockInsertDb::fake(1))
+                .execute(conn)
+                .context("Failed to insert block in db")?;
+
+            let events = vec![
+                ValidatorEvent {
+                    address: validator.address.clone(),
+                    kind: ValidatorEventKind::BecomeValidator,
+                    block_height: 1,
+                    inner_tx_id: Id::Hash("a".repeat(64)),
+                    data: None,
+                },
+                ValidatorEvent {
+                    address: validator.address.clone(),
+                    kind: ValidatorEventKind::CommissionChange,
+                    block_height: 1,
+                    inner_tx_id: Id::Hash("b".repeat(64)),
+                    data: None,
+                },
+            ];
+
+            insert_validator_events(conn, events.clone())?;
+            insert_validator_events(conn, events)?;
+
+            let queried_events = validator_events::table
+                .select(ValidatorEventDb::as_select())
+                .load::<ValidatorEventDb>(conn)
+                .expect("Failed to query validator events");
+
+            assert_eq!(queried_events.len(), 2);
+            assert!(
+                queried_events.iter().all(|event| event.address
+                    == validator.address.to_string()
+                    && event.block_height == 1)
+            );
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
     fn seed_bonds(
         conn: &mut PgConnection,
         validator: Validator,
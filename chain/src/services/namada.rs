@@ -96,6 +96,20 @@ pub async fn query_native_token_effective_supply(
     default_retry(operation).await
 }
 
+pub async fn query_native_token_bonded_supply(
+    client: &HttpClient,
+    epoch: u32,
+) -> anyhow::Result<Amount> {
+    let operation = || async {
+        rpc::get_total_staked_tokens(client, NamadaSdkEpoch::from(epoch as u64))
+            .await
+            .map(Amount::from)
+            .context("Failed to query total bonded amount of native token")
+    };
+
+    default_retry(operation).await
+}
+
 pub async fn get_first_block_in_epoch(
     client: &HttpClient,
 ) -> anyhow::Result<BlockHeight> {
@@ -918,24 +932,41 @@ pub async fn get_pgf_receipients(
         .collect::<HashSet<_>>()
 }
 
+/// Supplies of the native token. Only the first supply of an epoch is kept,
+/// so the bonded supply, which is only updated once per epoch, is queried
+/// when `with_bonded` is set
 pub async fn get_native_token_supply(
     client: &HttpClient,
     native_token: &Id,
     epoch: u32,
+    with_bonded: bool,
 ) -> anyhow::Result<TokenSupply> {
     let total_supply_fut =
         query_native_token_total_supply(client, native_token);
     let effective_supply_fut = query_native_token_effective_supply(client);
+    let bonded_supply_fut = async {
+        if with_bonded {
+            query_native_token_bonded_supply(client, epoch)
+                .await
+                .map(Some)
+        } else {
+            anyhow::Ok(None)
+        }
+    };
 
-    let (total_supply, effective_supply) =
-        futures::try_join!(total_supply_fut, effective_supply_fut)
-            .context("Failed to query native token supplies")?;
+    let (total_supply, effective_supply, bonded_supply) = futures::try_join!(
+        total_supply_fut,
+        effective_supply_fut,
+        bonded_supply_fut
+    )
+    .context("Failed to query native token supplies")?;
 
     anyhow::Ok(TokenSupply {
         address: native_token.to_string(),
         epoch: epoch as _,
         total: total_supply.into(),
         effective: Some(effective_supply.into()),
+        bonded: bonded_supply.map(Into::into),
     })
 }
 
@@ -963,6 +994,7 @@ pub async fn get_token_supply(
         epoch: epoch as _,
         total: supply.into(),
         effective: None,
+        bonded: None,
     })
 }
 
@@ -5,6 +5,7 @@ use namada_sdk::borsh::BorshDeserialize;
 use namada_sdk::queries::RPC;
 use namada_sdk::storage::{self, PrefixValue};
 use shared::block::BlockHeight;
+use shared::token::{TokenMetadata, parse_token_registry};
 use tendermint_rpc::HttpClient;
 use tokio::time::sleep;
 
@@ -140,3 +141,12 @@ where
 {
     _retry(operation, 3, Duration::from_millis(500)).await
 }
+
+/// Read the token metadata listed in the registry file at `path`
+pub fn load_token_registry(path: &str) -> anyhow::Result<Vec<TokenMetadata>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read token registry {}", path))?;
+
+    parse_token_registry(&content)
+        .with_context(|| format!("Failed to parse token registry {}", path))
+}
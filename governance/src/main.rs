@@ -13,6 +13,7 @@ use governance::services::{
 };
 use governance::state::AppState;
 use namada_governance::storage::proposal::{AddRemove, PGFAction, PGFTarget};
+use namada_sdk::address::Address;
 use namada_sdk::time::DateTimeUtc;
 use orm::migrations::CustomMigrationSource;
 use shared::balance::Amount as NamadaAmount;
@@ -20,7 +21,10 @@ use shared::crawler;
 use shared::crawler_state::{CrawlerName, IntervalCrawlerState};
 use shared::error::{AsDbError, AsRpcError, ContextDbInteractError, MainError};
 use shared::id::Id;
-use shared::pgf::{PaymentKind, PaymentRecurrence, PgfAction, PgfPayment};
+use shared::pgf::{
+    PaymentKind, PaymentRecurrence, PgfAction, PgfPayment, PgfStewardEvent,
+    PgfStewardEventKind,
+};
 use shared::proposal::GovernanceProposalResult;
 use tendermint_rpc::HttpClient;
 use tendermint_rpc::client::CompatMode;
@@ -127,6 +131,40 @@ async fn crawling_fn(
         running_governance_proposals.len()
     );
 
+    // Only recompute the votes power of proposals that have votes without a
+    // voting power computed at the epoch they are tallied with
+    let outdated_votes_power_proposals = {
+        let proposals = running_governance_proposals.clone();
+        conn.interact(move |conn| {
+            repository::governance::get_outdated_votes_power_proposals(
+                conn,
+                &proposals,
+                epoch as u32,
+            )
+        })
+        .await
+        .context_db_interact_error()
+        .and_then(identity)
+        .into_db_error()?
+    };
+
+    let votes_power = namada_service::get_governance_proposals_votes_power(
+        &client,
+        running_governance_proposals
+            .iter()
+            .filter(|proposal| {
+                outdated_votes_power_proposals.contains(&proposal.id)
+            })
+            .cloned()
+            .collect(),
+        epoch as u32,
+    )
+    .await;
+    tracing::debug!(
+        "Computed votes power of {} proposals...",
+        votes_power.len()
+    );
+
     let proposals_statuses = namada_service::get_governance_proposals_updates(
         &client,
         running_governance_proposals,
@@ -253,6 +291,52 @@ async fn crawling_fn(
         .collect::<Vec<_>>();
     tracing::debug!("Got {} pgf payments...", pgf_payments.len());
 
+    let pgf_steward_events = conn
+        .interact(move |conn| {
+            repository::governance::get_all_steward_executed_proposals_data(
+                conn, epoch,
+            )
+        })
+        .await
+        .context_db_interact_error()
+        .and_then(identity)
+        .into_db_error()?
+        .into_iter()
+        .filter_map(|(id, data)| {
+            data.and_then(|data| {
+                serde_json::from_str::<BTreeSet<AddRemove<Address>>>(&data).ok()
+            })
+            .map(|actions| (id, actions))
+        })
+        .flat_map(|(id, actions)| {
+            actions.into_iter().map(move |action| {
+                let (address, kind) = match action {
+                    AddRemove::Add(address) => {
+                        (address, PgfStewardEventKind::Added)
+                    }
+                    AddRemove::Remove(address) => {
+                        (address, PgfStewardEventKind::Removed)
+                    }
+                };
+
+                PgfStewardEvent {
+                    address: Id::from(address),
+                    kind,
+                    proposal_id: Some(id),
+                    block_height: None,
+                    inner_tx_id: None,
+                    commission: None,
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    tracing::debug!("Got {} pgf steward events...", pgf_steward_events.len());
+
+    let pgf_stewards = namada_service::get_pgf_stewards(&client)
+        .await
+        .into_rpc_error()?;
+    tracing::debug!("Got {} pgf stewards...", pgf_stewards.len());
+
     let timestamp = DateTimeUtc::now().0.timestamp();
     let crawler_state = IntervalCrawlerState { timestamp };
 
@@ -273,8 +357,23 @@ async fn crawling_fn(
                     )?;
                 }
 
+                repository::governance::update_votes_power(
+                    transaction_conn,
+                    votes_power,
+                )?;
+
                 repository::pgf::update_pgf(transaction_conn, pgf_payments)?;
 
+                repository::pgf::insert_pgf_steward_events(
+                    transaction_conn,
+                    pgf_steward_events,
+                )?;
+
+                repository::pgf::update_pgf_stewards(
+                    transaction_conn,
+                    pgf_stewards,
+                )?;
+
                 for (proposal_id, proposal_result) in executed_proposals {
                     repository::governance::update_proposal_result(
                         transaction_conn,
@@ -1,5 +1,9 @@
+use std::collections::HashSet;
+
 use anyhow::Context;
 use diesel::connection::DefaultLoadingMode;
+use diesel::dsl::{count, count_star, max, min};
+use diesel::sql_types::{Array, Bool, Integer, Numeric, Text};
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, PgConnection, QueryDsl,
     RunQueryDsl,
@@ -8,9 +12,12 @@ use orm::governance_proposal::{
     GovernanceProposalKindDb, GovernanceProposalResultDb,
     GovernanceProposalUpdateStatusDb,
 };
-use orm::schema::governance_proposals;
+use orm::governance_votes::GovernanceProposalVotePowerUpdateDb;
+use orm::schema::{governance_proposals, governance_votes};
+use shared::block::Epoch;
 use shared::proposal::GovernanceProposalResult;
 use shared::utils::GovernanceProposalShort;
+use shared::vote::GovernanceVotePower;
 
 pub fn get_all_running_proposals(
     conn: &mut PgConnection,
@@ -82,6 +89,37 @@ pub fn get_all_pgf_executed_proposals_data(
         .collect::<Result<Vec<(u64, Option<String>)>, _>>()
 }
 
+pub fn get_all_steward_executed_proposals_data(
+    conn: &mut PgConnection,
+    current_epoch: u32,
+) -> anyhow::Result<Vec<(u64, Option<String>)>> {
+    governance_proposals::table
+        .filter(
+            governance_proposals::dsl::result
+                .eq(GovernanceProposalResultDb::Passed)
+                .and(
+                    governance_proposals::dsl::activation_epoch
+                        .le(current_epoch as i32),
+                )
+                .and(
+                    governance_proposals::dsl::kind
+                        .eq(GovernanceProposalKindDb::PgfSteward),
+                ),
+        )
+        .select((
+            governance_proposals::dsl::id,
+            governance_proposals::dsl::data,
+        ))
+        .load_iter::<(i32, Option<String>), DefaultLoadingMode>(conn)
+        .context("Failed to get governance proposal ids from db")?
+        .map(|result| {
+            let (id, data) =
+                result.context("Failed to deserialize proposal from db")?;
+            anyhow::Ok((id as u64, data))
+        })
+        .collect::<Result<Vec<(u64, Option<String>)>, _>>()
+}
+
 pub fn get_all_executed_proposals(
     conn: &mut PgConnection,
     current_epoch: u32,
@@ -126,6 +164,104 @@ pub fn update_proposal_status(
     Ok(())
 }
 
+/// Returns the proposals with at least one vote whose voting power was not
+/// computed at the epoch it should be tallied with
+pub fn get_outdated_votes_power_proposals(
+    conn: &mut PgConnection,
+    proposals: &[GovernanceProposalShort],
+    current_epoch: Epoch,
+) -> anyhow::Result<HashSet<u64>> {
+    let proposal_ids = proposals
+        .iter()
+        .map(|proposal| proposal.id as i32)
+        .collect::<Vec<_>>();
+
+    let votes_power_epochs = governance_votes::table
+        .filter(governance_votes::dsl::proposal_id.eq_any(proposal_ids))
+        .group_by(governance_votes::dsl::proposal_id)
+        .select((
+            governance_votes::dsl::proposal_id,
+            min(governance_votes::dsl::voting_power_epoch),
+            max(governance_votes::dsl::voting_power_epoch),
+            count(governance_votes::dsl::voting_power_epoch),
+            count_star(),
+        ))
+        .load::<(i32, Option<i32>, Option<i32>, i64, i64)>(conn)
+        .context("Failed to get votes power epochs from db")?;
+
+    Ok(votes_power_epochs
+        .into_iter()
+        .filter(|(proposal_id, min_epoch, max_epoch, computed, votes)| {
+            let Some(proposal) = proposals
+                .iter()
+                .find(|proposal| proposal.id == *proposal_id as u64)
+            else {
+                return false;
+            };
+            let epoch = Some(proposal.votes_power_epoch(current_epoch) as i32);
+
+            computed < votes || *min_epoch != epoch || *max_epoch != epoch
+        })
+        .map(|(proposal_id, ..)| proposal_id as u64)
+        .collect())
+}
+
+pub fn update_votes_power(
+    transaction_conn: &mut PgConnection,
+    votes_power: Vec<(Epoch, Vec<GovernanceVotePower>)>,
+) -> anyhow::Result<()> {
+    let updates = votes_power
+        .into_iter()
+        .flat_map(|(epoch, votes_power)| {
+            votes_power.into_iter().map(move |vote_power| {
+                (
+                    vote_power.proposal_id as i32,
+                    vote_power.address.to_string(),
+                    GovernanceProposalVotePowerUpdateDb::from_vote_power(
+                        vote_power, epoch,
+                    ),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if updates.is_empty() {
+        return Ok(());
+    }
+
+    let mut proposal_ids = Vec::with_capacity(updates.len());
+    let mut voter_addresses = Vec::with_capacity(updates.len());
+    let mut voting_powers = Vec::with_capacity(updates.len());
+    let mut is_validators = Vec::with_capacity(updates.len());
+    let mut voting_power_epochs = Vec::with_capacity(updates.len());
+    for (proposal_id, voter_address, update) in updates {
+        proposal_ids.push(proposal_id);
+        voter_addresses.push(voter_address);
+        voting_powers.push(update.voting_power);
+        is_validators.push(update.is_validator);
+        voting_power_epochs.push(update.voting_power_epoch);
+    }
+
+    diesel::sql_query(
+        "UPDATE governance_votes SET voting_power = updates.voting_power, \
+         is_validator = updates.is_validator, voting_power_epoch = \
+         updates.voting_power_epoch FROM unnest($1, $2, $3, $4, $5) AS \
+         updates(proposal_id, voter_address, voting_power, is_validator, \
+         voting_power_epoch) WHERE governance_votes.proposal_id = \
+         updates.proposal_id AND governance_votes.voter_address = \
+         updates.voter_address",
+    )
+    .bind::<Array<Integer>, _>(proposal_ids)
+    .bind::<Array<Text>, _>(voter_addresses)
+    .bind::<Array<Numeric>, _>(voting_powers)
+    .bind::<Array<Bool>, _>(is_validators)
+    .bind::<Array<Integer>, _>(voting_power_epochs)
+    .execute(transaction_conn)
+    .context("Failed to update votes voting power in db")?;
+
+    Ok(())
+}
+
 pub fn update_proposal_result(
     transaction_conn: &mut PgConnection,
     proposal_id: u64,
@@ -3,9 +3,12 @@ use diesel::query_dsl::methods::FilterDsl;
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, PgConnection, RunQueryDsl,
 };
-use orm::pgf::{PaymentRecurrenceDb, PublicGoodFundingPaymentInsertDb};
-use orm::schema::public_good_funding;
-use shared::pgf::{PaymentRecurrence, PgfPayment};
+use orm::pgf::{
+    PaymentRecurrenceDb, PgfStewardEventInsertDb, PgfStewardInsertDb,
+    PublicGoodFundingPaymentInsertDb,
+};
+use orm::schema::{pgf_steward_events, pgf_stewards, public_good_funding};
+use shared::pgf::{PaymentRecurrence, PgfPayment, PgfSteward, PgfStewardEvent};
 
 pub fn update_pgf(
     transaction_conn: &mut PgConnection,
@@ -52,3 +55,40 @@ pub fn update_pgf(
 
     anyhow::Ok(())
 }
+
+/// Replaces the stewards set with the one currently stored on chain
+pub fn update_pgf_stewards(
+    transaction_conn: &mut PgConnection,
+    stewards: Vec<PgfSteward>,
+) -> anyhow::Result<()> {
+    diesel::delete(pgf_stewards::table)
+        .execute(transaction_conn)
+        .context("Failed to remove pgf stewards from db")?;
+
+    diesel::insert_into(pgf_stewards::table)
+        .values::<Vec<PgfStewardInsertDb>>(
+            stewards.into_iter().map(PgfStewardInsertDb::from).collect(),
+        )
+        .execute(transaction_conn)
+        .context("Failed to insert pgf stewards in db")?;
+
+    anyhow::Ok(())
+}
+
+pub fn insert_pgf_steward_events(
+    transaction_conn: &mut PgConnection,
+    steward_events: Vec<PgfStewardEvent>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(pgf_steward_events::table)
+        .values::<Vec<PgfStewardEventInsertDb>>(
+            steward_events
+                .into_iter()
+                .map(PgfStewardEventInsertDb::from)
+                .collect(),
+        )
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert pgf steward events in db")?;
+
+    anyhow::Ok(())
+}
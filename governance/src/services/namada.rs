@@ -1,11 +1,17 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
+use namada_core::chain::Epoch as NamadaEpoch;
 use namada_sdk::queries::RPC;
 use namada_sdk::rpc;
+use shared::balance::Amount;
 use shared::block::{BlockHeight, Epoch};
 use shared::id::Id;
+use shared::pgf::PgfSteward;
 use shared::proposal::{GovernanceProposalResult, GovernanceProposalStatus};
 use shared::utils::GovernanceProposalShort;
+use shared::vote::GovernanceVotePower;
 use tendermint_rpc::HttpClient;
 
 pub async fn query_latest_block_height(
@@ -33,6 +39,21 @@ pub async fn get_native_token(client: &HttpClient) -> anyhow::Result<Id> {
     Ok(Id::from(native_token))
 }
 
+pub async fn get_pgf_stewards(
+    client: &HttpClient,
+) -> anyhow::Result<Vec<PgfSteward>> {
+    let stewards = rpc::query_pgf_stewards(client)
+        .await
+        .context("Failed to query pgf stewards")?;
+
+    Ok(stewards
+        .into_iter()
+        .map(|steward| {
+            PgfSteward::new(steward.address, steward.reward_distribution)
+        })
+        .collect())
+}
+
 pub async fn get_governance_proposals_updates(
     client: &HttpClient,
     proposal_data: Vec<GovernanceProposalShort>,
@@ -87,3 +108,106 @@ pub async fn get_governance_proposals_updates(
         .collect::<Vec<_>>()
         .await)
 }
+
+pub async fn get_governance_proposals_votes_power(
+    client: &HttpClient,
+    proposal_data: Vec<GovernanceProposalShort>,
+    current_epoch: Epoch,
+) -> Vec<(Epoch, Vec<GovernanceVotePower>)> {
+    let current_epoch = current_epoch as u64;
+
+    futures::stream::iter(proposal_data)
+        .filter(|proposal| {
+            futures::future::ready(
+                current_epoch.ge(&proposal.voting_start_epoch),
+            )
+        })
+        .filter_map(|proposal| async move {
+            let epoch = proposal.votes_power_epoch(current_epoch as Epoch);
+
+            match get_proposal_votes_power(client, proposal.id, epoch).await {
+                Ok(votes_power) => Some((epoch, votes_power)),
+                Err(err) => {
+                    tracing::warn!(
+                        proposal_id = proposal.id,
+                        "Failed to compute votes power: {err:#}"
+                    );
+                    None
+                }
+            }
+        })
+        .map(futures::future::ready)
+        .buffer_unordered(32)
+        .collect::<Vec<_>>()
+        .await
+}
+
+async fn get_proposal_votes_power(
+    client: &HttpClient,
+    proposal_id: u64,
+    epoch: Epoch,
+) -> anyhow::Result<Vec<GovernanceVotePower>> {
+    let namada_epoch = NamadaEpoch::from(epoch as u64);
+
+    let votes = rpc::query_proposal_votes(client, proposal_id)
+        .await
+        .context("Failed to query proposal votes")?;
+
+    let (validator_votes, delegator_votes): (Vec<_>, Vec<_>) = votes
+        .into_iter()
+        .partition(|vote| vote.validator == vote.delegator);
+
+    let validator_stakes = futures::stream::iter(validator_votes)
+        .map(|vote| async move {
+            let stake =
+                rpc::get_validator_stake(client, namada_epoch, &vote.validator)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to query the stake of validator {} at \
+                             epoch {namada_epoch}",
+                            vote.validator
+                        )
+                    })?;
+
+            anyhow::Ok((Id::from(vote.validator), Amount::from(stake)))
+        })
+        .buffer_unordered(32)
+        .try_collect::<HashMap<_, _>>()
+        .await?;
+
+    let delegator_bonds = futures::stream::iter(delegator_votes)
+        .map(|vote| async move {
+            let bond = RPC
+                .vp()
+                .pos()
+                .bond_with_slashing(
+                    client,
+                    &vote.delegator,
+                    &vote.validator,
+                    &Some(namada_epoch),
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to query the bond of {} to {} at epoch \
+                         {namada_epoch}",
+                        vote.delegator, vote.validator
+                    )
+                })?;
+
+            anyhow::Ok((
+                (Id::from(vote.delegator), Id::from(vote.validator)),
+                Amount::from(bond),
+            ))
+        })
+        .buffer_unordered(32)
+        .try_collect::<HashMap<_, _>>()
+        .await?;
+
+    Ok(GovernanceVotePower::compute(
+        proposal_id,
+        validator_stakes,
+        delegator_bonds,
+    ))
+}
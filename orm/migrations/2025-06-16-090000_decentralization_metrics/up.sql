@@ -0,0 +1,15 @@
+-- Your SQL goes here
+
+CREATE TABLE decentralization_metrics (
+    id SERIAL PRIMARY KEY,
+    epoch INT NOT NULL,
+    consensus_validators INT NOT NULL,
+    total_voting_power DOUBLE PRECISION NOT NULL,
+    nakamoto_coefficient_one_third INT NOT NULL,
+    nakamoto_coefficient_two_thirds INT NOT NULL,
+    gini_coefficient DOUBLE PRECISION NOT NULL,
+    top_n INT NOT NULL,
+    top_n_share DOUBLE PRECISION NOT NULL
+);
+
+ALTER TABLE decentralization_metrics ADD UNIQUE (epoch);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE IF EXISTS validator_events;
+
+DROP TYPE IF EXISTS VALIDATOR_EVENT_KIND;
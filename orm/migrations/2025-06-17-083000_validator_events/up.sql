@@ -0,0 +1,24 @@
+-- Your SQL goes here
+
+CREATE TYPE VALIDATOR_EVENT_KIND AS ENUM (
+    'become_validator',
+    'deactivation',
+    'reactivation',
+    'unjail',
+    'metadata_change',
+    'commission_change',
+    'consensus_key_change'
+);
+
+CREATE TABLE validator_events (
+    id SERIAL PRIMARY KEY,
+    address VARCHAR NOT NULL,
+    kind VALIDATOR_EVENT_KIND NOT NULL,
+    block_height INT NOT NULL,
+    inner_tx_id VARCHAR(64) NOT NULL,
+    data VARCHAR,
+    CONSTRAINT fk_validator_events_block_height FOREIGN KEY(block_height) REFERENCES blocks(height) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX index_validator_events_inner_tx_id ON validator_events (inner_tx_id);
+CREATE INDEX index_validator_events_address_block_height ON validator_events (address, block_height DESC);
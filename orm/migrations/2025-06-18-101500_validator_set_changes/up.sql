@@ -0,0 +1,13 @@
+-- Your SQL goes here
+
+CREATE TABLE validator_set_changes (
+    id SERIAL PRIMARY KEY,
+    address VARCHAR NOT NULL,
+    epoch INT NOT NULL,
+    previous_state VALIDATOR_STATE,
+    current_state VALIDATOR_STATE NOT NULL
+);
+
+ALTER TABLE validator_set_changes ADD UNIQUE (epoch, address);
+
+CREATE INDEX index_validator_set_changes_address ON validator_set_changes (address);
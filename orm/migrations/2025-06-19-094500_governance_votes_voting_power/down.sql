@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_governance_votes_proposal_id_voting_power;
+
+ALTER TABLE governance_votes DROP COLUMN voting_power_epoch;
+ALTER TABLE governance_votes DROP COLUMN is_validator;
+ALTER TABLE governance_votes DROP COLUMN voting_power;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+
+ALTER TABLE governance_votes ADD COLUMN voting_power NUMERIC(78, 0);
+ALTER TABLE governance_votes ADD COLUMN is_validator BOOLEAN;
+ALTER TABLE governance_votes ADD COLUMN voting_power_epoch INT;
+
+CREATE INDEX index_governance_votes_proposal_id_voting_power ON governance_votes (proposal_id, voting_power);
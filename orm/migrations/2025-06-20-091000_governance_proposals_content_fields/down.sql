@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_governance_proposals_search_vector;
+
+ALTER TABLE governance_proposals DROP COLUMN search_vector;
+ALTER TABLE governance_proposals DROP COLUMN created;
+ALTER TABLE governance_proposals DROP COLUMN requires;
+ALTER TABLE governance_proposals DROP COLUMN details;
+ALTER TABLE governance_proposals DROP COLUMN motivation;
+ALTER TABLE governance_proposals DROP COLUMN abstract;
+ALTER TABLE governance_proposals DROP COLUMN discussions_to;
+ALTER TABLE governance_proposals DROP COLUMN authors;
+ALTER TABLE governance_proposals DROP COLUMN title;
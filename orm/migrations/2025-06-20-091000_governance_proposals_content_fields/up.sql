@@ -0,0 +1,32 @@
+-- Your SQL goes here
+
+ALTER TABLE governance_proposals ADD COLUMN title VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN authors VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN discussions_to VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN abstract VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN motivation VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN details VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN requires VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN created VARCHAR;
+
+-- Backfill the fields of the proposals indexed so far from the JSON content
+UPDATE governance_proposals
+SET
+    title = NULLIF(TRIM(content::jsonb ->> 'title'), ''),
+    authors = NULLIF(TRIM(content::jsonb ->> 'authors'), ''),
+    discussions_to = NULLIF(TRIM(COALESCE(content::jsonb ->> 'discussions-to', content::jsonb ->> 'discussions_to')), ''),
+    abstract = NULLIF(TRIM(content::jsonb ->> 'abstract'), ''),
+    motivation = NULLIF(TRIM(content::jsonb ->> 'motivation'), ''),
+    details = NULLIF(TRIM(content::jsonb ->> 'details'), ''),
+    requires = NULLIF(TRIM(content::jsonb ->> 'requires'), ''),
+    created = NULLIF(TRIM(content::jsonb ->> 'created'), '')
+WHERE content LIKE '{%';
+
+ALTER TABLE governance_proposals ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('english', COALESCE(title, '')), 'A') ||
+    setweight(to_tsvector('english', COALESCE(abstract, '')), 'B') ||
+    setweight(to_tsvector('english', COALESCE(motivation, '') || ' ' || COALESCE(details, '')), 'C') ||
+    setweight(to_tsvector('simple', COALESCE(authors, '')), 'D')
+) STORED;
+
+CREATE INDEX index_governance_proposals_search_vector ON governance_proposals USING GIN (search_vector);
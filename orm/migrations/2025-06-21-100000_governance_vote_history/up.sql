@@ -0,0 +1,16 @@
+-- Your SQL goes here
+
+CREATE TABLE governance_vote_history (
+    id SERIAL PRIMARY KEY,
+    proposal_id INT NOT NULL,
+    voter_address VARCHAR NOT NULL,
+    kind VOTE_KIND NOT NULL,
+    block_height INT NOT NULL,
+    inner_tx_id VARCHAR(64) NOT NULL,
+    CONSTRAINT fk_proposal FOREIGN KEY(proposal_id) REFERENCES governance_proposals(id) ON DELETE CASCADE,
+    CONSTRAINT fk_block_height FOREIGN KEY(block_height) REFERENCES blocks(height) ON DELETE CASCADE
+);
+
+ALTER TABLE governance_vote_history ADD UNIQUE (inner_tx_id);
+
+CREATE INDEX index_governance_vote_history_proposal_id_block_height ON governance_vote_history (proposal_id, block_height);
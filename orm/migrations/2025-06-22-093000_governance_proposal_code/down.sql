@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_governance_proposals_code_hash;
+
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS code_size;
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS code_hash;
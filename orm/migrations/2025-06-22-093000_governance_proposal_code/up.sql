@@ -0,0 +1,17 @@
+-- Your SQL goes here
+
+-- The proposal code is stored hex encoded in the data column, keep a content
+-- addressed reference to it
+ALTER TABLE governance_proposals ADD COLUMN code_hash VARCHAR(64) GENERATED ALWAYS AS (
+    CASE WHEN kind = 'default_with_wasm' AND data IS NOT NULL
+        THEN encode(sha256(decode(data, 'hex')), 'hex')
+    END
+) STORED;
+
+ALTER TABLE governance_proposals ADD COLUMN code_size INT GENERATED ALWAYS AS (
+    CASE WHEN kind = 'default_with_wasm' AND data IS NOT NULL
+        THEN octet_length(decode(data, 'hex'))
+    END
+) STORED;
+
+CREATE INDEX index_governance_proposals_code_hash ON governance_proposals (code_hash);
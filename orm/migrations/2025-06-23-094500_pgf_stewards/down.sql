@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE IF EXISTS pgf_steward_events;
+DROP TYPE IF EXISTS PGF_STEWARD_EVENT_KIND;
+DROP TABLE IF EXISTS pgf_stewards;
@@ -0,0 +1,28 @@
+-- Your SQL goes here
+
+ALTER TYPE TRANSACTION_KIND ADD VALUE 'update_steward_commission';
+ALTER TYPE TRANSACTION_KIND ADD VALUE 'resign_steward';
+
+CREATE TABLE pgf_stewards (
+    address VARCHAR PRIMARY KEY,
+    commission VARCHAR NOT NULL
+);
+
+CREATE TYPE PGF_STEWARD_EVENT_KIND AS ENUM ('added', 'removed', 'resigned', 'commission_update');
+
+CREATE TABLE pgf_steward_events (
+    id SERIAL PRIMARY KEY,
+    address VARCHAR NOT NULL,
+    kind PGF_STEWARD_EVENT_KIND NOT NULL,
+    proposal_id INT,
+    block_height INT,
+    inner_tx_id VARCHAR(64),
+    commission VARCHAR,
+    CONSTRAINT fk_proposal FOREIGN KEY(proposal_id) REFERENCES governance_proposals(id) ON DELETE CASCADE,
+    CONSTRAINT fk_block_height FOREIGN KEY(block_height) REFERENCES blocks(height) ON DELETE CASCADE
+);
+
+ALTER TABLE pgf_steward_events ADD UNIQUE (inner_tx_id);
+ALTER TABLE pgf_steward_events ADD UNIQUE (proposal_id, address, kind);
+
+CREATE INDEX index_pgf_steward_events_address ON pgf_steward_events (address);
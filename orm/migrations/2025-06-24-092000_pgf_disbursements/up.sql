@@ -0,0 +1,16 @@
+-- Your SQL goes here
+
+CREATE TABLE pgf_disbursements (
+    id SERIAL PRIMARY KEY,
+    receipient VARCHAR NOT NULL,
+    token VARCHAR(64) NOT NULL,
+    epoch INT NOT NULL,
+    block_height INT NOT NULL,
+    amount NUMERIC(78, 0) NOT NULL,
+    CONSTRAINT fk_block_height FOREIGN KEY(block_height) REFERENCES blocks(height) ON DELETE CASCADE
+);
+
+ALTER TABLE pgf_disbursements ADD UNIQUE (receipient, token, epoch);
+
+CREATE INDEX index_pgf_disbursements_receipient ON pgf_disbursements (receipient);
+CREATE INDEX index_pgf_disbursements_epoch ON pgf_disbursements (epoch);
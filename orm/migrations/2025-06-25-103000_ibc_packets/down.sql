@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE IF EXISTS ibc_packets;
+
+DROP TYPE IF EXISTS IBC_PACKET_STATUS;
+DROP TYPE IF EXISTS IBC_PACKET_DIRECTION;
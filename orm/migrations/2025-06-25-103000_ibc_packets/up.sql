@@ -0,0 +1,32 @@
+-- Your SQL goes here
+
+CREATE TYPE IBC_PACKET_DIRECTION AS ENUM ('outgoing', 'incoming');
+CREATE TYPE IBC_PACKET_STATUS AS ENUM ('sent', 'received', 'acknowledged', 'timed_out');
+
+CREATE TABLE ibc_packets (
+    id VARCHAR PRIMARY KEY,
+    direction IBC_PACKET_DIRECTION NOT NULL,
+    status IBC_PACKET_STATUS NOT NULL,
+    source_port VARCHAR NOT NULL,
+    source_channel VARCHAR NOT NULL,
+    dest_port VARCHAR NOT NULL,
+    dest_channel VARCHAR NOT NULL,
+    sequence BIGINT NOT NULL,
+    denom VARCHAR,
+    amount NUMERIC(78, 0),
+    sender VARCHAR,
+    receiver VARCHAR,
+    memo VARCHAR,
+    timeout_height VARCHAR NOT NULL,
+    timeout_timestamp BIGINT NOT NULL,
+    sent_at_height INT,
+    received_at_height INT,
+    acknowledged_at_height INT,
+    timed_out_at_height INT
+);
+
+CREATE INDEX index_ibc_packets_source_channel_sequence ON ibc_packets (source_channel, sequence);
+CREATE INDEX index_ibc_packets_dest_channel_sequence ON ibc_packets (dest_channel, sequence);
+CREATE INDEX index_ibc_packets_sender ON ibc_packets (sender);
+CREATE INDEX index_ibc_packets_receiver ON ibc_packets (receiver);
+CREATE INDEX index_ibc_packets_status ON ibc_packets (status);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE IF EXISTS ibc_channels;
+DROP TABLE IF EXISTS ibc_connections;
+DROP TABLE IF EXISTS ibc_clients;
+
+DROP TYPE IF EXISTS IBC_CHANNEL_STATE;
+DROP TYPE IF EXISTS IBC_CONNECTION_STATE;
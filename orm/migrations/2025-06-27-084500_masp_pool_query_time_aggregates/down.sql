@@ -0,0 +1,147 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_masp_pool_token_address_direction_timestamp;
+
+DROP INDEX IF EXISTS index_masp_pool_timestamp;
+
+CREATE TYPE MASP_POOL_AGGREGATE_WINDOW AS ENUM (
+    'one_day',
+    'seven_days',
+    'thirty_days',
+    'all_time'
+);
+
+CREATE TYPE MASP_POOL_AGGREGATE_KIND AS ENUM (
+    'inflows',
+    'outflows'
+);
+
+CREATE TABLE masp_pool_aggregate (
+    id SERIAL PRIMARY KEY,
+    token_address VARCHAR(45) NOT NULL,
+    time_window MASP_POOL_AGGREGATE_WINDOW NOT NULL,
+    kind MASP_POOL_AGGREGATE_KIND NOT NULL,
+    total_amount NUMERIC(78, 0) NOT NULL DEFAULT 0
+);
+
+CREATE UNIQUE INDEX index_masp_pool_aggregate_token_address_window_kind ON masp_pool_aggregate (token_address, time_window, kind);
+
+-- Restore the totals of the existing entries, the trigger only maintains them
+-- for new ones
+INSERT INTO masp_pool_aggregate (token_address, time_window, kind, total_amount)
+SELECT
+    token_address,
+    windows.time_window,
+    CASE
+        WHEN direction = 'in' THEN 'inflows'::MASP_POOL_AGGREGATE_KIND
+        ELSE 'outflows'::MASP_POOL_AGGREGATE_KIND
+    END,
+    COALESCE(SUM(raw_amount) FILTER (WHERE windows.cutoff IS NULL OR timestamp >= windows.cutoff), 0)
+FROM masp_pool
+CROSS JOIN (
+    VALUES
+        ('one_day'::MASP_POOL_AGGREGATE_WINDOW, (now() - INTERVAL '1 day')::TIMESTAMP),
+        ('seven_days'::MASP_POOL_AGGREGATE_WINDOW, (now() - INTERVAL '7 days')::TIMESTAMP),
+        ('thirty_days'::MASP_POOL_AGGREGATE_WINDOW, (now() - INTERVAL '30 days')::TIMESTAMP),
+        ('all_time'::MASP_POOL_AGGREGATE_WINDOW, NULL)
+) AS windows (time_window, cutoff)
+GROUP BY token_address, direction, windows.time_window;
+
+
+CREATE OR REPLACE FUNCTION update_masp_pool_aggregate_sum()
+RETURNS TRIGGER AS $$
+DECLARE
+  cutoff_1d TIMESTAMP := now() - INTERVAL '1 day';
+  cutoff_7d TIMESTAMP := now() - INTERVAL '7 days';
+  cutoff_30d TIMESTAMP := now() - INTERVAL '30 days';
+  nk MASP_POOL_AGGREGATE_KIND; -- Declare kind as the ENUM type
+BEGIN
+  -- Determine the kind based on the direction
+  nk := CASE
+            WHEN NEW.direction = 'in' THEN 'inflows'::MASP_POOL_AGGREGATE_KIND
+            ELSE 'outflows'::MASP_POOL_AGGREGATE_KIND
+          END;
+  -- 1 day
+  INSERT INTO masp_pool_aggregate (token_address, time_window, kind, total_amount)
+  VALUES (
+    NEW.token_address,
+    'one_day',
+    nk,
+    (SELECT COALESCE(SUM(raw_amount), 0)
+     FROM masp_pool
+     WHERE token_address = NEW.token_address
+       AND direction = NEW.direction
+       AND timestamp >= cutoff_1d)
+  )
+  ON CONFLICT (token_address, time_window, kind)
+  DO UPDATE SET total_amount = (
+    SELECT COALESCE(SUM(raw_amount), 0)
+    FROM masp_pool
+    WHERE token_address = NEW.token_address
+      AND direction = NEW.direction
+      AND timestamp >= cutoff_1d
+  );
+
+  -- 7 days
+  INSERT INTO masp_pool_aggregate (token_address, time_window, kind, total_amount)
+  VALUES (
+    NEW.token_address,
+    'seven_days',
+    nk,
+    (SELECT COALESCE(SUM(raw_amount), 0)
+     FROM masp_pool
+     WHERE token_address = NEW.token_address
+       AND direction = NEW.direction
+       AND timestamp >= cutoff_1d)
+  )
+  ON CONFLICT (token_address, time_window, kind) 
+  DO UPDATE SET total_amount = (
+    SELECT COALESCE(SUM(raw_amount), 0)
+    FROM masp_pool
+    WHERE token_address = NEW.token_address
+      AND direction = NEW.direction
+      AND timestamp >= cutoff_7d
+  );
+
+  -- 30 days
+  INSERT INTO masp_pool_aggregate (token_address, time_window, kind, total_amount)
+  VALUES (
+    NEW.token_address,
+    'thirty_days',
+    nk,
+    (SELECT COALESCE(SUM(raw_amount), 0)
+     FROM masp_pool
+     WHERE token_address = NEW.token_address
+       AND direction = NEW.direction
+       AND timestamp >= cutoff_1d)
+  )
+  ON CONFLICT (token_address, time_window, kind) 
+  DO UPDATE SET total_amount = (
+    SELECT COALESCE(SUM(raw_amount), 0)
+    FROM masp_pool
+    WHERE token_address = NEW.token_address
+      AND direction = NEW.direction
+      AND timestamp >= cutoff_30d
+  );
+
+  INSERT INTO masp_pool_aggregate (token_address, time_window, kind, total_amount)
+  VALUES (
+    NEW.token_address,
+    'all_time',
+    nk,
+    (SELECT COALESCE(SUM(raw_amount), 0)
+     FROM masp_pool
+     WHERE token_address = NEW.token_address
+       AND direction = NEW.direction)
+  )
+  ON CONFLICT (token_address, time_window, kind) 
+  DO UPDATE SET total_amount = masp_pool_aggregate.total_amount + NEW.raw_amount;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER update_masp_pool_aggregate_sum_trigger
+AFTER INSERT ON masp_pool
+FOR EACH ROW
+EXECUTE FUNCTION update_masp_pool_aggregate_sum();
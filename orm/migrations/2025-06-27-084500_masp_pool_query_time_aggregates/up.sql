@@ -0,0 +1,19 @@
+-- Your SQL goes here
+
+-- Rolling windows are computed from masp_pool when queried, as the
+-- trigger-maintained values went stale without new entries
+DROP TRIGGER IF EXISTS update_masp_pool_aggregate_sum_trigger ON masp_pool;
+
+DROP FUNCTION IF EXISTS update_masp_pool_aggregate_sum();
+
+DROP TABLE IF EXISTS masp_pool_aggregate;
+
+DROP TYPE IF EXISTS MASP_POOL_AGGREGATE_WINDOW;
+
+DROP TYPE IF EXISTS MASP_POOL_AGGREGATE_KIND;
+
+CREATE INDEX index_masp_pool_timestamp ON masp_pool (timestamp);
+
+-- Covers the per token and direction aggregates, so the rolling windows are
+-- summed with an index only scan
+CREATE INDEX index_masp_pool_token_address_direction_timestamp ON masp_pool (token_address, direction, timestamp) INCLUDE (raw_amount);
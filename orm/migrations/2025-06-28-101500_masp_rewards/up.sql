@@ -0,0 +1,18 @@
+-- Your SQL goes here
+
+CREATE TABLE masp_rewards (
+    id SERIAL PRIMARY KEY,
+    token VARCHAR(45) NOT NULL,
+    epoch INT NOT NULL,
+    max_reward_rate NUMERIC NOT NULL,
+    kp_gain NUMERIC NOT NULL,
+    kd_gain NUMERIC NOT NULL,
+    locked_amount_target NUMERIC(78, 0) NOT NULL,
+    last_inflation NUMERIC(78, 0) NOT NULL,
+    last_locked_amount NUMERIC(78, 0) NOT NULL,
+    reward_rate NUMERIC NOT NULL
+);
+
+ALTER TABLE masp_rewards ADD UNIQUE (token, epoch);
+
+CREATE INDEX index_masp_rewards_epoch ON masp_rewards (epoch);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+
+-- Transactions indexed before this migration are counted without notes
+ALTER TABLE inner_transactions ADD COLUMN notes INT NOT NULL DEFAULT 0;
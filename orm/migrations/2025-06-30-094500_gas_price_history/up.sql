@@ -0,0 +1,13 @@
+-- Your SQL goes here
+
+CREATE TABLE gas_price_history (
+    id SERIAL PRIMARY KEY,
+    token VARCHAR NOT NULL,
+    amount NUMERIC(78, 0) NOT NULL,
+    epoch INT NOT NULL,
+    timestamp TIMESTAMP NOT NULL
+);
+
+ALTER TABLE gas_price_history ADD UNIQUE (token, epoch);
+
+CREATE INDEX index_gas_price_history_token_epoch ON gas_price_history (token, epoch DESC);
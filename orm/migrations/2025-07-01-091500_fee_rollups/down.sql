@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE IF EXISTS fee_rollup_blocks;
+DROP TABLE IF EXISTS fee_rollups;
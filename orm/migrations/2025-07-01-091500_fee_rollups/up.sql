@@ -0,0 +1,73 @@
+-- Your SQL goes here
+
+CREATE TABLE fee_rollups (
+    id SERIAL PRIMARY KEY,
+    token VARCHAR NOT NULL,
+    fee_payer VARCHAR NOT NULL,
+    kind TRANSACTION_KIND NOT NULL,
+    epoch INT NOT NULL,
+    hour TIMESTAMP NOT NULL,
+    amount NUMERIC(78, 0) NOT NULL,
+    transactions INT NOT NULL
+);
+
+ALTER TABLE fee_rollups ADD UNIQUE (token, fee_payer, kind, epoch, hour);
+
+CREATE INDEX index_fee_rollups_hour ON fee_rollups (hour);
+CREATE INDEX index_fee_rollups_fee_payer ON fee_rollups (fee_payer);
+
+-- Blocks whose fees were already added to the rollups, so that processing a
+-- block again doesn't count its fees twice
+CREATE TABLE fee_rollup_blocks (
+    height INT PRIMARY KEY
+);
+
+-- Backfill the rollups from the already indexed transactions. Wrappers are
+-- charged for their whole gas limit, and the raw fee is split evenly between
+-- the inner transactions, attributing the remainder to the one with the
+-- lowest id
+WITH wrapper_fees AS (
+    SELECT
+        wrapper_transactions.id AS wrapper_id,
+        -- The price per gas unit is stored with all the decimal places of its
+        -- denomination, so dropping the separator gives the raw amount
+        wrapper_transactions.gas_limit::NUMERIC
+            * replace(wrapper_transactions.amount_per_gas_unit, '.', '')::NUMERIC AS total,
+        COUNT(*) AS inner_txs,
+        MIN(inner_transactions.id) AS remainder_tx_id
+    FROM wrapper_transactions
+    JOIN inner_transactions ON inner_transactions.wrapper_id = wrapper_transactions.id
+    WHERE wrapper_transactions.amount_per_gas_unit IS NOT NULL
+    GROUP BY wrapper_transactions.id
+)
+INSERT INTO fee_rollups (token, fee_payer, kind, epoch, hour, amount, transactions)
+SELECT
+    wrapper_transactions.fee_token,
+    wrapper_transactions.fee_payer,
+    inner_transactions.kind,
+    blocks.epoch,
+    date_trunc('hour', blocks.timestamp),
+    SUM(
+        div(wrapper_fees.total, wrapper_fees.inner_txs)
+        + CASE
+            WHEN inner_transactions.id = wrapper_fees.remainder_tx_id
+                THEN mod(wrapper_fees.total, wrapper_fees.inner_txs)
+            ELSE 0
+        END
+    ),
+    COUNT(*)
+FROM inner_transactions
+JOIN wrapper_transactions ON wrapper_transactions.id = inner_transactions.wrapper_id
+JOIN blocks ON blocks.height = wrapper_transactions.block_height
+JOIN wrapper_fees ON wrapper_fees.wrapper_id = wrapper_transactions.id
+WHERE blocks.epoch IS NOT NULL
+    AND blocks.timestamp IS NOT NULL
+GROUP BY
+    wrapper_transactions.fee_token,
+    wrapper_transactions.fee_payer,
+    inner_transactions.kind,
+    blocks.epoch,
+    date_trunc('hour', blocks.timestamp);
+
+INSERT INTO fee_rollup_blocks (height)
+SELECT DISTINCT block_height FROM wrapper_transactions;
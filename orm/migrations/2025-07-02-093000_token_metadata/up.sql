@@ -0,0 +1,9 @@
+-- Your SQL goes here
+
+CREATE TABLE token_metadata (
+    address VARCHAR(45) PRIMARY KEY,
+    symbol VARCHAR NOT NULL,
+    decimals INT NOT NULL,
+    name VARCHAR,
+    logo_url VARCHAR
+);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_balance_changes_token_owner_height;
+
+DROP TABLE IF EXISTS token_holders_per_epoch;
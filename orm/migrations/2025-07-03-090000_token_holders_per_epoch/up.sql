@@ -0,0 +1,53 @@
+-- Your SQL goes here
+
+CREATE TABLE token_holders_per_epoch (
+    id SERIAL PRIMARY KEY,
+    address VARCHAR(45) NOT NULL,
+    epoch INT NOT NULL,
+    holders INT NOT NULL,
+    CONSTRAINT fk_token_holders_per_epoch_address
+        FOREIGN KEY(address) REFERENCES token(address) ON DELETE CASCADE
+);
+
+ALTER TABLE token_holders_per_epoch ADD UNIQUE (address, epoch);
+
+-- Serves the latest balance of every owner of a single token without
+-- aggregating balance_changes for all tokens
+CREATE INDEX index_balance_changes_token_owner_height ON balance_changes (token, owner, height DESC) INCLUDE (raw_amount);
+
+-- Backfill the holders at the start of every past epoch, i.e. the owners
+-- whose last balance change before that epoch is non zero. Balance changes
+-- at heights without a known epoch are skipped.
+WITH last_change_per_epoch AS (
+    SELECT DISTINCT ON (balance_changes.owner, balance_changes.token, blocks.epoch)
+        balance_changes.owner,
+        balance_changes.token,
+        blocks.epoch,
+        balance_changes.raw_amount
+    FROM balance_changes
+    JOIN blocks ON blocks.height = balance_changes.height
+    WHERE blocks.epoch IS NOT NULL
+    ORDER BY balance_changes.owner, balance_changes.token, blocks.epoch, balance_changes.height DESC
+),
+balance_spans AS (
+    SELECT
+        token,
+        epoch AS from_epoch,
+        LEAD(epoch) OVER (PARTITION BY owner, token ORDER BY epoch) AS to_epoch,
+        raw_amount
+    FROM last_change_per_epoch
+)
+INSERT INTO token_holders_per_epoch (address, epoch, holders)
+SELECT
+    balance_spans.token,
+    epochs.epoch,
+    COUNT(*)
+FROM balance_spans
+JOIN generate_series(
+    (SELECT MIN(epoch) + 1 FROM blocks),
+    (SELECT MAX(epoch) FROM blocks)
+) AS epochs(epoch)
+    ON epochs.epoch > balance_spans.from_epoch
+    AND (balance_spans.to_epoch IS NULL OR epochs.epoch <= balance_spans.to_epoch)
+WHERE balance_spans.raw_amount > 0
+GROUP BY balance_spans.token, epochs.epoch;
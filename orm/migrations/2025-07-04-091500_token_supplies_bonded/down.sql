@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE token_supplies_per_epoch DROP COLUMN IF EXISTS bonded;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+
+-- Total amount of native tokens bonded at the epoch, only set for the native
+-- token
+ALTER TABLE token_supplies_per_epoch ADD COLUMN bonded NUMERIC(78, 0);
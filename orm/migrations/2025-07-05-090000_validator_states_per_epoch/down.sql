@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+
+DELETE FROM validator_set_changes WHERE current_state IS NULL;
+
+ALTER TABLE validator_set_changes ALTER COLUMN current_state SET NOT NULL;
+
+DROP TABLE IF EXISTS validator_states_per_epoch;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+
+CREATE TABLE validator_states_per_epoch (
+    id SERIAL PRIMARY KEY,
+    address VARCHAR NOT NULL,
+    epoch INT NOT NULL,
+    state VALIDATOR_STATE NOT NULL
+);
+
+ALTER TABLE validator_states_per_epoch ADD UNIQUE (epoch, address);
+
+-- Validators that are no longer part of the set are recorded without a
+-- current state
+ALTER TABLE validator_set_changes ALTER COLUMN current_state DROP NOT NULL;
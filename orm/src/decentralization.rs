@@ -0,0 +1,53 @@
+use diesel::{Insertable, Queryable, Selectable};
+use serde::Serialize;
+use shared::decentralization::DecentralizationMetrics;
+
+use crate::schema::decentralization_metrics;
+
+#[derive(Serialize, Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = decentralization_metrics)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct DecentralizationMetricsDb {
+    pub id: i32,
+    pub epoch: i32,
+    pub consensus_validators: i32,
+    pub total_voting_power: f64,
+    pub nakamoto_coefficient_one_third: i32,
+    pub nakamoto_coefficient_two_thirds: i32,
+    pub gini_coefficient: f64,
+    pub top_n: i32,
+    pub top_n_share: f64,
+}
+
+#[derive(Serialize, Insertable, Clone, Debug)]
+#[diesel(table_name = decentralization_metrics)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct DecentralizationMetricsInsertDb {
+    pub epoch: i32,
+    pub consensus_validators: i32,
+    pub total_voting_power: f64,
+    pub nakamoto_coefficient_one_third: i32,
+    pub nakamoto_coefficient_two_thirds: i32,
+    pub gini_coefficient: f64,
+    pub top_n: i32,
+    pub top_n_share: f64,
+}
+
+impl From<DecentralizationMetrics> for DecentralizationMetricsInsertDb {
+    fn from(metrics: DecentralizationMetrics) -> Self {
+        Self {
+            epoch: metrics.epoch as i32,
+            consensus_validators: metrics.consensus_validators as i32,
+            total_voting_power: metrics.total_voting_power,
+            nakamoto_coefficient_one_third: metrics
+                .nakamoto_coefficient_one_third
+                as i32,
+            nakamoto_coefficient_two_thirds: metrics
+                .nakamoto_coefficient_two_thirds
+                as i32,
+            gini_coefficient: metrics.gini_coefficient,
+            top_n: metrics.top_n as i32,
+            top_n_share: metrics.top_n_share,
+        }
+    }
+}
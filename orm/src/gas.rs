@@ -2,9 +2,12 @@ use std::str::FromStr;
 
 use bigdecimal::BigDecimal;
 use diesel::{Insertable, Queryable, Selectable};
-use shared::gas::{GasEstimation, GasPrice};
+use shared::gas::{FeeRollup, GasEstimation, GasPrice};
 
-use crate::schema::{gas_estimations, gas_price};
+use crate::schema::{
+    fee_rollups, gas_estimations, gas_price, gas_price_history,
+};
+use crate::transactions::TransactionKindDb;
 
 #[derive(Clone, Queryable, Selectable, Insertable)]
 #[diesel(table_name = gas_price)]
@@ -24,6 +27,27 @@ impl From<GasPrice> for GasPriceDb {
     }
 }
 
+#[derive(Clone, Queryable, Selectable)]
+#[diesel(table_name = gas_price_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GasPriceHistoryDb {
+    pub id: i32,
+    pub token: String,
+    pub amount: BigDecimal,
+    pub epoch: i32,
+    pub timestamp: chrono::NaiveDateTime,
+}
+
+#[derive(Clone, Insertable)]
+#[diesel(table_name = gas_price_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GasPriceHistoryInsertDb {
+    pub token: String,
+    pub amount: BigDecimal,
+    pub epoch: i32,
+    pub timestamp: chrono::NaiveDateTime,
+}
+
 #[derive(Clone, Queryable, Selectable, Insertable)]
 #[diesel(table_name = gas_estimations)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
@@ -72,3 +96,34 @@ impl From<GasEstimation> for GasEstimationInsertDb {
         }
     }
 }
+
+#[derive(Clone, Insertable)]
+#[diesel(table_name = fee_rollups)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct FeeRollupInsertDb {
+    pub token: String,
+    pub fee_payer: String,
+    pub kind: TransactionKindDb,
+    pub epoch: i32,
+    pub hour: chrono::NaiveDateTime,
+    pub amount: BigDecimal,
+    pub transactions: i32,
+}
+
+impl From<FeeRollup> for FeeRollupInsertDb {
+    fn from(value: FeeRollup) -> Self {
+        let hour = value.timestamp - value.timestamp.rem_euclid(3600);
+
+        Self {
+            token: value.token.to_string(),
+            fee_payer: value.fee_payer.to_string(),
+            kind: TransactionKindDb::from(value.kind),
+            epoch: value.epoch as i32,
+            hour: chrono::DateTime::from_timestamp(hour, 0)
+                .expect("Invalid timestamp")
+                .naive_utc(),
+            amount: BigDecimal::from(value.amount),
+            transactions: value.transactions as i32,
+        }
+    }
+}
@@ -109,6 +109,17 @@ pub struct GovernanceProposalDb {
     pub nay_votes: String,
     pub abstain_votes: String,
     pub result: GovernanceProposalResultDb,
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    #[diesel(column_name = abstract_)]
+    pub r#abstract: Option<String>,
+    pub motivation: Option<String>,
+    pub details: Option<String>,
+    pub requires: Option<String>,
+    pub created: Option<String>,
+    pub code_hash: Option<String>,
+    pub code_size: Option<i32>,
 }
 
 #[derive(Serialize, Insertable, Clone)]
@@ -124,6 +135,15 @@ pub struct GovernanceProposalInsertDb {
     pub start_epoch: i32,
     pub end_epoch: i32,
     pub activation_epoch: i32,
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    #[diesel(column_name = abstract_)]
+    pub r#abstract: Option<String>,
+    pub motivation: Option<String>,
+    pub details: Option<String>,
+    pub requires: Option<String>,
+    pub created: Option<String>,
 }
 
 impl GovernanceProposalInsertDb {
@@ -141,6 +161,14 @@ impl GovernanceProposalInsertDb {
             start_epoch: proposal.voting_start_epoch as i32,
             end_epoch: proposal.voting_end_epoch as i32,
             activation_epoch: proposal.activation_epoch as i32,
+            title: proposal.structured_content.title,
+            authors: proposal.structured_content.authors,
+            discussions_to: proposal.structured_content.discussions_to,
+            r#abstract: proposal.structured_content.r#abstract,
+            motivation: proposal.structured_content.motivation,
+            details: proposal.structured_content.details,
+            requires: proposal.structured_content.requires,
+            created: proposal.structured_content.created,
         }
     }
 }
@@ -1,8 +1,12 @@
-use diesel::{Insertable, Queryable, Selectable};
+use bigdecimal::BigDecimal;
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
-use shared::vote::{GovernanceVote, ProposalVoteKind};
+use shared::block::Epoch;
+use shared::vote::{
+    GovernanceVote, GovernanceVoteCast, GovernanceVotePower, ProposalVoteKind,
+};
 
-use crate::schema::governance_votes;
+use crate::schema::{governance_vote_history, governance_votes};
 
 #[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::VoteKind"]
@@ -24,6 +28,17 @@ impl From<ProposalVoteKind> for GovernanceVoteKindDb {
     }
 }
 
+impl From<GovernanceVoteKindDb> for ProposalVoteKind {
+    fn from(value: GovernanceVoteKindDb) -> Self {
+        match value {
+            GovernanceVoteKindDb::Nay => Self::Nay,
+            GovernanceVoteKindDb::Yay => Self::Yay,
+            GovernanceVoteKindDb::Abstain => Self::Abstain,
+            GovernanceVoteKindDb::Unknown => Self::Unknown,
+        }
+    }
+}
+
 #[derive(Serialize, Queryable, Selectable, Clone)]
 #[diesel(table_name = governance_votes)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
@@ -32,6 +47,9 @@ pub struct GovernanceProposalVoteDb {
     pub voter_address: String,
     pub kind: GovernanceVoteKindDb,
     pub proposal_id: i32,
+    pub voting_power: Option<BigDecimal>,
+    pub is_validator: Option<bool>,
+    pub voting_power_epoch: Option<i32>,
 }
 
 #[derive(Serialize, Insertable, Clone)]
@@ -52,3 +70,60 @@ impl GovernanceProposalVoteInsertDb {
         }
     }
 }
+
+#[derive(Serialize, Queryable, Selectable, Clone)]
+#[diesel(table_name = governance_vote_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GovernanceVoteHistoryDb {
+    pub id: i32,
+    pub proposal_id: i32,
+    pub voter_address: String,
+    pub kind: GovernanceVoteKindDb,
+    pub block_height: i32,
+    pub inner_tx_id: String,
+}
+
+#[derive(Serialize, Insertable, Clone)]
+#[diesel(table_name = governance_vote_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GovernanceVoteHistoryInsertDb {
+    pub proposal_id: i32,
+    pub voter_address: String,
+    pub kind: GovernanceVoteKindDb,
+    pub block_height: i32,
+    pub inner_tx_id: String,
+}
+
+impl From<GovernanceVoteCast> for GovernanceVoteHistoryInsertDb {
+    fn from(vote: GovernanceVoteCast) -> Self {
+        Self {
+            proposal_id: vote.proposal_id as i32,
+            voter_address: vote.address.to_string(),
+            kind: vote.vote.into(),
+            block_height: vote.block_height as i32,
+            inner_tx_id: vote.inner_tx_id.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, AsChangeset, Clone)]
+#[diesel(table_name = governance_votes)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GovernanceProposalVotePowerUpdateDb {
+    pub voting_power: BigDecimal,
+    pub is_validator: bool,
+    pub voting_power_epoch: i32,
+}
+
+impl GovernanceProposalVotePowerUpdateDb {
+    pub fn from_vote_power(
+        vote_power: GovernanceVotePower,
+        epoch: Epoch,
+    ) -> Self {
+        Self {
+            voting_power: BigDecimal::from(vote_power.voting_power),
+            is_validator: vote_power.is_validator,
+            voting_power_epoch: epoch as i32,
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use diesel::allow_columns_to_appear_in_same_group_by_clause;
 use diesel::expression::{SqlLiteral, ValidGrouping};
 
-use crate::schema::{bonds, redelegation, unbonds, validators};
+use crate::schema::{
+    blocks, bonds, fee_rollups, ibc_packets, masp_pool, redelegation, unbonds,
+    validators,
+};
 
 // For find_merged_bonds_by_address
 allow_columns_to_appear_in_same_group_by_clause!(
@@ -21,6 +24,12 @@ allow_columns_to_appear_in_same_group_by_clause!(
     validators::state,
 );
 
+// For find_masp_net_flows_per_epoch
+allow_columns_to_appear_in_same_group_by_clause!(
+    masp_pool::token_address,
+    blocks::epoch,
+);
+
 macro_rules! impl_valid_grouping {
     ($valid_grouping_type:ty, $column:path) => {
         impl ValidGrouping<$valid_grouping_type> for $column {
@@ -57,3 +66,29 @@ impl_valid_grouping!(
     validators::avatar,
     validators::state
 );
+
+// For get_ibc_channels_transfer_volume
+impl_valid_grouping!(
+    (SqlLiteral<diesel::sql_types::Text>, ibc_packets::denom),
+    ibc_packets::denom
+);
+
+// For find_masp_flows
+impl_valid_grouping!(
+    (
+        masp_pool::token_address,
+        SqlLiteral<diesel::sql_types::BigInt>
+    ),
+    masp_pool::token_address
+);
+
+// For find_fees
+impl_valid_grouping!(
+    (
+        fee_rollups::token,
+        SqlLiteral<diesel::sql_types::BigInt>,
+        fee_rollups::kind
+    ),
+    fee_rollups::token,
+    fee_rollups::kind
+);
@@ -2,10 +2,19 @@ use bigdecimal::BigDecimal;
 use diesel::prelude::Queryable;
 use diesel::{AsChangeset, Insertable, Selectable};
 use serde::{Deserialize, Serialize};
+use shared::block::BlockHeight;
+use shared::block_result::IbcCorePacketKind;
+use shared::ibc::{
+    IbcChannel, IbcChannelState, IbcClient, IbcClientEventKind, IbcConnection,
+    IbcConnectionState,
+};
 use shared::token::IbcRateLimit;
-use shared::transaction::{IbcAckStatus, IbcSequence};
+use shared::transaction::{IbcAckStatus, IbcPacketEvent, IbcSequence};
 
-use crate::schema::{ibc_ack, ibc_rate_limits, ibc_token_flows};
+use crate::schema::{
+    ibc_ack, ibc_channels, ibc_clients, ibc_connections, ibc_packets,
+    ibc_rate_limits, ibc_token_flows,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::IbcStatus"]
@@ -57,6 +66,233 @@ pub struct IbcSequencekStatusUpdateDb {
     pub status: IbcAckStatusDb,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcPacketDirection"]
+pub enum IbcPacketDirectionDb {
+    Outgoing,
+    Incoming,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcPacketStatus"]
+pub enum IbcPacketStatusDb {
+    Sent,
+    Received,
+    Acknowledged,
+    TimedOut,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_packets)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcPacketDb {
+    pub id: String,
+    pub direction: IbcPacketDirectionDb,
+    pub status: IbcPacketStatusDb,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: i64,
+    pub denom: Option<String>,
+    pub amount: Option<BigDecimal>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub timeout_height: String,
+    pub timeout_timestamp: i64,
+    pub sent_at_height: Option<i32>,
+    pub received_at_height: Option<i32>,
+    pub acknowledged_at_height: Option<i32>,
+    pub timed_out_at_height: Option<i32>,
+}
+
+pub type IbcPacketInsertDb = IbcPacketDb;
+
+impl From<IbcPacketEvent> for IbcPacketInsertDb {
+    fn from(value: IbcPacketEvent) -> Self {
+        let id = value.id();
+        let height = Some(value.block_height as i32);
+        let transfer = value.transfer.as_ref();
+
+        let mut packet = Self {
+            id,
+            direction: IbcPacketDirectionDb::Outgoing,
+            status: IbcPacketStatusDb::Sent,
+            source_port: value.source_port.clone(),
+            source_channel: value.source_channel.clone(),
+            dest_port: value.dest_port.clone(),
+            dest_channel: value.dest_channel.clone(),
+            sequence: value.sequence as i64,
+            denom: transfer.map(|transfer| transfer.denom.clone()),
+            amount: transfer.map(|transfer| transfer.amount.clone()),
+            sender: transfer.map(|transfer| transfer.sender.clone()),
+            receiver: transfer.map(|transfer| transfer.receiver.clone()),
+            memo: transfer.map(|transfer| transfer.memo.clone()),
+            timeout_height: value.timeout_height.clone(),
+            timeout_timestamp: value.timeout_timestamp as i64,
+            sent_at_height: None,
+            received_at_height: None,
+            acknowledged_at_height: None,
+            timed_out_at_height: None,
+        };
+
+        match value.kind {
+            IbcCorePacketKind::Send => {
+                packet.sent_at_height = height;
+            }
+            IbcCorePacketKind::Recv => {
+                packet.direction = IbcPacketDirectionDb::Incoming;
+                packet.status = IbcPacketStatusDb::Received;
+                packet.received_at_height = height;
+            }
+            IbcCorePacketKind::Ack => {
+                packet.status = IbcPacketStatusDb::Acknowledged;
+                packet.acknowledged_at_height = height;
+            }
+            IbcCorePacketKind::Timeout => {
+                packet.status = IbcPacketStatusDb::TimedOut;
+                packet.timed_out_at_height = height;
+            }
+        }
+
+        packet
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_clients)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcClientDb {
+    pub client_id: String,
+    pub client_type: String,
+    pub counterparty_chain_id: Option<String>,
+    pub latest_height: String,
+    pub created_at_height: Option<i32>,
+    pub updated_at_height: i32,
+}
+
+pub type IbcClientInsertDb = IbcClientDb;
+
+impl IbcClientInsertDb {
+    pub fn from_ibc_client(
+        client: IbcClient,
+        block_height: BlockHeight,
+    ) -> Self {
+        Self {
+            created_at_height: match client.kind {
+                IbcClientEventKind::Create => Some(block_height as i32),
+                IbcClientEventKind::Update => None,
+            },
+            client_id: client.client_id,
+            client_type: client.client_type,
+            counterparty_chain_id: client.counterparty_chain_id,
+            latest_height: client.consensus_height,
+            updated_at_height: block_height as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcConnectionState"]
+pub enum IbcConnectionStateDb {
+    Init,
+    TryOpen,
+    Open,
+}
+
+impl From<IbcConnectionState> for IbcConnectionStateDb {
+    fn from(value: IbcConnectionState) -> Self {
+        match value {
+            IbcConnectionState::Init => Self::Init,
+            IbcConnectionState::TryOpen => Self::TryOpen,
+            IbcConnectionState::Open => Self::Open,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_connections)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcConnectionDb {
+    pub connection_id: String,
+    pub client_id: String,
+    pub counterparty_client_id: String,
+    pub counterparty_connection_id: Option<String>,
+    pub state: IbcConnectionStateDb,
+    pub updated_at_height: i32,
+}
+
+pub type IbcConnectionInsertDb = IbcConnectionDb;
+
+impl IbcConnectionInsertDb {
+    pub fn from_ibc_connection(
+        connection: IbcConnection,
+        block_height: BlockHeight,
+    ) -> Self {
+        Self {
+            connection_id: connection.connection_id,
+            client_id: connection.client_id,
+            counterparty_client_id: connection.counterparty_client_id,
+            counterparty_connection_id: connection.counterparty_connection_id,
+            state: connection.state.into(),
+            updated_at_height: block_height as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcChannelState"]
+pub enum IbcChannelStateDb {
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<IbcChannelState> for IbcChannelStateDb {
+    fn from(value: IbcChannelState) -> Self {
+        match value {
+            IbcChannelState::Init => Self::Init,
+            IbcChannelState::TryOpen => Self::TryOpen,
+            IbcChannelState::Open => Self::Open,
+            IbcChannelState::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_channels)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcChannelDb {
+    pub channel_id: String,
+    pub port_id: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: Option<String>,
+    pub connection_id: String,
+    pub state: IbcChannelStateDb,
+    pub updated_at_height: i32,
+}
+
+pub type IbcChannelInsertDb = IbcChannelDb;
+
+impl IbcChannelInsertDb {
+    pub fn from_ibc_channel(
+        channel: IbcChannel,
+        block_height: BlockHeight,
+    ) -> Self {
+        Self {
+            channel_id: channel.channel_id,
+            port_id: channel.port_id,
+            counterparty_port_id: channel.counterparty_port_id,
+            counterparty_channel_id: channel.counterparty_channel_id,
+            connection_id: channel.connection_id,
+            state: channel.state.into(),
+            updated_at_height: block_height as i32,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Queryable, Selectable)]
 #[diesel(table_name = ibc_rate_limits)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
@@ -2,6 +2,7 @@ pub mod balances;
 pub mod blocks;
 pub mod bond;
 pub mod crawler_state;
+pub mod decentralization;
 pub mod gas;
 pub mod governance_proposal;
 pub mod governance_votes;
@@ -17,8 +18,10 @@ pub mod redelegation;
 pub mod revealed_pk;
 pub mod schema;
 pub mod token;
+pub mod token_holders_per_epoch;
 pub mod token_supplies_per_epoch;
 pub mod transactions;
 pub mod unbond;
+pub mod validator_events;
 pub mod validators;
 pub mod views;
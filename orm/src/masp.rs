@@ -2,9 +2,9 @@ use std::str::FromStr;
 
 use bigdecimal::BigDecimal;
 use diesel::{Insertable, Queryable, Selectable};
-use shared::masp::{MaspEntry, MaspEntryDirection};
+use shared::masp::{MaspEntry, MaspEntryDirection, MaspReward};
 
-use crate::schema::{masp_pool, masp_pool_aggregate};
+use crate::schema::{masp_pool, masp_rewards};
 
 #[derive(Debug, Clone, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::MaspPoolDirection"]
@@ -26,33 +26,6 @@ pub struct MaspDb {
 
 pub type MaspInsertDb = MaspDb;
 
-#[derive(Debug, Clone, diesel_derive_enum::DbEnum)]
-#[ExistingTypePath = "crate::schema::sql_types::MaspPoolAggregateWindow"]
-pub enum MaspPoolAggregateWindowDb {
-    OneDay,
-    SevenDays,
-    ThirtyDays,
-    AllTime,
-}
-
-#[derive(Debug, Clone, diesel_derive_enum::DbEnum)]
-#[ExistingTypePath = "crate::schema::sql_types::MaspPoolAggregateKind"]
-pub enum MaspPoolAggregateKindDb {
-    Inflows,
-    Outflows,
-}
-
-#[derive(Insertable, Queryable, Selectable, Clone, Debug)]
-#[diesel(table_name = masp_pool_aggregate)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
-pub struct MaspPoolDb {
-    pub id: i32,
-    pub token_address: String,
-    pub time_window: MaspPoolAggregateWindowDb,
-    pub kind: MaspPoolAggregateKindDb,
-    pub total_amount: BigDecimal,
-}
-
 impl From<MaspEntry> for MaspInsertDb {
     fn from(value: MaspEntry) -> Self {
         let timestamp = chrono::DateTime::from_timestamp(value.timestamp, 0)
@@ -74,3 +47,63 @@ impl From<MaspEntry> for MaspInsertDb {
         }
     }
 }
+
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = masp_rewards)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MaspRewardDb {
+    pub id: i32,
+    pub token: String,
+    pub epoch: i32,
+    pub max_reward_rate: BigDecimal,
+    pub kp_gain: BigDecimal,
+    pub kd_gain: BigDecimal,
+    pub locked_amount_target: BigDecimal,
+    pub last_inflation: BigDecimal,
+    pub last_locked_amount: BigDecimal,
+    pub reward_rate: BigDecimal,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = masp_rewards)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MaspRewardInsertDb {
+    pub token: String,
+    pub epoch: i32,
+    pub max_reward_rate: BigDecimal,
+    pub kp_gain: BigDecimal,
+    pub kd_gain: BigDecimal,
+    pub locked_amount_target: BigDecimal,
+    pub last_inflation: BigDecimal,
+    pub last_locked_amount: BigDecimal,
+    pub reward_rate: BigDecimal,
+}
+
+impl From<MaspReward> for MaspRewardInsertDb {
+    fn from(value: MaspReward) -> Self {
+        MaspRewardInsertDb {
+            token: value.token_address,
+            epoch: value.epoch as i32,
+            max_reward_rate: BigDecimal::from_str(&value.max_reward_rate)
+                .expect("Invalid max reward rate"),
+            kp_gain: BigDecimal::from_str(&value.kp_gain)
+                .expect("Invalid kp gain"),
+            kd_gain: BigDecimal::from_str(&value.kd_gain)
+                .expect("Invalid kd gain"),
+            locked_amount_target: BigDecimal::from_str(
+                &value.locked_amount_target,
+            )
+            .expect("Invalid locked amount target"),
+            last_inflation: BigDecimal::from_str(
+                &value.last_inflation.to_string(),
+            )
+            .expect("Invalid amount"),
+            last_locked_amount: BigDecimal::from_str(
+                &value.last_locked_amount.to_string(),
+            )
+            .expect("Invalid amount"),
+            reward_rate: BigDecimal::from_str(&value.reward_rate)
+                .expect("Invalid reward rate"),
+        }
+    }
+}
@@ -1,11 +1,17 @@
 use std::str::FromStr;
 
 use bigdecimal::BigDecimal;
-use diesel::{Insertable, Queryable};
+use diesel::{Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
-use shared::pgf::{PaymentKind, PaymentRecurrence, PgfPayment};
+use shared::pgf::{
+    PaymentKind, PaymentRecurrence, PgfDisbursement, PgfPayment, PgfSteward,
+    PgfStewardEvent, PgfStewardEventKind,
+};
+use shared::token::Token;
 
-use crate::schema::public_good_funding;
+use crate::schema::{
+    pgf_disbursements, pgf_steward_events, pgf_stewards, public_good_funding,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::PaymentRecurrence"]
@@ -66,3 +72,121 @@ impl PublicGoodFundingPaymentInsertDb {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::PgfStewardEventKind"]
+pub enum PgfStewardEventKindDb {
+    Added,
+    Removed,
+    Resigned,
+    CommissionUpdate,
+}
+
+impl From<PgfStewardEventKind> for PgfStewardEventKindDb {
+    fn from(value: PgfStewardEventKind) -> Self {
+        match value {
+            PgfStewardEventKind::Added => Self::Added,
+            PgfStewardEventKind::Removed => Self::Removed,
+            PgfStewardEventKind::Resigned => Self::Resigned,
+            PgfStewardEventKind::CommissionUpdate => Self::CommissionUpdate,
+        }
+    }
+}
+
+#[derive(Insertable, Clone, Queryable, Selectable, Debug)]
+#[diesel(table_name = pgf_stewards)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PgfStewardDb {
+    pub address: String,
+    pub commission: String,
+}
+
+pub type PgfStewardInsertDb = PgfStewardDb;
+
+impl From<PgfSteward> for PgfStewardInsertDb {
+    fn from(steward: PgfSteward) -> Self {
+        Self {
+            address: steward.address.to_string(),
+            commission: steward.commission,
+        }
+    }
+}
+
+#[derive(Clone, Queryable, Selectable, Debug)]
+#[diesel(table_name = pgf_steward_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PgfStewardEventDb {
+    pub id: i32,
+    pub address: String,
+    pub kind: PgfStewardEventKindDb,
+    pub proposal_id: Option<i32>,
+    pub block_height: Option<i32>,
+    pub inner_tx_id: Option<String>,
+    pub commission: Option<String>,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = pgf_steward_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PgfStewardEventInsertDb {
+    pub address: String,
+    pub kind: PgfStewardEventKindDb,
+    pub proposal_id: Option<i32>,
+    pub block_height: Option<i32>,
+    pub inner_tx_id: Option<String>,
+    pub commission: Option<String>,
+}
+
+impl From<PgfStewardEvent> for PgfStewardEventInsertDb {
+    fn from(event: PgfStewardEvent) -> Self {
+        Self {
+            address: event.address.to_string(),
+            kind: event.kind.into(),
+            proposal_id: event.proposal_id.map(|id| id as i32),
+            block_height: event.block_height.map(|height| height as i32),
+            inner_tx_id: event.inner_tx_id.map(|id| id.to_string()),
+            commission: event.commission,
+        }
+    }
+}
+
+#[derive(Clone, Queryable, Selectable, Debug)]
+#[diesel(table_name = pgf_disbursements)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PgfDisbursementDb {
+    pub id: i32,
+    pub receipient: String,
+    pub token: String,
+    pub epoch: i32,
+    pub block_height: i32,
+    pub amount: BigDecimal,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = pgf_disbursements)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PgfDisbursementInsertDb {
+    pub receipient: String,
+    pub token: String,
+    pub epoch: i32,
+    pub block_height: i32,
+    pub amount: BigDecimal,
+}
+
+impl From<PgfDisbursement> for PgfDisbursementInsertDb {
+    fn from(disbursement: PgfDisbursement) -> Self {
+        let token = match disbursement.token {
+            Token::Native(token) => token.to_string(),
+            Token::Ibc(token) => token.address.to_string(),
+        };
+
+        Self {
+            receipient: disbursement.receipient.to_string(),
+            token,
+            epoch: disbursement.epoch as i32,
+            block_height: disbursement.block_height as i32,
+            amount: BigDecimal::from_str(&disbursement.amount.to_string())
+                .expect("Invalid amount"),
+        }
+    }
+}
@@ -46,24 +46,40 @@ pub mod sql_types {
         std::fmt::Debug,
         diesel::sql_types::SqlType,
     )]
-    #[diesel(postgres_type(name = "ibc_status"))]
-    pub struct IbcStatus;
+    #[diesel(postgres_type(name = "ibc_channel_state"))]
+    pub struct IbcChannelState;
+
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_connection_state"))]
+    pub struct IbcConnectionState;
+
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_packet_direction"))]
+    pub struct IbcPacketDirection;
 
     #[derive(
         diesel::query_builder::QueryId,
         std::fmt::Debug,
         diesel::sql_types::SqlType,
     )]
-    #[diesel(postgres_type(name = "masp_pool_aggregate_kind"))]
-    pub struct MaspPoolAggregateKind;
+    #[diesel(postgres_type(name = "ibc_packet_status"))]
+    pub struct IbcPacketStatus;
 
     #[derive(
         diesel::query_builder::QueryId,
         std::fmt::Debug,
         diesel::sql_types::SqlType,
     )]
-    #[diesel(postgres_type(name = "masp_pool_aggregate_window"))]
-    pub struct MaspPoolAggregateWindow;
+    #[diesel(postgres_type(name = "ibc_status"))]
+    pub struct IbcStatus;
 
     #[derive(
         diesel::query_builder::QueryId,
@@ -89,6 +105,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "payment_recurrence"))]
     pub struct PaymentRecurrence;
 
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "pgf_steward_event_kind"))]
+    pub struct PgfStewardEventKind;
+
     #[derive(
         diesel::query_builder::QueryId,
         std::fmt::Debug,
@@ -113,6 +137,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "transaction_result"))]
     pub struct TransactionResult;
 
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "validator_event_kind"))]
+    pub struct ValidatorEventKind;
+
     #[derive(
         diesel::query_builder::QueryId,
         std::fmt::Debug,
@@ -198,6 +230,42 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    decentralization_metrics (id) {
+        id -> Int4,
+        epoch -> Int4,
+        consensus_validators -> Int4,
+        total_voting_power -> Float8,
+        nakamoto_coefficient_one_third -> Int4,
+        nakamoto_coefficient_two_thirds -> Int4,
+        gini_coefficient -> Float8,
+        top_n -> Int4,
+        top_n_share -> Float8,
+    }
+}
+
+diesel::table! {
+    fee_rollup_blocks (height) {
+        height -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::TransactionKind;
+
+    fee_rollups (id) {
+        id -> Int4,
+        token -> Varchar,
+        fee_payer -> Varchar,
+        kind -> TransactionKind,
+        epoch -> Int4,
+        hour -> Timestamp,
+        amount -> Numeric,
+        transactions -> Int4,
+    }
+}
+
 diesel::table! {
     gas_estimations (id) {
         id -> Int4,
@@ -229,6 +297,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    gas_price_history (id) {
+        id -> Int4,
+        token -> Varchar,
+        amount -> Numeric,
+        epoch -> Int4,
+        timestamp -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::GovernanceKind;
@@ -249,6 +327,33 @@ diesel::table! {
         yay_votes -> Varchar,
         nay_votes -> Varchar,
         abstain_votes -> Varchar,
+        title -> Nullable<Varchar>,
+        authors -> Nullable<Varchar>,
+        discussions_to -> Nullable<Varchar>,
+        #[sql_name = "abstract"]
+        abstract_ -> Nullable<Varchar>,
+        motivation -> Nullable<Varchar>,
+        details -> Nullable<Varchar>,
+        requires -> Nullable<Varchar>,
+        created -> Nullable<Varchar>,
+        #[max_length = 64]
+        code_hash -> Nullable<Varchar>,
+        code_size -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::VoteKind;
+
+    governance_vote_history (id) {
+        id -> Int4,
+        proposal_id -> Int4,
+        voter_address -> Varchar,
+        kind -> VoteKind,
+        block_height -> Int4,
+        #[max_length = 64]
+        inner_tx_id -> Varchar,
     }
 }
 
@@ -261,6 +366,9 @@ diesel::table! {
         kind -> VoteKind,
         voter_address -> Varchar,
         proposal_id -> Int4,
+        voting_power -> Nullable<Numeric>,
+        is_validator -> Nullable<Bool>,
+        voting_power_epoch -> Nullable<Int4>,
     }
 }
 
@@ -276,6 +384,74 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcChannelState;
+
+    ibc_channels (channel_id) {
+        channel_id -> Varchar,
+        port_id -> Varchar,
+        counterparty_port_id -> Varchar,
+        counterparty_channel_id -> Nullable<Varchar>,
+        connection_id -> Varchar,
+        state -> IbcChannelState,
+        updated_at_height -> Int4,
+    }
+}
+
+diesel::table! {
+    ibc_clients (client_id) {
+        client_id -> Varchar,
+        client_type -> Varchar,
+        counterparty_chain_id -> Nullable<Varchar>,
+        latest_height -> Varchar,
+        created_at_height -> Nullable<Int4>,
+        updated_at_height -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcConnectionState;
+
+    ibc_connections (connection_id) {
+        connection_id -> Varchar,
+        client_id -> Varchar,
+        counterparty_client_id -> Varchar,
+        counterparty_connection_id -> Nullable<Varchar>,
+        state -> IbcConnectionState,
+        updated_at_height -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcPacketDirection;
+    use super::sql_types::IbcPacketStatus;
+
+    ibc_packets (id) {
+        id -> Varchar,
+        direction -> IbcPacketDirection,
+        status -> IbcPacketStatus,
+        source_port -> Varchar,
+        source_channel -> Varchar,
+        dest_port -> Varchar,
+        dest_channel -> Varchar,
+        sequence -> Int8,
+        denom -> Nullable<Varchar>,
+        amount -> Nullable<Numeric>,
+        sender -> Nullable<Varchar>,
+        receiver -> Nullable<Varchar>,
+        memo -> Nullable<Varchar>,
+        timeout_height -> Varchar,
+        timeout_timestamp -> Int8,
+        sent_at_height -> Nullable<Int4>,
+        received_at_height -> Nullable<Int4>,
+        acknowledged_at_height -> Nullable<Int4>,
+        timed_out_at_height -> Nullable<Int4>,
+    }
+}
+
 diesel::table! {
     ibc_rate_limits (id) {
         id -> Int4,
@@ -319,6 +495,7 @@ diesel::table! {
         data -> Nullable<Varchar>,
         memo -> Nullable<Varchar>,
         exit_code -> TransactionResult,
+        notes -> Int4,
     }
 }
 
@@ -338,18 +515,54 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    masp_rewards (id) {
+        id -> Int4,
+        #[max_length = 45]
+        token -> Varchar,
+        epoch -> Int4,
+        max_reward_rate -> Numeric,
+        kp_gain -> Numeric,
+        kd_gain -> Numeric,
+        locked_amount_target -> Numeric,
+        last_inflation -> Numeric,
+        last_locked_amount -> Numeric,
+        reward_rate -> Numeric,
+    }
+}
+
+diesel::table! {
+    pgf_disbursements (id) {
+        id -> Int4,
+        receipient -> Varchar,
+        #[max_length = 64]
+        token -> Varchar,
+        epoch -> Int4,
+        block_height -> Int4,
+        amount -> Numeric,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
-    use super::sql_types::MaspPoolAggregateWindow;
-    use super::sql_types::MaspPoolAggregateKind;
+    use super::sql_types::PgfStewardEventKind;
 
-    masp_pool_aggregate (id) {
+    pgf_steward_events (id) {
         id -> Int4,
-        #[max_length = 45]
-        token_address -> Varchar,
-        time_window -> MaspPoolAggregateWindow,
-        kind -> MaspPoolAggregateKind,
-        total_amount -> Numeric,
+        address -> Varchar,
+        kind -> PgfStewardEventKind,
+        proposal_id -> Nullable<Int4>,
+        block_height -> Nullable<Int4>,
+        #[max_length = 64]
+        inner_tx_id -> Nullable<Varchar>,
+        commission -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    pgf_stewards (address) {
+        address -> Varchar,
+        commission -> Varchar,
     }
 }
 
@@ -407,6 +620,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    token_holders_per_epoch (id) {
+        id -> Int4,
+        #[max_length = 45]
+        address -> Varchar,
+        epoch -> Int4,
+        holders -> Int4,
+    }
+}
+
+diesel::table! {
+    token_metadata (address) {
+        #[max_length = 45]
+        address -> Varchar,
+        symbol -> Varchar,
+        decimals -> Int4,
+        name -> Nullable<Varchar>,
+        logo_url -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     token_supplies_per_epoch (id) {
         id -> Int4,
@@ -415,6 +649,7 @@ diesel::table! {
         epoch -> Int4,
         total -> Numeric,
         effective -> Nullable<Numeric>,
+        bonded -> Nullable<Numeric>,
     }
 }
 
@@ -441,6 +676,46 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ValidatorEventKind;
+
+    validator_events (id) {
+        id -> Int4,
+        address -> Varchar,
+        kind -> ValidatorEventKind,
+        block_height -> Int4,
+        #[max_length = 64]
+        inner_tx_id -> Varchar,
+        data -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ValidatorState;
+
+    validator_set_changes (id) {
+        id -> Int4,
+        address -> Varchar,
+        epoch -> Int4,
+        previous_state -> Nullable<ValidatorState>,
+        current_state -> Nullable<ValidatorState>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ValidatorState;
+
+    validator_states_per_epoch (id) {
+        id -> Int4,
+        address -> Varchar,
+        epoch -> Int4,
+        state -> ValidatorState,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::ValidatorState;
@@ -484,18 +759,25 @@ diesel::joinable!(balance_changes -> blocks (height));
 diesel::joinable!(balance_changes -> token (token));
 diesel::joinable!(bonds -> validators (validator_id));
 diesel::joinable!(gas_estimations -> wrapper_transactions (wrapper_id));
+diesel::joinable!(governance_vote_history -> blocks (block_height));
+diesel::joinable!(governance_vote_history -> governance_proposals (proposal_id));
 diesel::joinable!(governance_votes -> governance_proposals (proposal_id));
 diesel::joinable!(ibc_rate_limits -> token (address));
 diesel::joinable!(ibc_token -> token (address));
 diesel::joinable!(ibc_token_flows -> token (address));
 diesel::joinable!(inner_transactions -> wrapper_transactions (wrapper_id));
 diesel::joinable!(masp_pool -> inner_transactions (inner_tx_id));
+diesel::joinable!(pgf_disbursements -> blocks (block_height));
+diesel::joinable!(pgf_steward_events -> blocks (block_height));
+diesel::joinable!(pgf_steward_events -> governance_proposals (proposal_id));
 diesel::joinable!(pos_rewards -> validators (validator_id));
 diesel::joinable!(public_good_funding -> governance_proposals (proposal_id));
 diesel::joinable!(redelegation -> validators (validator_id));
+diesel::joinable!(token_holders_per_epoch -> token (address));
 diesel::joinable!(token_supplies_per_epoch -> token (address));
 diesel::joinable!(transaction_history -> inner_transactions (inner_tx_id));
 diesel::joinable!(unbonds -> validators (validator_id));
+diesel::joinable!(validator_events -> blocks (block_height));
 diesel::joinable!(wrapper_transactions -> blocks (block_height));
 
 diesel::allow_tables_to_appear_in_same_query!(
@@ -504,25 +786,42 @@ diesel::allow_tables_to_appear_in_same_query!(
     bonds,
     chain_parameters,
     crawler_state,
+    decentralization_metrics,
+    fee_rollup_blocks,
+    fee_rollups,
     gas_estimations,
     gas_price,
+    gas_price_history,
     governance_proposals,
+    governance_vote_history,
     governance_votes,
     ibc_ack,
+    ibc_channels,
+    ibc_clients,
+    ibc_connections,
+    ibc_packets,
     ibc_rate_limits,
     ibc_token,
     ibc_token_flows,
     inner_transactions,
     masp_pool,
-    masp_pool_aggregate,
+    masp_rewards,
+    pgf_disbursements,
+    pgf_steward_events,
+    pgf_stewards,
     pos_rewards,
     public_good_funding,
     redelegation,
     revealed_pk,
     token,
+    token_holders_per_epoch,
+    token_metadata,
     token_supplies_per_epoch,
     transaction_history,
     unbonds,
+    validator_events,
+    validator_set_changes,
+    validator_states_per_epoch,
     validators,
     wrapper_transactions,
 );
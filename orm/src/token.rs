@@ -1,8 +1,8 @@
 use diesel::{Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
-use shared::token::Token;
+use shared::token::{Token, TokenMetadata};
 
-use crate::schema::{ibc_token, token};
+use crate::schema::{ibc_token, token, token_metadata};
 
 #[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::TokenType"]
@@ -57,3 +57,44 @@ impl IbcTokenDb {
         }
     }
 }
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = token_metadata)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TokenMetadataDb {
+    pub address: String,
+    pub symbol: String,
+    pub decimals: i32,
+    pub name: Option<String>,
+    pub logo_url: Option<String>,
+}
+
+pub type TokenMetadataInsertDb = TokenMetadataDb;
+
+/// A token joined with its IBC trace and registry metadata, if any
+pub type TokenWithMetadataDb =
+    (TokenDb, Option<IbcTokenDb>, Option<TokenMetadataDb>);
+
+impl From<TokenMetadata> for TokenMetadataInsertDb {
+    fn from(value: TokenMetadata) -> Self {
+        Self {
+            address: value.address,
+            symbol: value.symbol,
+            decimals: value.decimals as i32,
+            name: value.name,
+            logo_url: value.logo_url,
+        }
+    }
+}
+
+impl From<TokenMetadataDb> for TokenMetadata {
+    fn from(value: TokenMetadataDb) -> Self {
+        Self {
+            address: value.address,
+            symbol: value.symbol,
+            decimals: value.decimals as u32,
+            name: value.name,
+            logo_url: value.logo_url,
+        }
+    }
+}
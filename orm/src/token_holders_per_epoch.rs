@@ -0,0 +1,22 @@
+use diesel::{Insertable, Queryable, Selectable};
+
+use crate::schema::token_holders_per_epoch;
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = token_holders_per_epoch)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TokenHoldersDb {
+    pub id: i32,
+    pub address: String,
+    pub epoch: i32,
+    pub holders: i32,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = token_holders_per_epoch)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TokenHoldersInsertDb {
+    pub address: String,
+    pub epoch: i32,
+    pub holders: i32,
+}
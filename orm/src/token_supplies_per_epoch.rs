@@ -13,6 +13,7 @@ pub struct TokenSuppliesDb {
     pub epoch: i32,
     pub total: BigDecimal,
     pub effective: Option<BigDecimal>,
+    pub bonded: Option<BigDecimal>,
 }
 
 #[derive(Debug, Clone, Queryable, Selectable, Insertable)]
@@ -23,6 +24,7 @@ pub struct TokenSuppliesInsertDb {
     pub epoch: i32,
     pub total: BigDecimal,
     pub effective: Option<BigDecimal>,
+    pub bonded: Option<BigDecimal>,
 }
 
 impl From<SharedTokenSupply> for TokenSuppliesInsertDb {
@@ -32,6 +34,7 @@ impl From<SharedTokenSupply> for TokenSuppliesInsertDb {
             epoch,
             total,
             effective,
+            bonded,
         } = supply;
 
         Self {
@@ -39,6 +42,7 @@ impl From<SharedTokenSupply> for TokenSuppliesInsertDb {
             epoch,
             total,
             effective,
+            bonded,
         }
     }
 }
@@ -37,6 +37,8 @@ pub enum TransactionKindDb {
     UnjailValidator,
     ChangeConsensusKey,
     InitAccount,
+    UpdateStewardCommission,
+    ResignSteward,
     Unknown,
 }
 
@@ -52,6 +54,10 @@ impl From<TransactionKind> for TransactionKindDb {
             }
             TransactionKind::InitAccount(_) => Self::InitAccount,
             TransactionKind::ChangeConsensusKey(_) => Self::ChangeConsensusKey,
+            TransactionKind::UpdateStewardCommission(_) => {
+                Self::UpdateStewardCommission
+            }
+            TransactionKind::ResignSteward(_) => Self::ResignSteward,
             TransactionKind::ShieldingTransfer(_) => Self::ShieldingTransfer,
             TransactionKind::MixedTransfer(_) => Self::MixedTransfer,
             TransactionKind::IbcMsg(_) => Self::IbcMsgTransfer,
@@ -113,6 +119,7 @@ pub struct InnerTransactionInsertDb {
     pub data: Option<String>,
     pub memo: Option<String>,
     pub exit_code: TransactionResultDb,
+    pub notes: i32,
 }
 
 pub type InnerTransactionDb = InnerTransactionInsertDb;
@@ -126,6 +133,7 @@ impl InnerTransactionInsertDb {
             data: tx.data,
             memo: tx.memo,
             exit_code: TransactionResultDb::from(tx.exit_code),
+            notes: tx.notes as i32,
         }
     }
 }
@@ -0,0 +1,66 @@
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use shared::validator::{ValidatorEvent, ValidatorEventKind};
+
+use crate::schema::validator_events;
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::ValidatorEventKind"]
+pub enum ValidatorEventKindDb {
+    BecomeValidator,
+    Deactivation,
+    Reactivation,
+    Unjail,
+    MetadataChange,
+    CommissionChange,
+    ConsensusKeyChange,
+}
+
+impl From<ValidatorEventKind> for ValidatorEventKindDb {
+    fn from(value: ValidatorEventKind) -> Self {
+        match value {
+            ValidatorEventKind::BecomeValidator => Self::BecomeValidator,
+            ValidatorEventKind::Deactivation => Self::Deactivation,
+            ValidatorEventKind::Reactivation => Self::Reactivation,
+            ValidatorEventKind::Unjail => Self::Unjail,
+            ValidatorEventKind::MetadataChange => Self::MetadataChange,
+            ValidatorEventKind::CommissionChange => Self::CommissionChange,
+            ValidatorEventKind::ConsensusKeyChange => Self::ConsensusKeyChange,
+        }
+    }
+}
+
+#[derive(Serialize, Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = validator_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorEventDb {
+    pub id: i32,
+    pub address: String,
+    pub kind: ValidatorEventKindDb,
+    pub block_height: i32,
+    pub inner_tx_id: String,
+    pub data: Option<String>,
+}
+
+#[derive(Serialize, Insertable, Clone, Debug)]
+#[diesel(table_name = validator_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorEventInsertDb {
+    pub address: String,
+    pub kind: ValidatorEventKindDb,
+    pub block_height: i32,
+    pub inner_tx_id: String,
+    pub data: Option<String>,
+}
+
+impl From<ValidatorEvent> for ValidatorEventInsertDb {
+    fn from(event: ValidatorEvent) -> Self {
+        Self {
+            address: event.address.to_string(),
+            kind: event.kind.into(),
+            block_height: event.block_height as i32,
+            inner_tx_id: event.inner_tx_id.to_string(),
+            data: event.data,
+        }
+    }
+}
@@ -7,10 +7,14 @@ use diesel::{
     Selectable,
 };
 use serde::{Deserialize, Serialize};
-use shared::validator::{Validator, ValidatorState};
+use shared::validator::{
+    Validator, ValidatorSet, ValidatorSetChange, ValidatorState,
+};
 
 use crate::helpers::OrderByDb;
-use crate::schema::validators;
+use crate::schema::{
+    validator_set_changes, validator_states_per_epoch, validators,
+};
 use crate::{asc_desc, rev_asc_desc};
 
 #[derive(Debug)]
@@ -50,6 +54,22 @@ impl From<ValidatorState> for ValidatorStateDb {
     }
 }
 
+impl From<ValidatorStateDb> for ValidatorState {
+    fn from(value: ValidatorStateDb) -> Self {
+        match value {
+            ValidatorStateDb::Consensus => Self::Consensus,
+            ValidatorStateDb::BelowCapacity => Self::BelowCapacity,
+            ValidatorStateDb::BelowThreshold => Self::BelowThreshold,
+            ValidatorStateDb::Inactive => Self::Inactive,
+            ValidatorStateDb::Jailed => Self::Jailed,
+            ValidatorStateDb::Deactivating => Self::Deactivating,
+            ValidatorStateDb::Reactivating => Self::Reactivating,
+            ValidatorStateDb::Unjailing => Self::Unjailing,
+            ValidatorStateDb::Unknown => Self::Unknown,
+        }
+    }
+}
+
 #[derive(Serialize, Queryable, Selectable, Clone, Debug)]
 #[diesel(table_name = validators)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
@@ -104,6 +124,61 @@ pub struct ValidatorStateChangeDb {
     pub state: ValidatorStateDb,
 }
 
+#[derive(Serialize, Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = validator_set_changes)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorSetChangeDb {
+    pub id: i32,
+    pub address: String,
+    pub epoch: i32,
+    pub previous_state: Option<ValidatorStateDb>,
+    pub current_state: Option<ValidatorStateDb>,
+}
+
+#[derive(Serialize, Insertable, Clone)]
+#[diesel(table_name = validator_set_changes)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorSetChangeInsertDb {
+    pub address: String,
+    pub epoch: i32,
+    pub previous_state: Option<ValidatorStateDb>,
+    pub current_state: Option<ValidatorStateDb>,
+}
+
+impl From<ValidatorSetChange> for ValidatorSetChangeInsertDb {
+    fn from(change: ValidatorSetChange) -> Self {
+        Self {
+            address: change.address.to_string(),
+            epoch: change.epoch as i32,
+            previous_state: change.previous_state.map(ValidatorStateDb::from),
+            current_state: change.current_state.map(ValidatorStateDb::from),
+        }
+    }
+}
+
+#[derive(Serialize, Insertable, Clone)]
+#[diesel(table_name = validator_states_per_epoch)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorStatePerEpochInsertDb {
+    pub address: String,
+    pub epoch: i32,
+    pub state: ValidatorStateDb,
+}
+
+impl ValidatorStatePerEpochInsertDb {
+    pub fn from_validator_set(validator_set: &ValidatorSet) -> Vec<Self> {
+        validator_set
+            .validators
+            .iter()
+            .map(|validator| Self {
+                address: validator.address.to_string(),
+                epoch: validator_set.epoch as i32,
+                state: validator.state.clone().into(),
+            })
+            .collect()
+    }
+}
+
 #[derive(Serialize, AsChangeset, Clone)]
 #[diesel(table_name = validators)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
@@ -7,6 +7,7 @@ use deadpool_diesel::postgres::Object;
 use namada_sdk::state::EPOCH_SWITCH_BLOCKS_DELAY;
 use namada_sdk::time::{DateTimeUtc, Utc};
 use orm::gas::GasPriceDb;
+use orm::masp::MaspRewardInsertDb;
 use orm::migrations::CustomMigrationSource;
 use orm::parameters::ParametersInsertDb;
 use parameters::app_state::AppState;
@@ -94,8 +95,17 @@ async fn crawling_fn(
 
     let gas_price = namada_service::get_gas_price(&client).await;
 
+    let masp_rewards = namada_service::get_masp_rewards(
+        &client,
+        epoch_to_process,
+        parameters.epochs_per_year,
+    )
+    .await
+    .into_rpc_error()?;
+
     let timestamp = DateTimeUtc::now().0.timestamp();
     let crawler_state = IntervalCrawlerState { timestamp };
+    let gas_price_timestamp = Utc::now().naive_utc();
 
     tracing::info!("Queried parameters successfully",);
 
@@ -122,6 +132,25 @@ async fn crawling_fn(
                         .collect::<Vec<GasPriceDb>>(),
                 )?;
 
+                repository::parameters::insert_gas_price_history(
+                    transaction_conn,
+                    gas_price
+                        .iter()
+                        .cloned()
+                        .map(GasPriceDb::from)
+                        .collect::<Vec<GasPriceDb>>(),
+                    epoch_to_process,
+                    gas_price_timestamp,
+                )?;
+
+                repository::parameters::upsert_masp_rewards(
+                    transaction_conn,
+                    masp_rewards
+                        .into_iter()
+                        .map(MaspRewardInsertDb::from)
+                        .collect::<Vec<MaspRewardInsertDb>>(),
+                )?;
+
                 repository::crawler_state::upsert_crawler_state(
                     transaction_conn,
                     (CrawlerName::Parameters, crawler_state).into(),
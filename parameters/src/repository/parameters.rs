@@ -1,9 +1,17 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
+use bigdecimal::BigDecimal;
 use diesel::upsert::excluded;
-use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
-use orm::gas::GasPriceDb;
+use diesel::{
+    ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+};
+use orm::gas::{GasPriceDb, GasPriceHistoryDb, GasPriceHistoryInsertDb};
+use orm::masp::MaspRewardInsertDb;
 use orm::parameters::ParametersInsertDb;
-use orm::schema::{chain_parameters, gas_price};
+use orm::schema::{
+    chain_parameters, gas_price, gas_price_history, masp_rewards,
+};
 
 pub fn upsert_chain_parameters(
     transaction_conn: &mut PgConnection,
@@ -47,3 +55,76 @@ pub fn upsert_gas_price(
 
     Ok(())
 }
+
+/// Records the gas prices that changed since the last recorded one of their
+/// token
+pub fn insert_gas_price_history(
+    transaction_conn: &mut PgConnection,
+    gas_price: Vec<GasPriceDb>,
+    epoch: u32,
+    timestamp: chrono::NaiveDateTime,
+) -> anyhow::Result<()> {
+    let last_gas_prices: HashMap<String, BigDecimal> = gas_price_history::table
+        .distinct_on(gas_price_history::token)
+        .order((gas_price_history::token, gas_price_history::epoch.desc()))
+        .select(GasPriceHistoryDb::as_select())
+        .load(transaction_conn)
+        .context("Failed to query last gas prices from db")?
+        .into_iter()
+        .map(|gas_price| (gas_price.token, gas_price.amount))
+        .collect();
+
+    let changed_gas_prices = gas_price
+        .into_iter()
+        .filter(|gas_price| {
+            last_gas_prices.get(&gas_price.token) != Some(&gas_price.amount)
+        })
+        .map(|gas_price| GasPriceHistoryInsertDb {
+            token: gas_price.token,
+            amount: gas_price.amount,
+            epoch: epoch as i32,
+            timestamp,
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(gas_price_history::table)
+        .values(changed_gas_prices)
+        .on_conflict((gas_price_history::token, gas_price_history::epoch))
+        .do_update()
+        .set((
+            gas_price_history::amount.eq(excluded(gas_price_history::amount)),
+            gas_price_history::timestamp
+                .eq(excluded(gas_price_history::timestamp)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to insert gas price history in db")?;
+
+    Ok(())
+}
+
+pub fn upsert_masp_rewards(
+    transaction_conn: &mut PgConnection,
+    masp_rewards: Vec<MaspRewardInsertDb>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(masp_rewards::table)
+        .values(masp_rewards)
+        .on_conflict((masp_rewards::token, masp_rewards::epoch))
+        .do_update()
+        .set((
+            masp_rewards::max_reward_rate
+                .eq(excluded(masp_rewards::max_reward_rate)),
+            masp_rewards::kp_gain.eq(excluded(masp_rewards::kp_gain)),
+            masp_rewards::kd_gain.eq(excluded(masp_rewards::kd_gain)),
+            masp_rewards::locked_amount_target
+                .eq(excluded(masp_rewards::locked_amount_target)),
+            masp_rewards::last_inflation
+                .eq(excluded(masp_rewards::last_inflation)),
+            masp_rewards::last_locked_amount
+                .eq(excluded(masp_rewards::last_locked_amount)),
+            masp_rewards::reward_rate.eq(excluded(masp_rewards::reward_rate)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update masp rewards in db")?;
+
+    Ok(())
+}
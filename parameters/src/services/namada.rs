@@ -14,10 +14,14 @@ use namada_sdk::rpc::{
 };
 use namada_sdk::state::Key;
 use namada_sdk::token::Amount as NamadaSdkAmount;
+use namada_sdk::token::storage_key::{
+    masp_last_inflation_key, masp_last_locked_amount_key,
+};
 use shared::balance::Amount;
 use shared::block::Epoch;
 use shared::checksums::Checksums;
 use shared::gas::GasPrice;
+use shared::masp::MaspReward;
 use shared::parameters::Parameters;
 use tendermint_rpc::HttpClient;
 
@@ -143,6 +147,69 @@ pub async fn get_gas_price(client: &HttpClient) -> Vec<GasPrice> {
     gas_table
 }
 
+pub async fn get_masp_rewards(
+    client: &HttpClient,
+    epoch: Epoch,
+    epochs_per_year: u64,
+) -> anyhow::Result<Vec<MaspReward>> {
+    let reward_tokens = rpc::query_masp_reward_tokens(client)
+        .await
+        .context("Failed to query masp reward tokens")?;
+
+    let mut rewards = Vec::new();
+
+    for reward_token in reward_tokens {
+        let last_inflation: NamadaSdkAmount = query_storage_value(
+            client,
+            &masp_last_inflation_key(&reward_token.address),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to query masp last inflation of token {}",
+                reward_token.address
+            )
+        })?;
+
+        let last_locked_amount: NamadaSdkAmount = query_storage_value(
+            client,
+            &masp_last_locked_amount_key(&reward_token.address),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to query masp last locked amount of token {}",
+                reward_token.address
+            )
+        })?;
+
+        // Inflation of the last epoch relative to the amount locked in the
+        // pool, annualized. Nothing is locked before the first shielding so
+        // the rate is zero then.
+        let reward_rate = Dec::try_from(last_inflation)
+            .ok()
+            .zip(Dec::try_from(last_locked_amount).ok())
+            .and_then(|(inflation, locked_amount)| {
+                checked!(inflation / locked_amount * epochs_per_year).ok()
+            })
+            .unwrap_or_else(Dec::zero);
+
+        rewards.push(MaspReward {
+            token_address: reward_token.address.to_string(),
+            epoch,
+            max_reward_rate: reward_token.max_reward_rate.to_string(),
+            kp_gain: reward_token.kp_gain.to_string(),
+            kd_gain: reward_token.kd_gain.to_string(),
+            locked_amount_target: reward_token.locked_amount_target.to_string(),
+            last_inflation: Amount::from(last_inflation),
+            last_locked_amount: Amount::from(last_locked_amount),
+            reward_rate: reward_rate.to_string(),
+        })
+    }
+
+    Ok(rewards)
+}
+
 pub async fn get_current_epoch(client: &HttpClient) -> anyhow::Result<Epoch> {
     let epoch = rpc::query_epoch(client)
         .await
@@ -23,6 +23,15 @@ pub struct AppConfig {
     #[clap(long, env)]
     pub database_url: String,
 
+    #[clap(
+        long,
+        env,
+        default_value_t = 10,
+        help = "Number of top validators used for the stake concentration \
+                metric"
+    )]
+    pub top_n_validators: u64,
+
     #[clap(flatten)]
     pub log: LogConfig,
 }
@@ -6,6 +6,7 @@ use clap::Parser;
 use deadpool_diesel::postgres::Object;
 use namada_sdk::time::DateTimeUtc;
 use orm::crawler_state::EpochStateInsertDb;
+use orm::decentralization::DecentralizationMetricsInsertDb;
 use orm::migrations::CustomMigrationSource;
 use orm::validators::ValidatorInsertDb;
 use pos::app_state::AppState;
@@ -16,6 +17,7 @@ use pos::services::{
 };
 use shared::crawler;
 use shared::crawler_state::{CrawlerName, EpochCrawlerState};
+use shared::decentralization::DecentralizationMetrics;
 use shared::error::{AsDbError, AsRpcError, ContextDbInteractError, MainError};
 use tendermint_rpc::HttpClient;
 use tendermint_rpc::client::CompatMode;
@@ -56,8 +58,12 @@ async fn main() -> Result<(), MainError> {
         .await
         .into_rpc_error()?;
 
+    let top_n_validators = config.top_n_validators;
+
     crawler::crawl(
-        move |epoch| crawling_fn(epoch, conn.clone(), client.clone()),
+        move |epoch| {
+            crawling_fn(epoch, conn.clone(), client.clone(), top_n_validators)
+        },
         next_epoch,
         None,
     )
@@ -68,6 +74,7 @@ async fn crawling_fn(
     epoch_to_process: u32,
     conn: Arc<Object>,
     client: Arc<HttpClient>,
+    top_n_validators: u64,
 ) -> Result<(), MainError> {
     let should_process = can_process(epoch_to_process, client.clone()).await?;
 
@@ -94,6 +101,19 @@ async fn crawling_fn(
         "Queried validators successfully...",
     );
 
+    let decentralization_metrics = DecentralizationMetrics::from_validator_set(
+        &validators_set,
+        top_n_validators,
+    );
+
+    tracing::info!(
+        epoch = epoch_to_process,
+        nakamoto_coefficient =
+            decentralization_metrics.nakamoto_coefficient_one_third,
+        gini_coefficient = decentralization_metrics.gini_coefficient,
+        "Computed decentralization metrics...",
+    );
+
     let timestamp = DateTimeUtc::now().0.timestamp();
     let crawler_state = EpochCrawlerState {
         last_processed_epoch: epoch_to_process,
@@ -106,6 +126,35 @@ async fn crawling_fn(
         conn.build_transaction()
             .read_write()
             .run(|transaction_conn| {
+                let previous_states =
+                    repository::pos::get_previous_validator_states(
+                        transaction_conn,
+                        epoch_to_process,
+                    )?;
+
+                // On the very first run there is no previous set to diff
+                // against, so every validator would be reported as new
+                if !previous_states.is_empty() {
+                    let changes =
+                        validators_set.changes_since(&previous_states);
+
+                    tracing::info!(
+                        epoch = epoch_to_process,
+                        changes = changes.len(),
+                        "Computed validator set changes...",
+                    );
+
+                    repository::pos::insert_validator_set_changes(
+                        transaction_conn,
+                        changes,
+                    )?;
+                }
+
+                repository::pos::insert_validator_states(
+                    transaction_conn,
+                    &validators_set,
+                )?;
+
                 let validators_dbo = &validators_set
                     .validators
                     .into_iter()
@@ -117,6 +166,13 @@ async fn crawling_fn(
                     validators_dbo,
                 )?;
 
+                repository::pos::upsert_decentralization_metrics(
+                    transaction_conn,
+                    DecentralizationMetricsInsertDb::from(
+                        decentralization_metrics,
+                    ),
+                )?;
+
                 repository::crawler_state::upsert_crawler_state(
                     transaction_conn,
                     crawler_state,
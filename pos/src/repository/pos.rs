@@ -1,8 +1,94 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
+use diesel::dsl::max;
 use diesel::upsert::excluded;
-use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
-use orm::schema::validators;
-use orm::validators::ValidatorInsertDb;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
+use orm::decentralization::DecentralizationMetricsInsertDb;
+use orm::schema::{
+    decentralization_metrics, validator_set_changes,
+    validator_states_per_epoch, validators,
+};
+use orm::validators::{
+    ValidatorInsertDb, ValidatorSetChangeInsertDb, ValidatorStateDb,
+    ValidatorStatePerEpochInsertDb,
+};
+use shared::block::Epoch;
+use shared::id::Id;
+use shared::validator::{ValidatorSet, ValidatorSetChange, ValidatorState};
+
+/// Returns the validator states persisted for the latest epoch before `epoch`
+pub fn get_previous_validator_states(
+    transaction_conn: &mut PgConnection,
+    epoch: Epoch,
+) -> anyhow::Result<HashMap<Id, ValidatorState>> {
+    let previous_epoch = validator_states_per_epoch::table
+        .filter(validator_states_per_epoch::epoch.lt(epoch as i32))
+        .select(max(validator_states_per_epoch::epoch))
+        .first::<Option<i32>>(transaction_conn)
+        .context("Failed to query previous validator states epoch from db")?;
+
+    let Some(previous_epoch) = previous_epoch else {
+        return Ok(HashMap::new());
+    };
+
+    let states = validator_states_per_epoch::table
+        .filter(validator_states_per_epoch::epoch.eq(previous_epoch))
+        .select((
+            validator_states_per_epoch::address,
+            validator_states_per_epoch::state,
+        ))
+        .load::<(String, ValidatorStateDb)>(transaction_conn)
+        .context("Failed to query previous validator states from db")?;
+
+    Ok(states
+        .into_iter()
+        .map(|(address, state)| (Id::Account(address), state.into()))
+        .collect())
+}
+
+pub fn insert_validator_states(
+    transaction_conn: &mut PgConnection,
+    validator_set: &ValidatorSet,
+) -> anyhow::Result<()> {
+    diesel::insert_into(validator_states_per_epoch::table)
+        .values::<Vec<ValidatorStatePerEpochInsertDb>>(
+            ValidatorStatePerEpochInsertDb::from_validator_set(validator_set),
+        )
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert validator states in db")?;
+
+    Ok(())
+}
+
+pub fn insert_validator_set_changes(
+    transaction_conn: &mut PgConnection,
+    changes: Vec<ValidatorSetChange>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(validator_set_changes::table)
+        .values::<Vec<ValidatorSetChangeInsertDb>>(
+            changes
+                .into_iter()
+                .map(ValidatorSetChangeInsertDb::from)
+                .collect(),
+        )
+        .on_conflict((
+            validator_set_changes::epoch,
+            validator_set_changes::address,
+        ))
+        .do_update()
+        .set((
+            validator_set_changes::previous_state
+                .eq(excluded(validator_set_changes::previous_state)),
+            validator_set_changes::current_state
+                .eq(excluded(validator_set_changes::current_state)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to insert validator set changes in db")?;
+
+    Ok(())
+}
 
 pub fn upsert_validators(
     transaction_conn: &mut PgConnection,
@@ -26,3 +112,39 @@ pub fn upsert_validators(
 
     Ok(())
 }
+
+pub fn upsert_decentralization_metrics(
+    transaction_conn: &mut PgConnection,
+    metrics: DecentralizationMetricsInsertDb,
+) -> anyhow::Result<()> {
+    diesel::insert_into(decentralization_metrics::table)
+        .values::<&DecentralizationMetricsInsertDb>(&metrics)
+        .on_conflict(decentralization_metrics::epoch)
+        .do_update()
+        .set((
+            decentralization_metrics::consensus_validators
+                .eq(excluded(decentralization_metrics::consensus_validators)),
+            decentralization_metrics::total_voting_power
+                .eq(excluded(decentralization_metrics::total_voting_power)),
+            decentralization_metrics::nakamoto_coefficient_one_third.eq(
+                excluded(
+                    decentralization_metrics::nakamoto_coefficient_one_third,
+                ),
+            ),
+            decentralization_metrics::nakamoto_coefficient_two_thirds.eq(
+                excluded(
+                    decentralization_metrics::nakamoto_coefficient_two_thirds,
+                ),
+            ),
+            decentralization_metrics::gini_coefficient
+                .eq(excluded(decentralization_metrics::gini_coefficient)),
+            decentralization_metrics::top_n
+                .eq(excluded(decentralization_metrics::top_n)),
+            decentralization_metrics::top_n_share
+                .eq(excluded(decentralization_metrics::top_n_share)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update decentralization metrics in db")?;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, Zero};
 use fake::Fake;
 use namada_sdk::token::{
     Amount as NamadaAmount, DenominatedAmount as NamadaDenominatedAmount,
@@ -67,6 +67,18 @@ impl Amount {
         self.0.checked_add(other.0).map(Self)
     }
 
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    pub fn checked_mul_u64(&self, other: u64) -> Option<Self> {
+        self.0.checked_mul(NamadaAmount::from_u64(other)).map(Self)
+    }
+
+    pub fn checked_div_u64(&self, other: u64) -> Option<Self> {
+        self.0.checked_div_u64(other).map(Self)
+    }
+
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
     }
@@ -153,10 +165,52 @@ pub struct TokenSupply {
     pub epoch: i32,
     pub total: BigDecimal,
     pub effective: Option<BigDecimal>,
+    /// Total amount bonded in PoS, only known for the native token
+    pub bonded: Option<BigDecimal>,
+}
+
+/// Amount minted per epoch between two total supply snapshots. Supplies are
+/// only stored when they change, so the difference is spread evenly over the
+/// epochs separating the snapshots.
+pub fn minted_per_epoch(
+    (previous_epoch, previous_total): (i32, &BigDecimal),
+    (epoch, total): (i32, &BigDecimal),
+) -> Option<BigDecimal> {
+    let epochs = epoch.checked_sub(previous_epoch).filter(|e| *e > 0)?;
+
+    Some((total - previous_total) / BigDecimal::from(epochs))
+}
+
+/// Yearly inflation rate implied by minting `minted_per_epoch` on top of
+/// `previous_total` every epoch
+pub fn annual_inflation_rate(
+    minted_per_epoch: &BigDecimal,
+    previous_total: &BigDecimal,
+    epochs_per_year: u64,
+) -> Option<BigDecimal> {
+    if previous_total.is_zero() {
+        return None;
+    }
+
+    Some(minted_per_epoch * BigDecimal::from(epochs_per_year) / previous_total)
+}
+
+/// Ratio of the total supply that is bonded
+pub fn staked_ratio(
+    bonded: &BigDecimal,
+    total: &BigDecimal,
+) -> Option<BigDecimal> {
+    if total.is_zero() {
+        return None;
+    }
+
+    Some(bonded / total)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use super::*;
 
     #[test]
@@ -188,4 +242,31 @@ mod tests {
         };
         assert_eq!(initial_bigdec, bigdec_round_trip);
     }
+
+    #[test]
+    fn supply_derived_metrics() {
+        let previous = BigDecimal::from(1_000_000);
+        let current = BigDecimal::from(1_000_300);
+
+        let minted = minted_per_epoch((10, &previous), (13, &current));
+        assert_eq!(minted, Some(BigDecimal::from(100)));
+        assert_eq!(minted_per_epoch((13, &current), (13, &current)), None);
+        assert_eq!(minted_per_epoch((13, &current), (10, &previous)), None);
+
+        let rate =
+            annual_inflation_rate(&BigDecimal::from(100), &previous, 365);
+        assert_eq!(rate, Some(BigDecimal::from_str("0.0365").unwrap()));
+        assert_eq!(
+            annual_inflation_rate(
+                &BigDecimal::from(100),
+                &BigDecimal::zero(),
+                365
+            ),
+            None
+        );
+
+        let ratio = staked_ratio(&BigDecimal::from(400_000), &previous);
+        assert_eq!(ratio, Some(BigDecimal::from_str("0.4").unwrap()));
+        assert_eq!(staked_ratio(&previous, &BigDecimal::zero()), None);
+    }
 }
@@ -15,8 +15,11 @@ use crate::checksums::Checksums;
 use crate::header::BlockHeader;
 use crate::id::Id;
 use crate::masp::{MaspEntry, MaspEntryDirection};
+use crate::pgf::{PgfStewardEvent, PgfStewardEventKind, commission_to_json};
 use crate::pos::{BondAddresses, UnbondAddresses};
-use crate::proposal::{GovernanceProposal, GovernanceProposalKind};
+use crate::proposal::{
+    GovernanceProposal, GovernanceProposalContent, GovernanceProposalKind,
+};
 use crate::public_key::PublicKey;
 use crate::token::{IbcToken, Token};
 use crate::transaction::{
@@ -25,9 +28,10 @@ use crate::transaction::{
 };
 use crate::utils::{BalanceChange, MASP_ADDRESS, ibc_ack_to_balance_info};
 use crate::validator::{
-    Validator, ValidatorMetadataChange, ValidatorState, ValidatorStateChange,
+    Validator, ValidatorEvent, ValidatorEventKind, ValidatorMetadataChange,
+    ValidatorState, ValidatorStateChange,
 };
-use crate::vote::GovernanceVote;
+use crate::vote::{GovernanceVote, GovernanceVoteCast};
 
 pub type Epoch = u32;
 pub type BlockHeight = u32;
@@ -399,6 +403,26 @@ impl Block {
                         vec![]
                     }
                 }
+                TransactionKind::UpdateStewardCommission(data) => {
+                    if let Some(data) = data {
+                        vec![TransactionTarget::sent(
+                            tx.tx_id,
+                            data.steward.to_string(),
+                        )]
+                    } else {
+                        vec![]
+                    }
+                }
+                TransactionKind::ResignSteward(address) => {
+                    if let Some(data) = address {
+                        vec![TransactionTarget::sent(
+                            tx.tx_id,
+                            data.to_string(),
+                        )]
+                    } else {
+                        vec![]
+                    }
+                }
                 TransactionKind::IbcMsg(_)
                 | TransactionKind::InitAccount(_)
                 | TransactionKind::Unknown(_) => vec![],
@@ -600,6 +624,9 @@ impl Block {
                             init_proposal_data.activation_epoch.0 as u32,
                         ),
                         content: proposal_content_serialized,
+                        structured_content: GovernanceProposalContent::from(
+                            &proposal_content,
+                        ),
                     })
                 }
                 _ => None,
@@ -663,6 +690,76 @@ impl Block {
             .collect()
     }
 
+    pub fn governance_vote_casts(&self) -> Vec<GovernanceVoteCast> {
+        self.transactions
+            .iter()
+            .fold(vec![], |mut acc, (wrapper_tx, inner_txs)| {
+                // Extract successful inner txs
+                for inner_tx in inner_txs {
+                    if inner_tx.was_successful(wrapper_tx) {
+                        acc.push(inner_tx)
+                    }
+                }
+
+                acc
+            })
+            .iter()
+            .filter_map(|tx| match &tx.kind {
+                TransactionKind::ProposalVote(Some(vote_proposal_data)) => {
+                    Some(GovernanceVoteCast {
+                        proposal_id: vote_proposal_data.id,
+                        vote: vote_proposal_data.vote.to_owned().into(),
+                        address: Id::from(vote_proposal_data.voter.to_owned()),
+                        block_height: self.header.height,
+                        inner_tx_id: tx.tx_id.to_owned(),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn pgf_steward_events(&self) -> Vec<PgfStewardEvent> {
+        self.transactions
+            .iter()
+            .fold(vec![], |mut acc, (wrapper_tx, inner_txs)| {
+                // Extract successful inner txs
+                for inner_tx in inner_txs {
+                    if inner_tx.was_successful(wrapper_tx) {
+                        acc.push(inner_tx)
+                    }
+                }
+
+                acc
+            })
+            .iter()
+            .filter_map(|tx| {
+                let (address, kind, commission) = match &tx.kind {
+                    TransactionKind::UpdateStewardCommission(Some(data)) => (
+                        data.steward.to_owned(),
+                        PgfStewardEventKind::CommissionUpdate,
+                        Some(commission_to_json(data.commission.to_owned())),
+                    ),
+                    TransactionKind::ResignSteward(Some(address)) => (
+                        address.to_owned(),
+                        PgfStewardEventKind::Resigned,
+                        None,
+                    ),
+                    _ => return None,
+                };
+
+                Some(PgfStewardEvent {
+                    address: Id::from(address),
+                    kind,
+                    proposal_id: None,
+                    block_height: Some(self.header.height),
+                    inner_tx_id: Some(tx.tx_id.to_owned()),
+                    commission,
+                })
+            })
+            .collect()
+    }
+
     pub fn ibc_tokens(&self) -> HashSet<IbcToken> {
         self.transactions
             .iter()
@@ -849,6 +946,8 @@ impl Block {
             }
             TransactionKind::Redelegation(_)
             | TransactionKind::ChangeConsensusKey(_)
+            | TransactionKind::UpdateStewardCommission(_)
+            | TransactionKind::ResignSteward(_)
             | TransactionKind::InitAccount(_)
             | TransactionKind::CommissionChange(_)
             | TransactionKind::RevealPk(_)
@@ -1148,6 +1247,61 @@ impl Block {
             .collect()
     }
 
+    pub fn validator_events(&self) -> Vec<ValidatorEvent> {
+        self.transactions
+            .iter()
+            .fold(vec![], |mut acc, (wrapper_tx, inner_txs)| {
+                // Extract successful inner txs
+                for inner_tx in inner_txs {
+                    if inner_tx.was_successful(wrapper_tx) {
+                        acc.push(inner_tx)
+                    }
+                }
+
+                acc
+            })
+            .iter()
+            .filter_map(|tx| {
+                let (address, kind) = match &tx.kind {
+                    TransactionKind::BecomeValidator(Some(data)) => (
+                        data.address.to_owned(),
+                        ValidatorEventKind::BecomeValidator,
+                    ),
+                    TransactionKind::DeactivateValidator(Some(address)) => {
+                        (address.to_owned(), ValidatorEventKind::Deactivation)
+                    }
+                    TransactionKind::ReactivateValidator(Some(address)) => {
+                        (address.to_owned(), ValidatorEventKind::Reactivation)
+                    }
+                    TransactionKind::UnjailValidator(Some(address)) => {
+                        (address.to_owned(), ValidatorEventKind::Unjail)
+                    }
+                    TransactionKind::MetadataChange(Some(data)) => (
+                        data.validator.to_owned(),
+                        ValidatorEventKind::MetadataChange,
+                    ),
+                    TransactionKind::CommissionChange(Some(data)) => (
+                        data.validator.to_owned(),
+                        ValidatorEventKind::CommissionChange,
+                    ),
+                    TransactionKind::ChangeConsensusKey(Some(data)) => (
+                        data.validator.to_owned(),
+                        ValidatorEventKind::ConsensusKeyChange,
+                    ),
+                    _ => return None,
+                };
+
+                Some(ValidatorEvent {
+                    address: Id::from(address),
+                    kind,
+                    block_height: self.header.height,
+                    inner_tx_id: tx.tx_id.to_owned(),
+                    data: tx.data.to_owned(),
+                })
+            })
+            .collect()
+    }
+
     pub fn revealed_pks(&self) -> Vec<(PublicKey, Id)> {
         self.transactions
             .iter()
@@ -6,15 +6,22 @@ use std::str::FromStr;
 use bigdecimal::BigDecimal;
 use namada_core::masp::MaspTxId;
 use namada_core::token::Amount as NamadaAmount;
-use namada_events::extend::ReadFromEventAttributes;
+use namada_core::uint::Uint;
+use namada_events::extend::{ReadFromEventAttributes, UserAccount};
 use namada_ibc::IbcTxDataHash;
 use namada_ibc::apps::transfer::types::packet::PacketData as Ics20PacketData;
+use namada_sdk::address::Address as NamadaAddress;
+use namada_sdk::token::event::{Descriptor, SourceAccounts, TargetAccounts};
 use namada_tx::IndexedTx;
 use namada_tx::data::TxResult;
 use namada_tx::event::MaspTxRef;
 use tendermint_rpc::endpoint::block_results::Response as TendermintBlockResultResponse;
 
 use crate::balance::Amount;
+use crate::ibc::{
+    IbcChannel, IbcChannelState, IbcClient, IbcClientEventKind, IbcConnection,
+    IbcConnectionState,
+};
 use crate::id::Id;
 use crate::transaction::{IbcTokenAction, TransactionExitStatus};
 
@@ -30,9 +37,13 @@ pub enum IbcCorePacketKind {
 pub enum EventKind {
     Applied,
     IbcCore(IbcCorePacketKind),
+    IbcClient(IbcClientEventKind),
+    IbcConnection(IbcConnectionState),
+    IbcChannel(IbcChannelState),
     FungibleTokenPacket,
     MaspFeePayment,
     MaspTransfer,
+    TokenTransfer,
     Unknown,
 }
 
@@ -42,9 +53,31 @@ impl From<&String> for EventKind {
             "tx/applied" => Self::Applied,
             "send_packet" => Self::IbcCore(IbcCorePacketKind::Send),
             "recv_packet" => Self::IbcCore(IbcCorePacketKind::Recv),
+            "acknowledge_packet" => Self::IbcCore(IbcCorePacketKind::Ack),
+            "timeout_packet" => Self::IbcCore(IbcCorePacketKind::Timeout),
+            "create_client" => Self::IbcClient(IbcClientEventKind::Create),
+            "update_client" => Self::IbcClient(IbcClientEventKind::Update),
+            "connection_open_init" => {
+                Self::IbcConnection(IbcConnectionState::Init)
+            }
+            "connection_open_try" => {
+                Self::IbcConnection(IbcConnectionState::TryOpen)
+            }
+            "connection_open_ack" | "connection_open_confirm" => {
+                Self::IbcConnection(IbcConnectionState::Open)
+            }
+            "channel_open_init" => Self::IbcChannel(IbcChannelState::Init),
+            "channel_open_try" => Self::IbcChannel(IbcChannelState::TryOpen),
+            "channel_open_ack" | "channel_open_confirm" => {
+                Self::IbcChannel(IbcChannelState::Open)
+            }
+            "channel_close_init" | "channel_close_confirm" => {
+                Self::IbcChannel(IbcChannelState::Closed)
+            }
             "fungible_token_packet" => Self::FungibleTokenPacket,
             "masp/fee-payment" => Self::MaspFeePayment,
             "masp/transfer" => Self::MaspTransfer,
+            "token/transfer" => Self::TokenTransfer,
             _ => Self::Unknown,
         }
     }
@@ -197,6 +230,25 @@ pub struct IbcPacket {
     pub data: String,
 }
 
+impl IbcPacket {
+    /// Decodes the ics20 transfer carried by the packet, if any. Only send and
+    /// recv events include the packet data.
+    pub fn fungible_token_packet(&self) -> Option<FungibleTokenPacket> {
+        let packet_data: Ics20PacketData =
+            serde_json::from_str(&self.data).ok()?;
+        let ibc_amount: NamadaAmount =
+            packet_data.token.amount.try_into().ok()?;
+
+        Some(FungibleTokenPacket {
+            memo: packet_data.memo.to_string(),
+            sender: packet_data.sender.to_string(),
+            receiver: packet_data.receiver.to_string(),
+            denom: packet_data.token.denom.to_string(),
+            amount: Amount::from(ibc_amount).into(),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct FungibleTokenPacket {
     pub sender: String,
@@ -206,11 +258,50 @@ pub struct FungibleTokenPacket {
     pub amount: BigDecimal,
 }
 
+/// Account touched by a token transfer event
+#[derive(Debug, Clone)]
+pub struct TokenTransferAccount {
+    pub owner: Id,
+    pub token: Id,
+    pub amount: Amount,
+}
+
+impl TokenTransferAccount {
+    fn from_event_value(
+        accounts: Vec<((UserAccount, NamadaAddress), Uint)>,
+    ) -> Vec<Self> {
+        accounts
+            .into_iter()
+            .map(|((account, token), amount)| Self {
+                owner: match account {
+                    UserAccount::Internal(address) => Id::from(address),
+                    UserAccount::External(address) => Id::Account(address),
+                },
+                token: Id::from(token),
+                amount: Amount::from(NamadaAmount::from(amount)),
+            })
+            .collect()
+    }
+}
+
+/// Token transfer executed by the protocol, e.g. retro pgf payments
+#[derive(Debug, Clone)]
+pub struct TokenTransfer {
+    pub descriptor: String,
+    pub sources: Vec<TokenTransferAccount>,
+    pub targets: Vec<TokenTransferAccount>,
+}
+
 #[derive(Debug, Clone)]
 pub enum TxAttributesType {
     TxApplied(TxApplied),
     SendPacket(IbcPacket),
     RecvPacket(IbcPacket),
+    AckPacket(IbcPacket),
+    TimeoutPacket(IbcPacket),
+    IbcClient(IbcClient),
+    IbcConnection(IbcConnection),
+    IbcChannel(IbcChannel),
     FungibleTokenPacket {
         is_ack: bool,
         success: bool,
@@ -218,6 +309,7 @@ pub enum TxAttributesType {
     },
     MaspFeePayment(MaspTxData),
     MaspTransfer(MaspTxData),
+    TokenTransfer(TokenTransfer),
 }
 
 impl TxAttributesType {
@@ -260,15 +352,47 @@ impl TxAttributesType {
                     },
                 })
             }
-            EventKind::IbcCore(
-                kind @ (IbcCorePacketKind::Ack | IbcCorePacketKind::Timeout),
-            ) => {
-                tracing::warn!(?kind, "Received unhandled IBC packet kind");
-                None
+            EventKind::IbcClient(kind) => Some(Self::IbcClient(IbcClient {
+                kind: kind.clone(),
+                client_id: attributes.get("client_id")?.to_owned(),
+                client_type: attributes.get("client_type")?.to_owned(),
+                consensus_height: attributes
+                    .get("consensus_height")?
+                    .to_owned(),
+                counterparty_chain_id: None,
+            })),
+            EventKind::IbcConnection(state) => {
+                Some(Self::IbcConnection(IbcConnection {
+                    connection_id: attributes.get("connection_id")?.to_owned(),
+                    client_id: attributes.get("client_id")?.to_owned(),
+                    counterparty_client_id: attributes
+                        .get("counterparty_client_id")?
+                        .to_owned(),
+                    // The counterparty identifier is not known yet when the
+                    // handshake is initiated by this chain
+                    counterparty_connection_id: attributes
+                        .get("counterparty_connection_id")
+                        .filter(|id| !id.is_empty())
+                        .cloned(),
+                    state: state.clone(),
+                }))
+            }
+            EventKind::IbcChannel(state) => {
+                Some(Self::IbcChannel(IbcChannel {
+                    port_id: attributes.get("port_id")?.to_owned(),
+                    channel_id: attributes.get("channel_id")?.to_owned(),
+                    counterparty_port_id: attributes
+                        .get("counterparty_port_id")?
+                        .to_owned(),
+                    counterparty_channel_id: attributes
+                        .get("counterparty_channel_id")
+                        .filter(|id| !id.is_empty())
+                        .cloned(),
+                    connection_id: attributes.get("connection_id")?.to_owned(),
+                    state: state.clone(),
+                }))
             }
-            EventKind::IbcCore(
-                kind @ (IbcCorePacketKind::Send | IbcCorePacketKind::Recv),
-            ) => {
+            EventKind::IbcCore(kind) => {
                 let source_port =
                     attributes.get("packet_src_port").unwrap().to_owned();
                 let dest_port =
@@ -287,12 +411,16 @@ impl TxAttributesType {
                     .to_owned();
                 let timeout_height =
                     attributes.get("packet_timeout_height").unwrap().to_owned();
-                let data = attributes.get("packet_data").unwrap().to_owned();
+                // Acknowledgement and timeout events do not carry the packet
+                // data
+                let data =
+                    attributes.get("packet_data").cloned().unwrap_or_default();
 
                 let constructor = match kind {
                     IbcCorePacketKind::Send => Self::SendPacket,
                     IbcCorePacketKind::Recv => Self::RecvPacket,
-                    _ => unreachable!(),
+                    IbcCorePacketKind::Ack => Self::AckPacket,
+                    IbcCorePacketKind::Timeout => Self::TimeoutPacket,
                 };
 
                 Some(constructor(IbcPacket {
@@ -355,6 +483,34 @@ impl TxAttributesType {
 
                 Some(Self::MaspTransfer(MaspTxData { indexed_tx, data }))
             }
+            EventKind::TokenTransfer => {
+                let descriptor =
+                    Descriptor::read_from_event_attributes(attributes).ok()?;
+                let sources =
+                    SourceAccounts::read_from_event_attributes(attributes)
+                        .ok()?;
+                let targets =
+                    TargetAccounts::read_from_event_attributes(attributes)
+                        .ok()?;
+
+                Some(Self::TokenTransfer(TokenTransfer {
+                    descriptor,
+                    sources: TokenTransferAccount::from_event_value(sources.0),
+                    targets: TokenTransferAccount::from_event_value(targets.0),
+                }))
+            }
+        }
+    }
+
+    pub fn as_ibc_packet(&self) -> Option<(IbcCorePacketKind, &IbcPacket)> {
+        match self {
+            Self::SendPacket(packet) => Some((IbcCorePacketKind::Send, packet)),
+            Self::RecvPacket(packet) => Some((IbcCorePacketKind::Recv, packet)),
+            Self::AckPacket(packet) => Some((IbcCorePacketKind::Ack, packet)),
+            Self::TimeoutPacket(packet) => {
+                Some((IbcCorePacketKind::Timeout, packet))
+            }
+            _ => None,
         }
     }
 
@@ -393,18 +549,7 @@ impl TxAttributesType {
             _ => return None,
         };
 
-        let packet_data: Ics20PacketData =
-            serde_json::from_str(&packet.data).ok()?;
-        let ibc_amount: NamadaAmount =
-            packet_data.token.amount.try_into().ok()?;
-
-        let ics20_packet = FungibleTokenPacket {
-            memo: packet_data.memo.to_string(),
-            sender: packet_data.sender.to_string(),
-            receiver: packet_data.receiver.to_string(),
-            denom: packet_data.token.denom.to_string(),
-            amount: Amount::from(ibc_amount).into(),
-        };
+        let ics20_packet = packet.fungible_token_packet()?;
 
         Some((action, Some(packet), Cow::Owned(ics20_packet)))
     }
@@ -559,6 +704,21 @@ impl BlockResult {
             })
             .flatten()
     }
+
+    /// Token transfers emitted at the block level with the given descriptor
+    pub fn token_transfers(&self, descriptor: &str) -> Vec<&TokenTransfer> {
+        self.end_events
+            .iter()
+            .filter_map(|event| match &event.attributes {
+                Some(TxAttributesType::TokenTransfer(transfer))
+                    if transfer.descriptor == descriptor =>
+                {
+                    Some(transfer)
+                }
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -599,7 +759,25 @@ mod tests {
             })
             .collect();
 
-        assert_eq!(events.len(), 1);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events.remove(0),
+            Event {
+                kind: EventKind::IbcCore(IbcCorePacketKind::Ack),
+                attributes: Some(TxAttributesType::AckPacket(IbcPacket {
+                    source_channel,
+                    dest_channel,
+                    sequence,
+                    data,
+                    ..
+                })),
+            }
+            if
+                source_channel == "channel-85077"
+                    && dest_channel == "channel-0"
+                    && sequence == "56446"
+                    && data.is_empty()
+        ));
         assert!(matches!(
             events.remove(0),
             Event {
@@ -627,6 +805,73 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn ibc_packet_transfer_data() {
+        let packet = IbcPacket {
+            data: r#"{"amount":"1000","denom":"transfer/channel-1/uosmo","memo":"","receiver":"tnam1qq0yql7pwmyxtruxh9g7qxw2x8sqy0fwtu9y2g8m","sender":"osmo1m8wg4vxkefhs374qxmmqpyusgz289wmulex5qdwpfx7jnrxzer5s9cv83q"}"#.to_owned(),
+            ..Default::default()
+        };
+
+        let transfer = packet.fungible_token_packet().unwrap();
+
+        assert_eq!(transfer.denom, "transfer/channel-1/uosmo");
+        assert_eq!(transfer.amount, "1000".parse::<BigDecimal>().unwrap());
+        assert_eq!(
+            transfer.receiver,
+            "tnam1qq0yql7pwmyxtruxh9g7qxw2x8sqy0fwtu9y2g8m"
+        );
+
+        let ack_packet = IbcPacket::default();
+
+        assert!(ack_packet.fungible_token_packet().is_none());
+    }
+
+    #[test]
+    fn ibc_channel_events() {
+        let kind = EventKind::from(&"channel_open_init".to_owned());
+        let attributes = BTreeMap::from([
+            ("port_id".to_owned(), "transfer".to_owned()),
+            ("channel_id".to_owned(), "channel-7".to_owned()),
+            ("counterparty_port_id".to_owned(), "transfer".to_owned()),
+            ("counterparty_channel_id".to_owned(), "".to_owned()),
+            ("connection_id".to_owned(), "connection-3".to_owned()),
+        ]);
+
+        assert!(matches!(
+            TxAttributesType::deserialize(&kind, &attributes),
+            Some(TxAttributesType::IbcChannel(IbcChannel {
+                channel_id,
+                counterparty_channel_id: None,
+                connection_id,
+                state: IbcChannelState::Init,
+                ..
+            }))
+            if channel_id == "channel-7" && connection_id == "connection-3"
+        ));
+
+        let kind = EventKind::from(&"channel_open_ack".to_owned());
+        let attributes = BTreeMap::from([
+            ("port_id".to_owned(), "transfer".to_owned()),
+            ("channel_id".to_owned(), "channel-7".to_owned()),
+            ("counterparty_port_id".to_owned(), "transfer".to_owned()),
+            (
+                "counterparty_channel_id".to_owned(),
+                "channel-42".to_owned(),
+            ),
+            ("connection_id".to_owned(), "connection-3".to_owned()),
+        ]);
+
+        assert!(matches!(
+            TxAttributesType::deserialize(&kind, &attributes),
+            Some(TxAttributesType::IbcChannel(IbcChannel {
+                counterparty_channel_id: Some(counterparty_channel_id),
+                state: IbcChannelState::Open,
+                ..
+            }))
+            if counterparty_channel_id == "channel-42"
+        ));
+    }
+
     fn example_events() -> Vec<TestEvent> {
         vec![
             TestEvent {
@@ -0,0 +1,164 @@
+use std::str::FromStr;
+
+use crate::block::Epoch;
+use crate::validator::{ValidatorSet, ValidatorState};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecentralizationMetrics {
+    pub epoch: Epoch,
+    pub consensus_validators: u64,
+    pub total_voting_power: f64,
+    pub nakamoto_coefficient_one_third: u64,
+    pub nakamoto_coefficient_two_thirds: u64,
+    pub gini_coefficient: f64,
+    pub top_n: u64,
+    pub top_n_share: f64,
+}
+
+impl DecentralizationMetrics {
+    /// Computes the metrics over the voting power of the consensus set, as
+    /// only consensus validators take part in block production.
+    pub fn from_validator_set(
+        validator_set: &ValidatorSet,
+        top_n: u64,
+    ) -> Self {
+        let voting_powers = validator_set
+            .validators
+            .iter()
+            .filter(|validator| validator.state == ValidatorState::Consensus)
+            .filter_map(|validator| f64::from_str(&validator.voting_power).ok())
+            .collect();
+
+        Self::from_voting_powers(validator_set.epoch, voting_powers, top_n)
+    }
+
+    pub fn from_voting_powers(
+        epoch: Epoch,
+        mut voting_powers: Vec<f64>,
+        top_n: u64,
+    ) -> Self {
+        // Sort in descending order of voting power
+        voting_powers.sort_by(|a, b| b.total_cmp(a));
+
+        let total_voting_power = voting_powers.iter().sum::<f64>();
+        let top_n_voting_power =
+            voting_powers.iter().take(top_n as usize).sum::<f64>();
+
+        Self {
+            epoch,
+            consensus_validators: voting_powers.len() as u64,
+            total_voting_power,
+            nakamoto_coefficient_one_third: nakamoto_coefficient(
+                &voting_powers,
+                total_voting_power,
+                (1, 3),
+            ),
+            nakamoto_coefficient_two_thirds: nakamoto_coefficient(
+                &voting_powers,
+                total_voting_power,
+                (2, 3),
+            ),
+            gini_coefficient: gini_coefficient(
+                &voting_powers,
+                total_voting_power,
+            ),
+            top_n,
+            top_n_share: share(top_n_voting_power, total_voting_power),
+        }
+    }
+}
+
+/// Minimum number of validators whose combined voting power exceeds the
+/// `numerator / denominator` of the total. Expects voting powers sorted in
+/// descending order.
+fn nakamoto_coefficient(
+    sorted_voting_powers: &[f64],
+    total_voting_power: f64,
+    (numerator, denominator): (u64, u64),
+) -> u64 {
+    if total_voting_power <= 0_f64 {
+        return 0;
+    }
+
+    let mut accumulated = 0_f64;
+    for (index, voting_power) in sorted_voting_powers.iter().enumerate() {
+        accumulated += voting_power;
+        // Compare cross-multiplied to avoid rounding `total * 1/3`
+        if accumulated * denominator as f64
+            > total_voting_power * numerator as f64
+        {
+            return index as u64 + 1;
+        }
+    }
+
+    sorted_voting_powers.len() as u64
+}
+
+/// Gini coefficient of the voting power distribution, 0 meaning all validators
+/// have the same voting power and values close to 1 meaning a single validator
+/// holds almost all of it.
+fn gini_coefficient(
+    sorted_voting_powers: &[f64],
+    total_voting_power: f64,
+) -> f64 {
+    let n = sorted_voting_powers.len() as f64;
+    if n == 0_f64 || total_voting_power <= 0_f64 {
+        return 0_f64;
+    }
+
+    // The formula expects the values in ascending order, with 1-based ranks
+    let weighted_sum = sorted_voting_powers
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, voting_power)| (index as f64 + 1_f64) * voting_power)
+        .sum::<f64>();
+
+    (2_f64 * weighted_sum) / (n * total_voting_power) - (n + 1_f64) / n
+}
+
+fn share(part: f64, total: f64) -> f64 {
+    if total <= 0_f64 { 0_f64 } else { part / total }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decentralization_metrics_equal_distribution() {
+        let metrics =
+            DecentralizationMetrics::from_voting_powers(1, vec![10_f64; 9], 3);
+
+        assert_eq!(metrics.consensus_validators, 9);
+        assert_eq!(metrics.nakamoto_coefficient_one_third, 4);
+        assert_eq!(metrics.nakamoto_coefficient_two_thirds, 7);
+        assert!(metrics.gini_coefficient.abs() < 1e-9);
+        assert!((metrics.top_n_share - 1_f64 / 3_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decentralization_metrics_concentrated_distribution() {
+        let metrics = DecentralizationMetrics::from_voting_powers(
+            1,
+            vec![1_f64, 1_f64, 1_f64, 97_f64],
+            1,
+        );
+
+        assert_eq!(metrics.nakamoto_coefficient_one_third, 1);
+        assert_eq!(metrics.nakamoto_coefficient_two_thirds, 1);
+        assert!((metrics.top_n_share - 0.97).abs() < 1e-9);
+        assert!(metrics.gini_coefficient > 0.7);
+    }
+
+    #[test]
+    fn decentralization_metrics_empty_set() {
+        let metrics =
+            DecentralizationMetrics::from_voting_powers(1, vec![], 10);
+
+        assert_eq!(metrics.nakamoto_coefficient_one_third, 0);
+        assert_eq!(metrics.nakamoto_coefficient_two_thirds, 0);
+        assert_eq!(metrics.gini_coefficient, 0_f64);
+        assert_eq!(metrics.top_n_share, 0_f64);
+    }
+}
@@ -1,5 +1,11 @@
+use std::str::FromStr;
+
+use namada_sdk::token::DenominatedAmount as NamadaDenominatedAmount;
+
 use crate::balance::Amount;
+use crate::block::Epoch;
 use crate::id::Id;
+use crate::transaction::TransactionKind;
 
 #[derive(Clone, Debug)]
 pub struct GasPrice {
@@ -113,3 +119,92 @@ impl GasEstimation {
         self.reveal_pk += 1
     }
 }
+
+/// Fees paid in a block for one token, payer and transaction kind, together
+/// with the number of inner transactions they were paid for
+#[derive(Clone, Debug)]
+pub struct FeeRollup {
+    pub token: Id,
+    pub fee_payer: Id,
+    pub kind: TransactionKind,
+    pub epoch: Epoch,
+    pub timestamp: i64,
+    pub amount: Amount,
+    pub transactions: u64,
+}
+
+/// Raw amount of fee paid by a wrapper with the given gas limit and
+/// denominated price per gas unit. The whole gas limit is charged, regardless
+/// of the gas actually used
+pub fn fee_paid(gas_limit: u64, amount_per_gas_unit: &str) -> Option<Amount> {
+    let amount_per_gas_unit =
+        NamadaDenominatedAmount::from_str(amount_per_gas_unit).ok()?;
+
+    Amount::from(amount_per_gas_unit.amount()).checked_mul_u64(gas_limit)
+}
+
+/// Splits a raw fee in `parts` equal shares, returning the share and the
+/// remainder of the division
+pub fn split_fee(fee: &Amount, parts: u64) -> Option<(Amount, Amount)> {
+    let share = fee.checked_div_u64(parts)?;
+    let remainder = fee.checked_sub(&share.checked_mul_u64(parts)?)?;
+
+    Some((share, remainder))
+}
+
+/// Width in bytes of the buckets used to group transactions of similar size
+pub const TX_SIZE_BUCKET_WIDTH: u64 = 512;
+
+/// Inclusive size range of the bucket containing `tx_size`, widened by
+/// `spread` neighbouring buckets on each side
+pub fn tx_size_bucket_range(tx_size: u64, spread: u64) -> (u64, u64) {
+    let bucket = tx_size / TX_SIZE_BUCKET_WIDTH;
+    let lower = bucket.saturating_sub(spread) * TX_SIZE_BUCKET_WIDTH;
+    let upper = bucket
+        .saturating_add(spread)
+        .saturating_add(1)
+        .saturating_mul(TX_SIZE_BUCKET_WIDTH)
+        - 1;
+
+    (lower, upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::token::Amount as NamadaAmount;
+
+    use super::*;
+
+    fn amount(value: u64) -> Amount {
+        Amount::from(NamadaAmount::from_u64(value))
+    }
+
+    #[test]
+    fn fee_paid_multiplies_gas_limit_by_raw_price() {
+        assert_eq!(fee_paid(50_000, "0.000001"), Some(amount(50_000)));
+        assert_eq!(fee_paid(50_000, "0.000010"), Some(amount(500_000)));
+        assert_eq!(fee_paid(0, "0.000001"), Some(amount(0)));
+        assert_eq!(fee_paid(50_000, "not a number"), None);
+    }
+
+    #[test]
+    fn split_fee_keeps_remainder() {
+        assert_eq!(split_fee(&amount(10), 3), Some((amount(3), amount(1))));
+        assert_eq!(split_fee(&amount(9), 3), Some((amount(3), amount(0))));
+        assert_eq!(split_fee(&amount(10), 0), None);
+    }
+
+    #[test]
+    fn tx_size_bucket_range_exact_bucket() {
+        assert_eq!(tx_size_bucket_range(0, 0), (0, 511));
+        assert_eq!(tx_size_bucket_range(511, 0), (0, 511));
+        assert_eq!(tx_size_bucket_range(512, 0), (512, 1023));
+        assert_eq!(tx_size_bucket_range(1500, 0), (1024, 1535));
+    }
+
+    #[test]
+    fn tx_size_bucket_range_with_neighbours() {
+        assert_eq!(tx_size_bucket_range(1500, 1), (512, 2047));
+        assert_eq!(tx_size_bucket_range(100, 2), (0, 1535));
+    }
+}
@@ -0,0 +1,206 @@
+use bigdecimal::{BigDecimal, Zero};
+use namada_ibc::IbcMessage;
+use namada_ibc::clients::tendermint::types::ClientState as TmClientState;
+use namada_ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
+use namada_ibc::core::handler::types::msgs::MsgEnvelope;
+use namada_sdk::token::Transfer;
+
+#[derive(Debug, Clone)]
+pub enum IbcClientEventKind {
+    Create,
+    Update,
+}
+
+#[derive(Debug, Clone)]
+pub struct IbcClient {
+    pub kind: IbcClientEventKind,
+    pub client_id: String,
+    pub client_type: String,
+    pub consensus_height: String,
+    pub counterparty_chain_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum IbcConnectionState {
+    Init,
+    TryOpen,
+    Open,
+}
+
+#[derive(Debug, Clone)]
+pub struct IbcConnection {
+    pub connection_id: String,
+    pub client_id: String,
+    pub counterparty_client_id: String,
+    pub counterparty_connection_id: Option<String>,
+    pub state: IbcConnectionState,
+}
+
+#[derive(Debug, Clone)]
+pub enum IbcChannelState {
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone)]
+pub struct IbcChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: Option<String>,
+    pub connection_id: String,
+    pub state: IbcChannelState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IbcTraceHop {
+    pub port_id: String,
+    pub channel_id: String,
+}
+
+/// A denom trace split into its hops, the first hop being the channel on
+/// this chain the token was last received through
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IbcDenomTrace {
+    pub hops: Vec<IbcTraceHop>,
+    pub base_denom: String,
+}
+
+impl IbcDenomTrace {
+    pub fn parse(trace: &str) -> Self {
+        let parts = trace.split('/').collect::<Vec<&str>>();
+
+        let mut hops = Vec::new();
+        let mut index = 0;
+        // The base denom can contain slashes too, so a hop is only consumed
+        // when the pair looks like a port followed by a channel
+        while index + 2 < parts.len()
+            && !parts[index].is_empty()
+            && parts[index + 1].starts_with("channel-")
+        {
+            hops.push(IbcTraceHop {
+                port_id: parts[index].to_string(),
+                channel_id: parts[index + 1].to_string(),
+            });
+            index += 2;
+        }
+
+        Self {
+            hops,
+            base_denom: parts[index..].join("/"),
+        }
+    }
+}
+
+/// Share of the throughput limit used by the net flow of a token in an epoch,
+/// as a percentage. A zero limit disallows any transfer so no utilization is
+/// returned.
+pub fn ibc_rate_limit_utilization(
+    deposit: &BigDecimal,
+    withdraw: &BigDecimal,
+    throughput_limit: &BigDecimal,
+) -> Option<BigDecimal> {
+    if throughput_limit.is_zero() {
+        return None;
+    }
+
+    let throughput = (withdraw - deposit).abs();
+
+    Some((throughput * BigDecimal::from(100) / throughput_limit).round(2))
+}
+
+pub fn ibc_create_client_msg(
+    msg: &IbcMessage<Transfer>,
+) -> Option<&MsgCreateClient> {
+    let IbcMessage::Envelope(envelope) = msg else {
+        return None;
+    };
+
+    let MsgEnvelope::Client(ClientMsg::CreateClient(create_client)) =
+        envelope.as_ref()
+    else {
+        return None;
+    };
+
+    Some(create_client)
+}
+
+/// Initial consensus height and chain id of the counterparty tracked by the
+/// created client, only known for tendermint clients
+pub fn ibc_client_height_and_chain_id(
+    msg: &MsgCreateClient,
+) -> Option<(String, String)> {
+    TmClientState::try_from(msg.client_state.clone())
+        .ok()
+        .map(|client_state| {
+            (
+                client_state.latest_height.to_string(),
+                client_state.chain_id.to_string(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn denom_trace_with_single_hop() {
+        let trace = IbcDenomTrace::parse("transfer/channel-0/uatom");
+
+        assert_eq!(
+            trace.hops,
+            vec![IbcTraceHop {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+            }]
+        );
+        assert_eq!(trace.base_denom, "uatom");
+    }
+
+    #[test]
+    fn denom_trace_with_multiple_hops_and_slashed_base_denom() {
+        let trace = IbcDenomTrace::parse(
+            "transfer/channel-4/transfer/channel-141/gamm/pool/1",
+        );
+
+        assert_eq!(trace.hops.len(), 2);
+        assert_eq!(trace.hops[0].channel_id, "channel-4");
+        assert_eq!(trace.hops[1].channel_id, "channel-141");
+        assert_eq!(trace.base_denom, "gamm/pool/1");
+    }
+
+    #[test]
+    fn rate_limit_utilization_uses_net_flow() {
+        let utilization = ibc_rate_limit_utilization(
+            &BigDecimal::from(100),
+            &BigDecimal::from(400),
+            &BigDecimal::from(1000),
+        );
+        assert_eq!(utilization, Some(BigDecimal::from(30)));
+
+        let utilization = ibc_rate_limit_utilization(
+            &BigDecimal::from(400),
+            &BigDecimal::from(100),
+            &BigDecimal::from(1000),
+        );
+        assert_eq!(utilization, Some(BigDecimal::from(30)));
+
+        let utilization = ibc_rate_limit_utilization(
+            &BigDecimal::from(1),
+            &BigDecimal::from(0),
+            &BigDecimal::from(0),
+        );
+        assert_eq!(utilization, None);
+    }
+
+    #[test]
+    fn denom_trace_without_hops() {
+        let trace = IbcDenomTrace::parse("uosmo");
+
+        assert!(trace.hops.is_empty());
+        assert_eq!(trace.base_denom, "uosmo");
+    }
+}
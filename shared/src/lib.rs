@@ -4,11 +4,13 @@ pub mod block_result;
 pub mod checksums;
 pub mod crawler;
 pub mod crawler_state;
+pub mod decentralization;
 pub mod error;
 pub mod futures;
 pub mod gas;
 pub mod genesis;
 pub mod header;
+pub mod ibc;
 pub mod id;
 pub mod log_config;
 pub mod masp;
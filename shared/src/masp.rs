@@ -1,4 +1,9 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+
 use crate::balance::Amount;
+use crate::block::Epoch;
 use crate::id::Id;
 
 #[derive(Debug, Clone)]
@@ -15,3 +20,133 @@ pub struct MaspEntry {
     pub direction: MaspEntryDirection,
     pub inner_tx_id: Id,
 }
+
+/// Shielded reward parameters of a token and the inflation it received in the
+/// last epoch
+#[derive(Debug, Clone)]
+pub struct MaspReward {
+    pub token_address: String,
+    pub epoch: Epoch,
+    pub max_reward_rate: String,
+    pub kp_gain: String,
+    pub kd_gain: String,
+    pub locked_amount_target: String,
+    pub last_inflation: Amount,
+    pub last_locked_amount: Amount,
+    pub reward_rate: String,
+}
+
+/// Value held in the masp pool for a token at the end of an epoch, next to the
+/// balance of the masp address tracked from balance changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaspTvl {
+    pub token_address: String,
+    pub epoch: Epoch,
+    pub tvl: BigDecimal,
+    pub masp_balance: Option<BigDecimal>,
+}
+
+impl MaspTvl {
+    /// Cumulates the net flows of each epoch into the value held in the pool.
+    /// Balances have to be ordered by height, so the last one of an epoch is
+    /// the balance at its end. Epochs without activity carry the previous
+    /// values forward.
+    pub fn series(
+        net_flows: Vec<(String, Epoch, BigDecimal)>,
+        balances: Vec<(String, Epoch, BigDecimal)>,
+    ) -> Vec<Self> {
+        let mut by_token: BTreeMap<
+            String,
+            BTreeMap<Epoch, (Option<BigDecimal>, Option<BigDecimal>)>,
+        > = BTreeMap::new();
+
+        for (token, epoch, net_flow) in net_flows {
+            let entry =
+                by_token.entry(token).or_default().entry(epoch).or_default();
+            entry.0 = Some(entry.0.take().unwrap_or_default() + net_flow);
+        }
+
+        for (token, epoch, balance) in balances {
+            let entry =
+                by_token.entry(token).or_default().entry(epoch).or_default();
+            entry.1 = Some(balance);
+        }
+
+        by_token
+            .into_iter()
+            .flat_map(|(token, epochs)| {
+                let first_epoch = epochs.keys().next().copied().unwrap_or(0);
+                let last_epoch = epochs.keys().last().copied().unwrap_or(0);
+
+                let mut tvl = BigDecimal::from(0);
+                let mut masp_balance = None;
+
+                (first_epoch..=last_epoch)
+                    .map(|epoch| {
+                        if let Some((net_flow, balance)) = epochs.get(&epoch) {
+                            if let Some(net_flow) = net_flow {
+                                tvl += net_flow;
+                            }
+                            if balance.is_some() {
+                                masp_balance.clone_from(balance);
+                            }
+                        }
+
+                        MaspTvl {
+                            token_address: token.clone(),
+                            epoch,
+                            tvl: tvl.clone(),
+                            masp_balance: masp_balance.clone(),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masp_tvl_cumulates_flows_and_carries_balances_forward() {
+        let net_flows = vec![
+            ("token".to_string(), 1, BigDecimal::from(100)),
+            ("token".to_string(), 3, BigDecimal::from(-40)),
+        ];
+        let balances = vec![
+            ("token".to_string(), 1, BigDecimal::from(50)),
+            ("token".to_string(), 1, BigDecimal::from(100)),
+            ("token".to_string(), 3, BigDecimal::from(60)),
+        ];
+
+        let series = MaspTvl::series(net_flows, balances);
+
+        assert_eq!(series.len(), 3);
+        assert_eq!(series[0].tvl, BigDecimal::from(100));
+        assert_eq!(series[0].masp_balance, Some(BigDecimal::from(100)));
+        assert_eq!(series[1].epoch, 2);
+        assert_eq!(series[1].tvl, BigDecimal::from(100));
+        assert_eq!(series[1].masp_balance, Some(BigDecimal::from(100)));
+        assert_eq!(series[2].tvl, BigDecimal::from(60));
+        assert_eq!(series[2].masp_balance, Some(BigDecimal::from(60)));
+    }
+
+    #[test]
+    fn masp_tvl_without_balance() {
+        let net_flows = vec![("token".to_string(), 5, BigDecimal::from(10))];
+
+        let series = MaspTvl::series(net_flows, vec![]);
+
+        assert_eq!(
+            series,
+            vec![MaspTvl {
+                token_address: "token".to_string(),
+                epoch: 5,
+                tvl: BigDecimal::from(10),
+                masp_balance: None,
+            }]
+        );
+    }
+}
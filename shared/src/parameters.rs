@@ -17,6 +17,14 @@ impl Parameters {
     pub fn slash_processing_epoch_offset(&self) -> u64 {
         self.unbonding_length + self.cubic_slashing_window_length + 1
     }
+
+    /// Number of epochs after which a bond unbonded at the current epoch can
+    /// be withdrawn.
+    pub fn withdrawable_epoch_offset(&self) -> u64 {
+        self.pipeline_length
+            + self.unbonding_length
+            + self.cubic_slashing_window_length
+    }
 }
 
 pub type EpochSwitchBlocksDelay = u32;
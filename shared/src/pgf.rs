@@ -1,7 +1,14 @@
+use std::collections::{BTreeMap, HashMap};
+
+use namada_sdk::address::Address;
+use namada_sdk::dec::Dec;
 use serde::Serialize;
 
 use crate::balance::Amount;
+use crate::block::{BlockHeight, Epoch};
+use crate::block_result::TokenTransfer;
 use crate::id::Id;
+use crate::token::Token;
 
 #[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
@@ -33,3 +40,182 @@ pub struct PgfPayment {
     pub amount: Amount,
     pub action: Option<PgfAction>,
 }
+
+#[derive(Debug, Clone)]
+pub struct PgfSteward {
+    pub address: Id,
+    pub commission: String,
+}
+
+impl PgfSteward {
+    pub fn new(address: Address, commission: HashMap<Address, Dec>) -> Self {
+        Self {
+            address: Id::from(address),
+            commission: commission_to_json(commission),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum PgfStewardEventKind {
+    Added,
+    Removed,
+    Resigned,
+    CommissionUpdate,
+}
+
+/// A change to the stewards set, either from an executed steward proposal or
+/// from a steward transaction
+#[derive(Debug, Clone)]
+pub struct PgfStewardEvent {
+    pub address: Id,
+    pub kind: PgfStewardEventKind,
+    pub proposal_id: Option<u64>,
+    pub block_height: Option<BlockHeight>,
+    pub inner_tx_id: Option<Id>,
+    pub commission: Option<String>,
+}
+
+/// Serializes a steward reward distribution as a JSON object sorted by
+/// address
+pub fn commission_to_json(commission: HashMap<Address, Dec>) -> String {
+    let commission = commission
+        .into_iter()
+        .map(|(address, rate)| (address.to_string(), rate.to_string()))
+        .collect::<BTreeMap<_, _>>();
+
+    serde_json::to_string(&commission).unwrap_or_default()
+}
+
+/// Descriptor of the transfer events emitted for native retro pgf payments
+pub const PGF_PAYMENTS_EVENT_DESCRIPTOR: &str = "pgf-payments";
+
+/// Amount actually received by a pgf recipient at the start of an epoch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgfDisbursement {
+    pub receipient: Id,
+    pub token: Token,
+    pub epoch: Epoch,
+    pub block_height: BlockHeight,
+    pub amount: Amount,
+}
+
+impl PgfDisbursement {
+    /// Computes the disbursements executed at the start of the epoch. Retro
+    /// payments are read from the transfer events whose source is the pgf
+    /// account. Continuous payments don't emit any event: they only fail when
+    /// the treasury can't cover them, and a failed payment leaves the treasury
+    /// below its amount for the rest of the block, so only the payments
+    /// covered by the treasury balance at the end of the block are accounted.
+    pub fn from_payments(
+        retro_transfers: &[&TokenTransfer],
+        continuous_payments: &[(Id, Amount)],
+        pgf_address: &Id,
+        treasury_balance: &Amount,
+        native_token: &Id,
+        epoch: Epoch,
+        block_height: BlockHeight,
+    ) -> Vec<Self> {
+        let retro_payments = retro_transfers
+            .iter()
+            .filter(|transfer| {
+                transfer
+                    .sources
+                    .iter()
+                    .any(|source| &source.owner == pgf_address)
+            })
+            .flat_map(|transfer| transfer.targets.iter())
+            .filter(|target| &target.token == native_token)
+            .map(|target| (target.owner.clone(), target.amount.clone()));
+
+        let continuous_payments = continuous_payments
+            .iter()
+            .filter(|(_, amount)| {
+                treasury_balance.checked_sub(amount).is_some()
+            })
+            .cloned();
+
+        retro_payments
+            .chain(continuous_payments)
+            .fold(BTreeMap::new(), |mut acc, (receipient, amount)| {
+                let total = acc.entry(receipient).or_insert_with(Amount::zero);
+                if let Some(sum) = total.checked_add(&amount) {
+                    *total = sum;
+                }
+                acc
+            })
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(receipient, amount)| Self {
+                receipient,
+                token: Token::Native(native_token.clone()),
+                epoch,
+                block_height,
+                amount,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::token::Amount as NamadaAmount;
+
+    use super::*;
+    use crate::block_result::TokenTransferAccount;
+
+    fn amount(value: u64) -> Amount {
+        Amount::from(NamadaAmount::from_u64(value))
+    }
+
+    fn account(owner: &str, value: u64) -> TokenTransferAccount {
+        TokenTransferAccount {
+            owner: Id::Account(owner.to_string()),
+            token: Id::Account("native".to_string()),
+            amount: amount(value),
+        }
+    }
+
+    #[test]
+    fn pgf_disbursements_from_payments() {
+        let retro_transfer = TokenTransfer {
+            descriptor: PGF_PAYMENTS_EVENT_DESCRIPTOR.to_string(),
+            sources: vec![account("pgf", 70)],
+            targets: vec![account("alice", 70)],
+        };
+        let other_transfer = TokenTransfer {
+            descriptor: PGF_PAYMENTS_EVENT_DESCRIPTOR.to_string(),
+            sources: vec![account("dave", 30)],
+            targets: vec![account("carol", 30)],
+        };
+        let continuous_payments = vec![
+            (Id::Account("alice".to_string()), amount(10)),
+            (Id::Account("bob".to_string()), amount(50)),
+            (Id::Account("carol".to_string()), amount(500)),
+        ];
+
+        let disbursements = PgfDisbursement::from_payments(
+            &[&retro_transfer, &other_transfer],
+            &continuous_payments,
+            &Id::Account("pgf".to_string()),
+            &amount(100),
+            &Id::Account("native".to_string()),
+            3,
+            42,
+        );
+
+        assert_eq!(
+            disbursements
+                .into_iter()
+                .map(|disbursement| (
+                    disbursement.receipient.to_string(),
+                    disbursement.amount
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("alice".to_string(), amount(80)),
+                ("bob".to_string(), amount(50)),
+            ]
+        );
+    }
+}
@@ -1,6 +1,7 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use fake::Fake;
+use fake::faker::company::en::CatchPhrase;
 use namada_governance::ProposalType;
 use namada_governance::storage::proposal::{
     AddRemove, PGFAction, PGFIbcTarget, PGFInternalTarget, PGFTarget,
@@ -45,10 +46,52 @@ pub enum GovernanceProposalResult {
     Unknown,
 }
 
+/// The standard fields of a proposal content. Keys are matched ignoring case
+/// and treating `_` as `-`, as proposals are free-form key-value maps.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GovernanceProposalContent {
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub r#abstract: Option<String>,
+    pub motivation: Option<String>,
+    pub details: Option<String>,
+    pub requires: Option<String>,
+    pub created: Option<String>,
+}
+
+impl From<&BTreeMap<String, String>> for GovernanceProposalContent {
+    fn from(content: &BTreeMap<String, String>) -> Self {
+        let fields = content
+            .iter()
+            .filter(|(_, value)| !value.trim().is_empty())
+            .map(|(key, value)| {
+                (
+                    key.trim().to_lowercase().replace('_', "-"),
+                    value.trim().to_string(),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let field = |key: &str| fields.get(key).cloned();
+
+        Self {
+            title: field("title"),
+            authors: field("authors"),
+            discussions_to: field("discussions-to"),
+            r#abstract: field("abstract"),
+            motivation: field("motivation"),
+            details: field("details"),
+            requires: field("requires"),
+            created: field("created"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GovernanceProposal {
     pub id: u64,
     pub content: String,
+    pub structured_content: GovernanceProposalContent,
     pub r#type: GovernanceProposalKind,
     pub data: Option<String>,
     pub author: Id,
@@ -65,6 +108,9 @@ impl From<StorageProposal> for GovernanceProposal {
         Self {
             id: proposal.id,
             content: proposal_content_serialized,
+            structured_content: GovernanceProposalContent::from(
+                &proposal.content,
+            ),
             author: Id::from(proposal.author),
             r#type: proposal.r#type.into(),
             data: None,
@@ -147,6 +193,10 @@ impl GovernanceProposal {
             voting_start_epoch,
             voting_end_epoch,
             activation_epoch,
+            structured_content: GovernanceProposalContent {
+                title: Some(CatchPhrase().fake()),
+                ..GovernanceProposalContent::default()
+            },
             content: "Lorem ipsum dolor sit amet, consectetur adipiscing \
                       elit. Nullam purus tellus, mollis in nisi sed, laoreet \
                       scelerisque ante. Mauris at odio in magna ullamcorper \
@@ -281,3 +331,34 @@ impl Distribution<TallyType> for Standard {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn governance_proposal_content_from_map() {
+        let content = BTreeMap::from([
+            ("title".to_string(), " Upgrade the chain ".to_string()),
+            ("Authors".to_string(), "alice, bob".to_string()),
+            (
+                "discussions_to".to_string(),
+                "https://forum.namada.net/t/1".to_string(),
+            ),
+            ("abstract".to_string(), "".to_string()),
+            ("license".to_string(), "MIT".to_string()),
+        ]);
+
+        assert_eq!(
+            GovernanceProposalContent::from(&content),
+            GovernanceProposalContent {
+                title: Some("Upgrade the chain".to_string()),
+                authors: Some("alice, bob".to_string()),
+                discussions_to: Some(
+                    "https://forum.namada.net/t/1".to_string()
+                ),
+                ..GovernanceProposalContent::default()
+            }
+        );
+    }
+}
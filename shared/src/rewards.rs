@@ -1,4 +1,9 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use bigdecimal::{BigDecimal, Zero};
+
 use crate::balance::Amount;
+use crate::block::Epoch;
 use crate::id::Id;
 use crate::utils::DelegationPair;
 
@@ -24,3 +29,89 @@ impl Reward {
         }
     }
 }
+
+/// Average reward rate per epoch realized by the delegators of a validator,
+/// given the unclaimed rewards of each delegator at consecutive epochs and the
+/// amount bonded to the validator. A decrease between two epochs means the
+/// rewards were claimed, so it is not counted.
+pub fn realized_reward_rate(
+    unclaimed_rewards: &[(String, Epoch, BigDecimal)],
+    bonded_amount: &BigDecimal,
+) -> Option<BigDecimal> {
+    if bonded_amount <= &BigDecimal::zero() {
+        return None;
+    }
+
+    let epochs = unclaimed_rewards
+        .iter()
+        .map(|(_, epoch, _)| *epoch)
+        .collect::<BTreeSet<_>>();
+    let (first_epoch, last_epoch) = (*epochs.first()?, *epochs.last()?);
+    if first_epoch == last_epoch {
+        return None;
+    }
+
+    let mut rewards_by_owner: HashMap<&str, BTreeMap<Epoch, &BigDecimal>> =
+        HashMap::new();
+    for (owner, epoch, amount) in unclaimed_rewards {
+        rewards_by_owner
+            .entry(owner.as_str())
+            .or_default()
+            .insert(*epoch, amount);
+    }
+
+    let accrued = rewards_by_owner
+        .values()
+        .flat_map(|rewards| {
+            rewards.iter().zip(rewards.iter().skip(1)).filter_map(
+                |((prev_epoch, prev), (epoch, current))| {
+                    (epoch - prev_epoch == 1 && current > prev)
+                        .then(|| *current - *prev)
+                },
+            )
+        })
+        .sum::<BigDecimal>();
+
+    Some(accrued / (BigDecimal::from(last_epoch - first_epoch) * bonded_amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn realized_reward_rate_ignores_claims() {
+        let unclaimed_rewards = vec![
+            ("tnam1".to_string(), 1, BigDecimal::from(0)),
+            ("tnam1".to_string(), 2, BigDecimal::from(10)),
+            ("tnam1".to_string(), 3, BigDecimal::from(20)),
+            ("tnam2".to_string(), 1, BigDecimal::from(50)),
+            // Claimed at epoch 2
+            ("tnam2".to_string(), 2, BigDecimal::from(0)),
+            ("tnam2".to_string(), 3, BigDecimal::from(10)),
+        ];
+
+        let rate =
+            realized_reward_rate(&unclaimed_rewards, &BigDecimal::from(1000))
+                .unwrap();
+
+        // 30 accrued over 2 epochs on 1000 bonded
+        assert_eq!(rate, BigDecimal::from(15) / BigDecimal::from(1000));
+    }
+
+    #[test]
+    fn realized_reward_rate_needs_history() {
+        let unclaimed_rewards =
+            vec![("tnam1".to_string(), 1, BigDecimal::from(10))];
+
+        assert!(
+            realized_reward_rate(&unclaimed_rewards, &BigDecimal::from(1000))
+                .is_none()
+        );
+        assert!(realized_reward_rate(&[], &BigDecimal::from(1000)).is_none());
+        assert!(
+            realized_reward_rate(&unclaimed_rewards, &BigDecimal::zero())
+                .is_none()
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use std::fmt::Display;
 
 use bigdecimal::BigDecimal;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
+use crate::ibc::IbcDenomTrace;
 use crate::id::Id;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
@@ -52,3 +53,176 @@ pub struct IbcRateLimit {
     /// Throughput limit of token `address` at epoch `epoch`
     pub throughput_limit: BigDecimal,
 }
+
+/// Base denoms of common IBC tokens with the decimals implied by their SI
+/// prefix, `u` for micro and `a` for atto
+const KNOWN_PREFIXED_DENOMS: [(&str, u32); 12] = [
+    ("uatom", 6),
+    ("uosmo", 6),
+    ("untrn", 6),
+    ("ustrd", 6),
+    ("utia", 6),
+    ("uusdc", 6),
+    ("uakt", 6),
+    ("ujuno", 6),
+    ("ustars", 6),
+    ("aevmos", 18),
+    ("adydx", 18),
+    ("acanto", 18),
+];
+
+/// Display metadata of a token, as listed in the token registry file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenMetadata {
+    pub address: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub name: Option<String>,
+    pub logo_url: Option<String>,
+}
+
+impl TokenMetadata {
+    /// Metadata of the native token when the registry does not list it
+    pub fn native_default(address: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            symbol: "NAM".to_string(),
+            decimals: 6,
+            name: Some("Namada".to_string()),
+            logo_url: None,
+        }
+    }
+
+    /// Metadata derived from the base denom of an IBC token when the registry
+    /// does not list it. Only well known denoms get the decimals of their SI
+    /// prefix, any other denom is left in raw units.
+    pub fn ibc_default(address: &str, trace: &str) -> Self {
+        let base_denom = IbcDenomTrace::parse(trace).base_denom;
+        // Factory and other namespaced denoms end with the actual denom
+        let denom = base_denom.rsplit('/').next().unwrap_or_default();
+
+        let (symbol, decimals) = KNOWN_PREFIXED_DENOMS
+            .iter()
+            .find(|(known_denom, _)| *known_denom == denom)
+            .map(|(known_denom, decimals)| {
+                (known_denom[1..].to_uppercase(), *decimals)
+            })
+            .unwrap_or_else(|| (denom.to_uppercase(), 0));
+
+        Self {
+            address: address.to_string(),
+            symbol,
+            decimals,
+            name: Some(base_denom),
+            logo_url: None,
+        }
+    }
+
+    /// Amount in display units of a raw amount of this token
+    pub fn denominate(&self, raw_amount: &BigDecimal) -> BigDecimal {
+        let (digits, scale) = raw_amount.as_bigint_and_exponent();
+
+        BigDecimal::new(digits, scale + self.decimals as i64).normalized()
+    }
+}
+
+/// Parse a token registry file, a JSON list of token metadata
+pub fn parse_token_registry(
+    content: &str,
+) -> Result<Vec<TokenMetadata>, serde_json::Error> {
+    serde_json::from_str(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn ibc_default_from_micro_denom() {
+        let metadata =
+            TokenMetadata::ibc_default("tnam1abc", "transfer/channel-1/uosmo");
+
+        assert_eq!(metadata.symbol, "OSMO");
+        assert_eq!(metadata.decimals, 6);
+        assert_eq!(metadata.name, Some("uosmo".to_string()));
+    }
+
+    #[test]
+    fn ibc_default_from_atto_denom() {
+        let metadata =
+            TokenMetadata::ibc_default("tnam1abc", "transfer/channel-2/adydx");
+
+        assert_eq!(metadata.symbol, "DYDX");
+        assert_eq!(metadata.decimals, 18);
+    }
+
+    #[test]
+    fn ibc_default_from_unknown_denom() {
+        let metadata = TokenMetadata::ibc_default(
+            "tnam1abc",
+            "transfer/channel-1/unicorn",
+        );
+
+        assert_eq!(metadata.symbol, "UNICORN");
+        assert_eq!(metadata.decimals, 0);
+    }
+
+    #[test]
+    fn ibc_default_from_factory_denom() {
+        let metadata = TokenMetadata::ibc_default(
+            "tnam1abc",
+            "transfer/channel-1/factory/osmo1xyz/token",
+        );
+
+        assert_eq!(metadata.symbol, "TOKEN");
+        assert_eq!(metadata.decimals, 0);
+    }
+
+    #[test]
+    fn denominate_raw_amount() {
+        let metadata = TokenMetadata::native_default("tnam1abc");
+
+        assert_eq!(
+            metadata.denominate(&BigDecimal::from(1_500_000)),
+            BigDecimal::from_str("1.5").unwrap()
+        );
+        assert_eq!(
+            metadata.denominate(&BigDecimal::from(1)),
+            BigDecimal::from_str("0.000001").unwrap()
+        );
+
+        let metadata =
+            TokenMetadata::ibc_default("tnam1abc", "transfer/channel-2/adydx");
+        assert_eq!(
+            metadata.denominate(&BigDecimal::from(1)).to_plain_string(),
+            "0.000000000000000001"
+        );
+    }
+
+    #[test]
+    fn parse_token_registry_entries() {
+        let registry = parse_token_registry(
+            r#"[
+                {"address": "tnam1abc", "symbol": "NAM", "decimals": 6},
+                {
+                    "address": "tnam1def",
+                    "symbol": "OSMO",
+                    "decimals": 6,
+                    "name": "Osmosis",
+                    "logoUrl": "https://example.com/osmo.svg"
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry[0].name, None);
+        assert_eq!(
+            registry[1].logo_url,
+            Some("https://example.com/osmo.svg".to_string())
+        );
+    }
+}
@@ -10,6 +10,7 @@ use namada_sdk::borsh::BorshDeserialize;
 use namada_sdk::key::common::PublicKey;
 use namada_sdk::token::Transfer;
 use namada_sdk::uint::Uint;
+use namada_tx::data::pgf::UpdateStewardCommission;
 use namada_tx::data::pos::{
     BecomeValidator, Bond, ClaimRewards, CommissionChange, ConsensusKeyChange,
     MetaDataChange, Redelegation, Unbond, Withdraw,
@@ -21,7 +22,10 @@ use namada_tx::{IndexedTx, Section, Tx};
 use serde::Serialize;
 
 use crate::block::BlockHeight;
-use crate::block_result::{BlockResult, TxEventStatusCode};
+use crate::block_result::{
+    BlockResult, FungibleTokenPacket, IbcCorePacketKind, IbcPacket,
+    TxEventStatusCode,
+};
 use crate::checksums::Checksums;
 use crate::id::Id;
 use crate::ser::{IbcMessage, TransferData};
@@ -96,6 +100,8 @@ pub enum TransactionKind {
     DeactivateValidator(Option<Address>),
     UnjailValidator(Option<Address>),
     ChangeConsensusKey(Option<ConsensusKeyChange>),
+    UpdateStewardCommission(Option<UpdateStewardCommission>),
+    ResignSteward(Option<Address>),
     Unknown(Option<UnknownTransaction>),
 }
 
@@ -276,6 +282,24 @@ impl TransactionKind {
                 };
                 TransactionKind::ChangeConsensusKey(data)
             }
+            "tx_update_steward_commission" => {
+                let data = if let Ok(data) =
+                    UpdateStewardCommission::try_from_slice(data)
+                {
+                    Some(data)
+                } else {
+                    None
+                };
+                TransactionKind::UpdateStewardCommission(data)
+            }
+            "tx_resign_steward" => {
+                let data = if let Ok(data) = Address::try_from_slice(data) {
+                    Some(data)
+                } else {
+                    None
+                };
+                TransactionKind::ResignSteward(data)
+            }
             _ => {
                 tracing::warn!("Unknown transaction kind: {}", tx_kind_name);
                 TransactionKind::Unknown(Some(UnknownTransaction {
@@ -673,6 +697,55 @@ impl IbcSequence {
     }
 }
 
+/// A stage of the lifecycle of an ibc packet sent or received by the chain
+#[derive(Debug, Clone)]
+pub struct IbcPacketEvent {
+    pub kind: IbcCorePacketKind,
+    pub source_port: String,
+    pub dest_port: String,
+    pub source_channel: String,
+    pub dest_channel: String,
+    pub sequence: u64,
+    pub timeout_height: String,
+    pub timeout_timestamp: u64,
+    pub transfer: Option<FungibleTokenPacket>,
+    pub block_height: BlockHeight,
+}
+
+impl IbcPacketEvent {
+    pub fn new(
+        kind: IbcCorePacketKind,
+        packet: &IbcPacket,
+        block_height: BlockHeight,
+    ) -> Option<Self> {
+        Some(Self {
+            kind,
+            source_port: packet.source_port.clone(),
+            dest_port: packet.dest_port.clone(),
+            source_channel: packet.source_channel.clone(),
+            dest_channel: packet.dest_channel.clone(),
+            sequence: packet.sequence.parse().ok()?,
+            timeout_height: packet.timeout_height.clone(),
+            timeout_timestamp: packet.timeout_timestamp,
+            transfer: packet.fungible_token_packet(),
+            block_height,
+        })
+    }
+
+    /// Same format as [`IbcSequence::id`], so that packets can be matched
+    /// with their acknowledgement status
+    pub fn id(&self) -> String {
+        format!(
+            "{}/{}/{}/{}/{}",
+            self.dest_port,
+            self.dest_channel,
+            self.source_port,
+            self.source_channel,
+            self.sequence
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum IbcAckStatus {
     Success,
@@ -10,15 +10,16 @@ use namada_ibc::core::channel::types::msgs::PacketMsg;
 use namada_ibc::core::channel::types::packet::Packet;
 use namada_ibc::core::handler::types::msgs::MsgEnvelope;
 use namada_ibc::core::host::types::identifiers::{ChannelId, PortId};
-use namada_sdk::address::Address;
+use namada_sdk::address::{Address, InternalAddress};
 use namada_sdk::token::Transfer;
 
+use crate::block::Epoch;
 use crate::id::Id;
 use crate::ser::{self, ChainAddress, TransferData};
 use crate::token::Token;
 use crate::transaction::TransactionKind;
 
-pub(crate) const MASP_ADDRESS: Address =
+pub const MASP_ADDRESS: Address =
     Address::Internal(namada_sdk::address::InternalAddress::Masp);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -40,6 +41,14 @@ pub struct GovernanceProposalShort {
     pub voting_end_epoch: u64,
 }
 
+impl GovernanceProposalShort {
+    /// Epoch of the stake used to compute the votes power, votes are tallied
+    /// with the stake at the end of the voting period
+    pub fn votes_power_epoch(&self, current_epoch: Epoch) -> Epoch {
+        (current_epoch as u64).min(self.voting_end_epoch) as Epoch
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct DelegationPair {
     pub validator_address: Id,
@@ -500,11 +509,64 @@ pub fn is_valid_bech32_address(address: &str, prefix: &str) -> bool {
     }
 }
 
+/// Label of the internal addresses that can hold tokens, e.g. the MASP or the
+/// PoS account
+pub fn internal_address_label(address: &str) -> Option<&'static str> {
+    let Ok(Address::Internal(internal_address)) = address.parse::<Address>()
+    else {
+        return None;
+    };
+
+    match internal_address {
+        InternalAddress::PoS => Some("PoS"),
+        InternalAddress::PosSlashPool => Some("PoS slash pool"),
+        InternalAddress::Pgf => Some("PGF"),
+        InternalAddress::Masp => Some("MASP"),
+        InternalAddress::Ibc => Some("IBC"),
+        InternalAddress::Governance => Some("Governance"),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
+    #[test]
+    fn governance_proposal_votes_power_epoch() {
+        let proposal = GovernanceProposalShort {
+            id: 0,
+            voting_start_epoch: 10,
+            voting_end_epoch: 20,
+        };
+
+        assert_eq!(proposal.votes_power_epoch(15), 15);
+        assert_eq!(proposal.votes_power_epoch(20), 20);
+        assert_eq!(proposal.votes_power_epoch(25), 20);
+    }
+
+    #[test]
+    fn internal_address_labels() {
+        assert_eq!(
+            internal_address_label(&MASP_ADDRESS.to_string()),
+            Some("MASP")
+        );
+        assert_eq!(
+            internal_address_label(
+                &Address::Internal(InternalAddress::Pgf).to_string()
+            ),
+            Some("PGF")
+        );
+        assert_eq!(
+            internal_address_label(
+                "tnam1qq6xmw8crfrprhlmajrq5c28p4dqy0nyjvtvupk5"
+            ),
+            None
+        );
+        assert_eq!(internal_address_label("not an address"), None);
+    }
+
     #[test]
     fn is_valid_bech32_address() {
         let address = "tnam1qq6xmw8crfrprhlmajrq5c28p4dqy0nyjvtvupk5";
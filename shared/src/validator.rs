@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use fake::Fake;
 use fake::faker::company::en::{CatchPhrase, CompanyName};
@@ -6,7 +6,7 @@ use fake::faker::internet::en::{DomainSuffix, SafeEmail, Username};
 use namada_proof_of_stake::types::ValidatorState as NamadaValidatorState;
 use rand::distributions::{Distribution, Standard};
 
-use crate::block::Epoch;
+use crate::block::{BlockHeight, Epoch};
 use crate::id::Id;
 
 pub type VotingPower = String;
@@ -57,6 +57,60 @@ impl ValidatorSet {
             epoch: self.epoch,
         }
     }
+
+    /// Returns the validators whose state differs from the one they had in
+    /// `previous_states`. Validators missing from `previous_states` are
+    /// reported as newly entering the set, and consensus or below capacity
+    /// validators missing from this set are reported as leaving it.
+    pub fn changes_since(
+        &self,
+        previous_states: &HashMap<Id, ValidatorState>,
+    ) -> Vec<ValidatorSetChange> {
+        let changed = self.validators.iter().filter_map(|validator| {
+            let previous_state = previous_states.get(&validator.address);
+            if previous_state == Some(&validator.state) {
+                return None;
+            }
+
+            Some(ValidatorSetChange {
+                address: validator.address.clone(),
+                epoch: self.epoch,
+                previous_state: previous_state.cloned(),
+                current_state: Some(validator.state.clone()),
+            })
+        });
+
+        let current_addresses = self
+            .validators
+            .iter()
+            .map(|validator| &validator.address)
+            .collect::<HashSet<_>>();
+        let left = previous_states
+            .iter()
+            .filter(|(address, state)| {
+                matches!(
+                    state,
+                    ValidatorState::Consensus | ValidatorState::BelowCapacity
+                ) && !current_addresses.contains(address)
+            })
+            .map(|(address, state)| ValidatorSetChange {
+                address: address.clone(),
+                epoch: self.epoch,
+                previous_state: Some(state.clone()),
+                current_state: None,
+            });
+
+        changed.chain(left).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSetChange {
+    pub address: Id,
+    pub epoch: Epoch,
+    pub previous_state: Option<ValidatorState>,
+    /// `None` when the validator is no longer part of the set
+    pub current_state: Option<ValidatorState>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -92,6 +146,26 @@ pub struct ValidatorStateChange {
     pub state: ValidatorState,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidatorEventKind {
+    BecomeValidator,
+    Deactivation,
+    Reactivation,
+    Unjail,
+    MetadataChange,
+    CommissionChange,
+    ConsensusKeyChange,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidatorEvent {
+    pub address: Id,
+    pub kind: ValidatorEventKind,
+    pub block_height: BlockHeight,
+    pub inner_tx_id: Id,
+    pub data: Option<String>,
+}
+
 impl Validator {
     pub fn fake() -> Self {
         let address =
@@ -140,3 +214,64 @@ impl Distribution<ValidatorState> for Standard {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator_with_state(address: &str, state: ValidatorState) -> Validator {
+        Validator {
+            address: Id::Account(address.to_string()),
+            state,
+            ..Validator::fake()
+        }
+    }
+
+    #[test]
+    fn validator_set_changes_since_previous_states() {
+        let validator_set = ValidatorSet {
+            validators: HashSet::from([
+                validator_with_state("tnam1", ValidatorState::Consensus),
+                validator_with_state("tnam2", ValidatorState::BelowCapacity),
+                validator_with_state("tnam3", ValidatorState::Consensus),
+            ]),
+            epoch: 10,
+        };
+        let previous_states = HashMap::from([
+            (Id::Account("tnam1".to_string()), ValidatorState::Consensus),
+            (Id::Account("tnam2".to_string()), ValidatorState::Consensus),
+            (
+                Id::Account("tnam4".to_string()),
+                ValidatorState::BelowCapacity,
+            ),
+            (Id::Account("tnam5".to_string()), ValidatorState::Jailed),
+        ]);
+
+        let mut changes = validator_set.changes_since(&previous_states);
+        changes.sort_by(|a, b| a.address.cmp(&b.address));
+
+        assert_eq!(
+            changes,
+            vec![
+                ValidatorSetChange {
+                    address: Id::Account("tnam2".to_string()),
+                    epoch: 10,
+                    previous_state: Some(ValidatorState::Consensus),
+                    current_state: Some(ValidatorState::BelowCapacity),
+                },
+                ValidatorSetChange {
+                    address: Id::Account("tnam3".to_string()),
+                    epoch: 10,
+                    previous_state: None,
+                    current_state: Some(ValidatorState::Consensus),
+                },
+                ValidatorSetChange {
+                    address: Id::Account("tnam4".to_string()),
+                    epoch: 10,
+                    previous_state: Some(ValidatorState::BelowCapacity),
+                    current_state: None,
+                },
+            ]
+        );
+    }
+}
@@ -1,8 +1,14 @@
+use std::collections::HashMap;
+
+use bigdecimal::BigDecimal;
 use namada_governance::ProposalVote;
 use rand::distributions::{Distribution, Standard};
 use serde::{Deserialize, Serialize};
 
+use crate::balance::Amount;
+use crate::block::BlockHeight;
 use crate::id::Id;
+use crate::proposal::GovernanceProposalResult;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub enum ProposalVoteKind {
@@ -43,6 +49,234 @@ impl GovernanceVote {
     }
 }
 
+/// A vote as cast on chain, kept even if the voter later changes it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GovernanceVoteCast {
+    pub proposal_id: u64,
+    pub vote: ProposalVoteKind,
+    pub address: Id,
+    pub block_height: BlockHeight,
+    pub inner_tx_id: Id,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VotesTally {
+    pub yay_power: BigDecimal,
+    pub nay_power: BigDecimal,
+    pub abstain_power: BigDecimal,
+    pub yay_voters: u64,
+    pub nay_voters: u64,
+    pub abstain_voters: u64,
+}
+
+impl VotesTally {
+    fn add(&mut self, vote: &ProposalVoteKind, power: &BigDecimal) {
+        match vote {
+            ProposalVoteKind::Yay => {
+                self.yay_power += power;
+                self.yay_voters += 1;
+            }
+            ProposalVoteKind::Nay => {
+                self.nay_power += power;
+                self.nay_voters += 1;
+            }
+            ProposalVoteKind::Abstain => {
+                self.abstain_power += power;
+                self.abstain_voters += 1;
+            }
+            ProposalVoteKind::Unknown => (),
+        }
+    }
+
+    fn remove(&mut self, vote: &ProposalVoteKind, power: &BigDecimal) {
+        match vote {
+            ProposalVoteKind::Yay => {
+                self.yay_power -= power;
+                self.yay_voters -= 1;
+            }
+            ProposalVoteKind::Nay => {
+                self.nay_power -= power;
+                self.nay_voters -= 1;
+            }
+            ProposalVoteKind::Abstain => {
+                self.abstain_power -= power;
+                self.abstain_voters -= 1;
+            }
+            ProposalVoteKind::Unknown => (),
+        }
+    }
+}
+
+/// Computes the cumulative tally at the end of each bucket (e.g. an epoch or
+/// an hour) from the votes ordered by the time they were cast. A new vote from
+/// the same voter replaces their previous one.
+pub fn cumulative_votes_timeline<B: PartialEq>(
+    votes: impl IntoIterator<Item = (B, String, ProposalVoteKind, BigDecimal)>,
+) -> Vec<(B, VotesTally)> {
+    let mut current_votes: HashMap<String, (ProposalVoteKind, BigDecimal)> =
+        HashMap::new();
+    let mut tally = VotesTally::default();
+    let mut timeline: Vec<(B, VotesTally)> = vec![];
+
+    for (bucket, voter, vote, power) in votes {
+        tally.add(&vote, &power);
+        if let Some((previous_vote, previous_power)) =
+            current_votes.insert(voter, (vote, power))
+        {
+            tally.remove(&previous_vote, &previous_power);
+        }
+
+        match timeline.last_mut() {
+            Some((last_bucket, last_tally)) if *last_bucket == bucket => {
+                *last_tally = tally.clone();
+            }
+            _ => timeline.push((bucket, tally.clone())),
+        }
+    }
+
+    timeline
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GovernanceVotePower {
+    pub proposal_id: u64,
+    pub address: Id,
+    pub is_validator: bool,
+    pub voting_power: Amount,
+}
+
+impl GovernanceVotePower {
+    /// Computes the effective voting power of each voter the same way the
+    /// tally does: a delegator vote overrides the vote of the validator for
+    /// the bonded amount, which is then removed from the validator power.
+    /// `delegator_bonds` is keyed by (delegator, validator).
+    pub fn compute(
+        proposal_id: u64,
+        validator_stakes: HashMap<Id, Amount>,
+        delegator_bonds: HashMap<(Id, Id), Amount>,
+    ) -> Vec<Self> {
+        let mut delegators_power: HashMap<Id, Amount> = HashMap::new();
+        let mut overridden_power: HashMap<Id, Amount> = HashMap::new();
+
+        for ((delegator, validator), amount) in delegator_bonds {
+            add_power(&mut delegators_power, delegator, &amount);
+            add_power(&mut overridden_power, validator, &amount);
+        }
+
+        let validators =
+            validator_stakes.into_iter().map(|(address, stake)| {
+                let voting_power = match overridden_power.get(&address) {
+                    Some(overridden) => stake
+                        .checked_sub(overridden)
+                        .unwrap_or_else(Amount::zero),
+                    None => stake,
+                };
+
+                Self {
+                    proposal_id,
+                    address,
+                    is_validator: true,
+                    voting_power,
+                }
+            });
+
+        let delegators =
+            delegators_power
+                .into_iter()
+                .map(|(address, voting_power)| Self {
+                    proposal_id,
+                    address,
+                    is_validator: false,
+                    voting_power,
+                });
+
+        validators.chain(delegators).collect()
+    }
+}
+
+fn add_power(powers: &mut HashMap<Id, Amount>, address: Id, amount: &Amount) {
+    let power = powers.entry(address).or_insert_with(Amount::zero);
+    *power = power
+        .checked_add(amount)
+        .expect("Voting power should not overflow");
+}
+
+/// How often an address votes on governance proposals and how often its yay
+/// or nay votes agree with the final result. Abstain votes count towards
+/// participation but not towards alignment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GovernanceParticipation {
+    pub eligible_proposals: u64,
+    pub voted_proposals: u64,
+    pub decided_votes: u64,
+    pub aligned_votes: u64,
+}
+
+impl GovernanceParticipation {
+    pub fn compute(
+        eligible_proposals: u64,
+        votes: impl IntoIterator<
+            Item = (ProposalVoteKind, GovernanceProposalResult),
+        >,
+    ) -> Self {
+        let participation = votes.into_iter().fold(
+            Self {
+                eligible_proposals,
+                ..Self::default()
+            },
+            |mut participation, (vote, result)| {
+                participation.voted_proposals += 1;
+
+                let passed = match result {
+                    GovernanceProposalResult::Passed
+                    | GovernanceProposalResult::ExecutedPassed => Some(true),
+                    GovernanceProposalResult::Rejected
+                    | GovernanceProposalResult::ExecutedRejected => Some(false),
+                    _ => None,
+                };
+
+                match (vote, passed) {
+                    (ProposalVoteKind::Yay, Some(passed)) => {
+                        participation.decided_votes += 1;
+                        participation.aligned_votes += u64::from(passed);
+                    }
+                    (ProposalVoteKind::Nay, Some(passed)) => {
+                        participation.decided_votes += 1;
+                        participation.aligned_votes += u64::from(!passed);
+                    }
+                    _ => (),
+                }
+
+                participation
+            },
+        );
+
+        participation.with_eligible_votes()
+    }
+
+    /// Proposals that were voted on are always eligible, even if the voter
+    /// seemingly appeared on chain after they ended
+    fn with_eligible_votes(mut self) -> Self {
+        self.eligible_proposals =
+            self.eligible_proposals.max(self.voted_proposals);
+        self
+    }
+
+    pub fn participation_rate(&self) -> Option<f64> {
+        if self.eligible_proposals == 0 {
+            return None;
+        }
+        Some(self.voted_proposals as f64 / self.eligible_proposals as f64)
+    }
+
+    pub fn alignment_rate(&self) -> Option<f64> {
+        if self.decided_votes == 0 {
+            return None;
+        }
+        Some(self.aligned_votes as f64 / self.decided_votes as f64)
+    }
+}
+
 impl Distribution<ProposalVoteKind> for Standard {
     fn sample<R: rand::prelude::Rng + ?Sized>(
         &self,
@@ -55,3 +289,153 @@ impl Distribution<ProposalVoteKind> for Standard {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::token::Amount as NamadaAmount;
+
+    use super::*;
+
+    fn amount(value: u64) -> Amount {
+        Amount::from(NamadaAmount::from_u64(value))
+    }
+
+    fn id(address: &str) -> Id {
+        Id::Account(address.to_string())
+    }
+
+    #[test]
+    fn governance_participation_counts_aligned_votes() {
+        let votes = vec![
+            (ProposalVoteKind::Yay, GovernanceProposalResult::Passed),
+            (
+                ProposalVoteKind::Nay,
+                GovernanceProposalResult::ExecutedPassed,
+            ),
+            (ProposalVoteKind::Nay, GovernanceProposalResult::Rejected),
+            (ProposalVoteKind::Abstain, GovernanceProposalResult::Passed),
+            (
+                ProposalVoteKind::Yay,
+                GovernanceProposalResult::VotingPeriod,
+            ),
+        ];
+
+        let participation = GovernanceParticipation::compute(10, votes.clone());
+
+        assert_eq!(
+            participation,
+            GovernanceParticipation {
+                eligible_proposals: 10,
+                voted_proposals: 5,
+                decided_votes: 3,
+                aligned_votes: 2,
+            }
+        );
+        assert_eq!(participation.participation_rate(), Some(0.5));
+        assert_eq!(GovernanceParticipation::default().alignment_rate(), None);
+
+        let participation = GovernanceParticipation::compute(2, votes);
+        assert_eq!(participation.eligible_proposals, 5);
+        assert_eq!(participation.participation_rate(), Some(1.0));
+    }
+
+    #[test]
+    fn cumulative_votes_timeline_replaces_changed_votes() {
+        let votes = vec![
+            (
+                1,
+                "alice".to_string(),
+                ProposalVoteKind::Yay,
+                BigDecimal::from(10),
+            ),
+            (
+                1,
+                "bob".to_string(),
+                ProposalVoteKind::Nay,
+                BigDecimal::from(5),
+            ),
+            (
+                2,
+                "alice".to_string(),
+                ProposalVoteKind::Nay,
+                BigDecimal::from(10),
+            ),
+            (
+                4,
+                "carol".to_string(),
+                ProposalVoteKind::Abstain,
+                BigDecimal::from(1),
+            ),
+        ];
+
+        let timeline = cumulative_votes_timeline(votes);
+
+        assert_eq!(
+            timeline,
+            vec![
+                (
+                    1,
+                    VotesTally {
+                        yay_power: BigDecimal::from(10),
+                        nay_power: BigDecimal::from(5),
+                        yay_voters: 1,
+                        nay_voters: 1,
+                        ..VotesTally::default()
+                    }
+                ),
+                (
+                    2,
+                    VotesTally {
+                        nay_power: BigDecimal::from(15),
+                        nay_voters: 2,
+                        ..VotesTally::default()
+                    }
+                ),
+                (
+                    4,
+                    VotesTally {
+                        nay_power: BigDecimal::from(15),
+                        abstain_power: BigDecimal::from(1),
+                        nay_voters: 2,
+                        abstain_voters: 1,
+                        ..VotesTally::default()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn governance_vote_power_removes_delegator_overrides() {
+        let validator_stakes = HashMap::from([
+            (id("validator1"), amount(1000)),
+            (id("validator2"), amount(500)),
+        ]);
+        let delegator_bonds = HashMap::from([
+            ((id("delegator1"), id("validator1")), amount(100)),
+            ((id("delegator1"), id("validator2")), amount(50)),
+            ((id("delegator2"), id("validator1")), amount(200)),
+        ]);
+
+        let mut powers =
+            GovernanceVotePower::compute(1, validator_stakes, delegator_bonds);
+        powers.sort_by(|a, b| a.address.cmp(&b.address));
+
+        let powers = powers
+            .into_iter()
+            .map(|power| {
+                (power.address, power.is_validator, power.voting_power)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            powers,
+            vec![
+                (id("delegator1"), false, amount(150)),
+                (id("delegator2"), false, amount(200)),
+                (id("validator1"), true, amount(700)),
+                (id("validator2"), true, amount(450)),
+            ]
+        );
+    }
+}
@@ -193,10 +193,21 @@ async fn crawling_fn(
     let transaction_sources = block.sources();
     let masp_entries = block.masp_entries();
     let gas_estimates = tx_service::get_gas_estimates(&block.transactions);
+    let fee_rollups = tx_service::get_fee_rollups(
+        &block.transactions,
+        block.epoch,
+        block.header.timestamp,
+    );
 
     let ibc_sequence_packet =
         tx_service::get_ibc_packets(&block_results, &block.transactions);
     let ibc_ack_packet = tx_service::get_ibc_ack_packet(&inner_txs);
+    let ibc_packet_events =
+        tx_service::get_ibc_packet_events(&block_results, block_height);
+    let ibc_clients =
+        tx_service::get_ibc_clients(&block_results, &block.transactions);
+    let ibc_connections = tx_service::get_ibc_connections(&block_results);
+    let ibc_channels = tx_service::get_ibc_channels(&block_results);
 
     let ibc_token_flows = {
         let epoch =
@@ -293,6 +304,29 @@ async fn crawling_fn(
                     ibc_ack_packet,
                 )?;
 
+                transaction_repo::upsert_ibc_packets(
+                    transaction_conn,
+                    ibc_packet_events,
+                )?;
+
+                transaction_repo::upsert_ibc_clients(
+                    transaction_conn,
+                    ibc_clients,
+                    block_height,
+                )?;
+
+                transaction_repo::upsert_ibc_connections(
+                    transaction_conn,
+                    ibc_connections,
+                    block_height,
+                )?;
+
+                transaction_repo::upsert_ibc_channels(
+                    transaction_conn,
+                    ibc_channels,
+                    block_height,
+                )?;
+
                 transaction_repo::upsert_ibc_token_flows(
                     transaction_conn,
                     ibc_token_flows,
@@ -308,6 +342,12 @@ async fn crawling_fn(
                     gas_estimates,
                 )?;
 
+                transaction_repo::upsert_fee_rollups(
+                    transaction_conn,
+                    fee_rollups,
+                    block_height,
+                )?;
+
                 masp_repo::insert_masp_entries(transaction_conn, masp_entries)?;
 
                 anyhow::Ok(())
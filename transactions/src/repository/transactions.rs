@@ -8,24 +8,29 @@ use diesel::{
     RunQueryDsl,
 };
 use orm::crawler_state::{BlockStateInsertDb, CrawlerNameDb};
-use orm::gas::GasEstimationInsertDb;
+use orm::gas::{FeeRollupInsertDb, GasEstimationInsertDb};
 use orm::ibc::{
-    IbcAckInsertDb, IbcAckStatusDb, IbcSequencekStatusUpdateDb,
+    IbcAckInsertDb, IbcAckStatusDb, IbcChannelInsertDb, IbcClientInsertDb,
+    IbcConnectionInsertDb, IbcPacketInsertDb, IbcSequencekStatusUpdateDb,
     IbcTokenFlowsInsertDb,
 };
 use orm::schema::{
-    crawler_state, gas_estimations, ibc_ack, ibc_token_flows,
+    crawler_state, fee_rollup_blocks, fee_rollups, gas_estimations, ibc_ack,
+    ibc_channels, ibc_clients, ibc_connections, ibc_packets, ibc_token_flows,
     inner_transactions, transaction_history, wrapper_transactions,
 };
 use orm::transactions::{
     InnerTransactionInsertDb, TransactionHistoryInsertDb,
     WrapperTransactionInsertDb,
 };
+use shared::block::BlockHeight;
+use shared::block_result::IbcCorePacketKind;
 use shared::crawler_state::{BlockCrawlerState, CrawlerName};
-use shared::gas::GasEstimation;
+use shared::gas::{FeeRollup, GasEstimation};
+use shared::ibc::{IbcChannel, IbcClient, IbcClientEventKind, IbcConnection};
 use shared::transaction::{
-    IbcAck, IbcSequence, IbcTokenFlow, InnerTransaction, TransactionTarget,
-    WrapperTransaction,
+    IbcAck, IbcPacketEvent, IbcSequence, IbcTokenFlow, InnerTransaction,
+    TransactionTarget, WrapperTransaction,
 };
 
 pub fn insert_inner_transactions(
@@ -47,6 +52,7 @@ pub fn insert_inner_transactions(
             // that were previously marked as "unknown".
             inner_transactions::kind.eq(excluded(inner_transactions::kind)),
             inner_transactions::data.eq(excluded(inner_transactions::data)),
+            inner_transactions::notes.eq(excluded(inner_transactions::notes)),
         ))
         .execute(transaction_conn)
         .context("Failed to insert inner transactions in db")?;
@@ -143,6 +149,130 @@ pub fn update_ibc_sequence(
     anyhow::Ok(())
 }
 
+pub fn upsert_ibc_packets(
+    transaction_conn: &mut PgConnection,
+    ibc_packets: Vec<IbcPacketEvent>,
+) -> anyhow::Result<()> {
+    for packet in ibc_packets {
+        let kind = packet.kind.clone();
+        let query = diesel::insert_into(ibc_packets::table)
+            .values(IbcPacketInsertDb::from(packet));
+
+        // Send and recv events create the packet, while acknowledgements and
+        // timeouts only move it forward in its lifecycle
+        let result = match kind {
+            IbcCorePacketKind::Send | IbcCorePacketKind::Recv => {
+                query.on_conflict_do_nothing().execute(transaction_conn)
+            }
+            IbcCorePacketKind::Ack => query
+                .on_conflict(ibc_packets::id)
+                .do_update()
+                .set((
+                    ibc_packets::status.eq(excluded(ibc_packets::status)),
+                    ibc_packets::acknowledged_at_height
+                        .eq(excluded(ibc_packets::acknowledged_at_height)),
+                ))
+                .execute(transaction_conn),
+            IbcCorePacketKind::Timeout => query
+                .on_conflict(ibc_packets::id)
+                .do_update()
+                .set((
+                    ibc_packets::status.eq(excluded(ibc_packets::status)),
+                    ibc_packets::timed_out_at_height
+                        .eq(excluded(ibc_packets::timed_out_at_height)),
+                ))
+                .execute(transaction_conn),
+        };
+        result.context("Failed to upsert ibc packet in db")?;
+    }
+
+    anyhow::Ok(())
+}
+
+pub fn upsert_ibc_clients(
+    transaction_conn: &mut PgConnection,
+    clients: Vec<IbcClient>,
+    block_height: BlockHeight,
+) -> anyhow::Result<()> {
+    for client in clients {
+        let kind = client.kind.clone();
+        let query = diesel::insert_into(ibc_clients::table)
+            .values(IbcClientInsertDb::from_ibc_client(client, block_height));
+
+        let result = match kind {
+            IbcClientEventKind::Create => {
+                query.on_conflict_do_nothing().execute(transaction_conn)
+            }
+            IbcClientEventKind::Update => query
+                .on_conflict(ibc_clients::client_id)
+                .do_update()
+                .set((
+                    ibc_clients::latest_height
+                        .eq(excluded(ibc_clients::latest_height)),
+                    ibc_clients::updated_at_height
+                        .eq(excluded(ibc_clients::updated_at_height)),
+                ))
+                .execute(transaction_conn),
+        };
+        result.context("Failed to upsert ibc client in db")?;
+    }
+
+    anyhow::Ok(())
+}
+
+pub fn upsert_ibc_connections(
+    transaction_conn: &mut PgConnection,
+    connections: Vec<IbcConnection>,
+    block_height: BlockHeight,
+) -> anyhow::Result<()> {
+    // A connection can go through several handshake steps in the same block,
+    // so we upsert them one by one
+    for connection in connections {
+        diesel::insert_into(ibc_connections::table)
+            .values(IbcConnectionInsertDb::from_ibc_connection(
+                connection,
+                block_height,
+            ))
+            .on_conflict(ibc_connections::connection_id)
+            .do_update()
+            .set((
+                ibc_connections::counterparty_connection_id
+                    .eq(excluded(ibc_connections::counterparty_connection_id)),
+                ibc_connections::state.eq(excluded(ibc_connections::state)),
+                ibc_connections::updated_at_height
+                    .eq(excluded(ibc_connections::updated_at_height)),
+            ))
+            .execute(transaction_conn)
+            .context("Failed to upsert ibc connection in db")?;
+    }
+
+    anyhow::Ok(())
+}
+
+pub fn upsert_ibc_channels(
+    transaction_conn: &mut PgConnection,
+    channels: Vec<IbcChannel>,
+    block_height: BlockHeight,
+) -> anyhow::Result<()> {
+    for channel in channels {
+        diesel::insert_into(ibc_channels::table)
+            .values(IbcChannelInsertDb::from_ibc_channel(channel, block_height))
+            .on_conflict(ibc_channels::channel_id)
+            .do_update()
+            .set((
+                ibc_channels::counterparty_channel_id
+                    .eq(excluded(ibc_channels::counterparty_channel_id)),
+                ibc_channels::state.eq(excluded(ibc_channels::state)),
+                ibc_channels::updated_at_height
+                    .eq(excluded(ibc_channels::updated_at_height)),
+            ))
+            .execute(transaction_conn)
+            .context("Failed to upsert ibc channel in db")?;
+    }
+
+    anyhow::Ok(())
+}
+
 pub fn insert_transactions_history(
     transaction_conn: &mut PgConnection,
     txs: HashSet<TransactionTarget>,
@@ -222,3 +352,49 @@ where
 
     anyhow::Ok(())
 }
+
+pub fn upsert_fee_rollups(
+    transaction_conn: &mut PgConnection,
+    rollups: Vec<FeeRollup>,
+    block_height: BlockHeight,
+) -> anyhow::Result<()> {
+    if rollups.is_empty() {
+        return anyhow::Ok(());
+    }
+
+    // Rollups are summed, so the fees of a block must only be added once even
+    // if the block is processed again
+    let inserted = diesel::insert_into(fee_rollup_blocks::table)
+        .values(fee_rollup_blocks::dsl::height.eq(block_height as i32))
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert fee rollup block in db")?;
+
+    if inserted == 0 {
+        return anyhow::Ok(());
+    }
+
+    diesel::insert_into(fee_rollups::table)
+        .values::<Vec<FeeRollupInsertDb>>(
+            rollups.into_iter().map(FeeRollupInsertDb::from).collect(),
+        )
+        .on_conflict((
+            fee_rollups::dsl::token,
+            fee_rollups::dsl::fee_payer,
+            fee_rollups::dsl::kind,
+            fee_rollups::dsl::epoch,
+            fee_rollups::dsl::hour,
+        ))
+        .do_update()
+        .set((
+            fee_rollups::dsl::amount
+                .eq(fee_rollups::dsl::amount
+                    + excluded(fee_rollups::dsl::amount)),
+            fee_rollups::dsl::transactions.eq(fee_rollups::dsl::transactions
+                + excluded(fee_rollups::dsl::transactions)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to upsert fee rollups in db")?;
+
+    anyhow::Ok(())
+}
@@ -1,12 +1,22 @@
+use std::collections::HashMap;
+
 use bigdecimal::BigDecimal;
+use namada_sdk::ibc::clients::tendermint::types::TENDERMINT_CLIENT_TYPE;
 use namada_sdk::ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
 use namada_sdk::ibc::core::channel::types::msgs::PacketMsg;
 use namada_sdk::ibc::core::handler::types::msgs::MsgEnvelope;
+use shared::balance::Amount;
+use shared::block::{BlockHeight, Epoch};
 use shared::block_result::{BlockResult, TxAttributesType};
-use shared::gas::GasEstimation;
+use shared::gas::{FeeRollup, GasEstimation, fee_paid, split_fee};
+use shared::ibc::{
+    IbcChannel, IbcClient, IbcClientEventKind, IbcConnection,
+    ibc_client_height_and_chain_id, ibc_create_client_msg,
+};
 use shared::transaction::{
-    IbcAck, IbcAckStatus, IbcSequence, IbcTokenAction, InnerTransaction,
-    TransactionKind, WrapperTransaction, ibc_denom_received, ibc_denom_sent,
+    IbcAck, IbcAckStatus, IbcPacketEvent, IbcSequence, IbcTokenAction,
+    InnerTransaction, TransactionKind, WrapperTransaction, ibc_denom_received,
+    ibc_denom_sent,
 };
 
 pub fn get_ibc_token_flows(
@@ -84,6 +94,98 @@ pub fn get_ibc_packets(
         .collect::<Vec<_>>()
 }
 
+pub fn get_ibc_packet_events(
+    block_results: &BlockResult,
+    block_height: BlockHeight,
+) -> Vec<IbcPacketEvent> {
+    block_results
+        .end_events
+        .iter()
+        .filter_map(|event| {
+            let (kind, packet) = event.attributes.as_ref()?.as_ibc_packet()?;
+            IbcPacketEvent::new(kind, packet, block_height)
+        })
+        .collect()
+}
+
+pub fn get_ibc_clients(
+    block_results: &BlockResult,
+    txs: &[(WrapperTransaction, Vec<InnerTransaction>)],
+) -> Vec<IbcClient> {
+    // The client identifier is only found in the events, while the chain id is
+    // only found in the tendermint client state of the message, so they are
+    // matched on the client type and the initial consensus height
+    let mut client_states: Vec<_> = txs.iter().fold(
+        Default::default(),
+        |mut acc, (wrapper_tx, inner_txs)| {
+            for inner_tx in inner_txs {
+                let TransactionKind::IbcMsg(Some(ibc_message)) = &inner_tx.kind
+                else {
+                    continue;
+                };
+                if !inner_tx.was_successful(wrapper_tx) {
+                    continue;
+                }
+                if let Some(client_state) =
+                    ibc_create_client_msg(&ibc_message.0)
+                        .and_then(ibc_client_height_and_chain_id)
+                {
+                    acc.push(client_state)
+                }
+            }
+            acc
+        },
+    );
+
+    block_results
+        .end_events
+        .iter()
+        .filter_map(|event| match &event.attributes {
+            Some(TxAttributesType::IbcClient(client)) => {
+                let mut client = client.clone();
+                if matches!(client.kind, IbcClientEventKind::Create)
+                    && client.client_type == TENDERMINT_CLIENT_TYPE
+                {
+                    client.counterparty_chain_id = client_states
+                        .iter()
+                        .position(|(height, _)| {
+                            height == &client.consensus_height
+                        })
+                        .map(|index| client_states.remove(index).1);
+                }
+                Some(client)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn get_ibc_connections(block_results: &BlockResult) -> Vec<IbcConnection> {
+    block_results
+        .end_events
+        .iter()
+        .filter_map(|event| match &event.attributes {
+            Some(TxAttributesType::IbcConnection(connection)) => {
+                Some(connection.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn get_ibc_channels(block_results: &BlockResult) -> Vec<IbcChannel> {
+    block_results
+        .end_events
+        .iter()
+        .filter_map(|event| match &event.attributes {
+            Some(TxAttributesType::IbcChannel(channel)) => {
+                Some(channel.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 pub fn get_ibc_ack_packet(inner_txs: &[InnerTransaction]) -> Vec<IbcAck> {
     inner_txs.iter().filter_map(|tx| match tx.kind.clone() {
         TransactionKind::IbcMsg(Some(ibc_message)) => match ibc_message.0 {
@@ -212,6 +314,8 @@ pub fn get_gas_estimates(
                     gas_estimate.increase_ibc_unshielding_transfer(notes)
                 }
                 TransactionKind::ChangeConsensusKey(_)
+                | TransactionKind::UpdateStewardCommission(_)
+                | TransactionKind::ResignSteward(_)
                 | TransactionKind::IbcMsg(_)
                 | TransactionKind::InitAccount(_)
                 | TransactionKind::InitProposal(_)
@@ -227,3 +331,71 @@ pub fn get_gas_estimates(
         })
         .collect()
 }
+
+pub fn get_fee_rollups(
+    txs: &[(WrapperTransaction, Vec<InnerTransaction>)],
+    epoch: Epoch,
+    timestamp: i64,
+) -> Vec<FeeRollup> {
+    let mut rollups = HashMap::new();
+
+    for (wrapper_tx, inner_txs) in txs {
+        let Some(fee) =
+            wrapper_tx
+                .fee
+                .gas
+                .parse::<u64>()
+                .ok()
+                .and_then(|gas_limit| {
+                    fee_paid(gas_limit, &wrapper_tx.fee.amount_per_gas_unit)
+                })
+        else {
+            continue;
+        };
+        // The fee is paid once per wrapper, so we split it evenly between its
+        // inner transactions and attribute the remainder to the one with the
+        // lowest id, so that the shares add up to the fee
+        let Some((share, remainder)) = split_fee(&fee, inner_txs.len() as u64)
+        else {
+            continue;
+        };
+        let remainder_tx_id =
+            inner_txs.iter().map(|inner_tx| &inner_tx.tx_id).min();
+
+        for inner_tx in inner_txs {
+            let amount = if Some(&inner_tx.tx_id) == remainder_tx_id {
+                share.checked_add(&remainder)
+            } else {
+                Some(share.clone())
+            };
+            let Some(amount) = amount else {
+                continue;
+            };
+
+            // All the transactions of a block share the same epoch and hour,
+            // so the rollups only need to be keyed by token, payer and kind
+            let rollup = rollups
+                .entry((
+                    wrapper_tx.fee.gas_token.clone(),
+                    wrapper_tx.fee.gas_payer.clone(),
+                    std::mem::discriminant(&inner_tx.kind),
+                ))
+                .or_insert_with(|| FeeRollup {
+                    token: wrapper_tx.fee.gas_token.clone(),
+                    fee_payer: wrapper_tx.fee.gas_payer.clone(),
+                    kind: inner_tx.kind.clone(),
+                    epoch,
+                    timestamp,
+                    amount: Amount::zero(),
+                    transactions: 0,
+                });
+            let Some(total) = rollup.amount.checked_add(&amount) else {
+                continue;
+            };
+            rollup.amount = total;
+            rollup.transactions += 1;
+        }
+    }
+
+    rollups.into_values().collect()
+}
@@ -2,7 +2,7 @@ use std::net::{Ipv4Addr, SocketAddr};
 use std::time::Duration;
 
 use axum::error_handling::HandleErrorLayer;
-use axum::http::{HeaderValue, StatusCode};
+use axum::http::{HeaderName, HeaderValue, StatusCode};
 use axum::response::IntoResponse;
 use axum::routing::get;
 use axum::{BoxError, Json, Router};
@@ -19,6 +19,7 @@ use tower_http::trace::TraceLayer;
 
 use crate::appstate::AppState;
 use crate::config::AppConfig;
+use crate::constant::PROPOSAL_CODE_HASH_HEADER;
 use crate::handler::{
     balance as balance_handlers, block as block_handlers,
     chain as chain_handlers, crawler_state as crawler_state_handlers,
@@ -60,6 +61,14 @@ impl ApplicationServer {
                     "/pos/validator/all",
                     get(pos_handlers::get_all_validators),
                 )
+                .route(
+                    "/pos/validator/:address",
+                    get(pos_handlers::get_validator),
+                )
+                .route(
+                    "/pos/validator/:address/events",
+                    get(pos_handlers::get_validator_events),
+                )
                 .route("/pos/bond/:address", get(pos_handlers::get_bonds))
                 .route(
                     "/pos/merged-bonds/:address",
@@ -83,6 +92,18 @@ impl ApplicationServer {
                     "/pos/voting-power",
                     get(pos_handlers::get_total_voting_power),
                 )
+                .route(
+                    "/pos/decentralization",
+                    get(pos_handlers::get_decentralization_metrics),
+                )
+                .route(
+                    "/pos/projection",
+                    get(pos_handlers::get_rewards_projection),
+                )
+                .route(
+                    "/pos/validator-set/changes",
+                    get(pos_handlers::get_validator_set_changes),
+                )
                 .route(
                     "/gov/proposal",
                     get(gov_handlers::get_governance_proposals),
@@ -99,6 +120,18 @@ impl ApplicationServer {
                     "/gov/proposal/:id/data",
                     get(gov_handlers::get_proposal_data_by_proposal_id),
                 )
+                .route(
+                    "/gov/proposal/:id/code",
+                    get(gov_handlers::get_proposal_code_by_proposal_id),
+                )
+                .route(
+                    "/gov/proposal/:id/code/hex",
+                    get(gov_handlers::get_proposal_code_hex_by_proposal_id),
+                )
+                .route(
+                    "/gov/proposal/:id/code/verify",
+                    get(gov_handlers::verify_proposal_code),
+                )
                 .route(
                     "/gov/proposal/:id/votes",
                     get(gov_handlers::get_governance_proposal_votes),
@@ -107,6 +140,14 @@ impl ApplicationServer {
                     "/gov/proposal/:id/votes/:address",
                     get(gov_handlers::get_governance_proposal_votes_by_address),
                 )
+                .route(
+                    "/gov/proposal/:id/timeline",
+                    get(gov_handlers::get_governance_proposal_timeline),
+                )
+                .route(
+                    "/gov/participation/:address",
+                    get(gov_handlers::get_governance_participation),
+                )
                 .route(
                     "/gov/voter/:address/votes",
                     get(gov_handlers::get_governance_proposal_votes_by_voter),
@@ -115,6 +156,10 @@ impl ApplicationServer {
                     "/account/:address",
                     get(balance_handlers::get_address_balance),
                 )
+                .route(
+                    "/account/:address/fees",
+                    get(gas_handlers::get_account_fees),
+                )
                 .route(
                     "/revealed-public-key/:address",
                     get(pk_handlers::get_revealed_pk),
@@ -124,6 +169,10 @@ impl ApplicationServer {
                     "/gas-price/:token",
                     get(gas_handlers::get_gas_price_by_token),
                 )
+                .route(
+                    "/gas-price/:token/history",
+                    get(gas_handlers::get_gas_price_history_by_token),
+                )
                 .route("/gas-price", get(gas_handlers::get_all_gas_prices))
                 .route(
                     "/chain/wrapper/:id",
@@ -140,6 +189,15 @@ impl ApplicationServer {
                 .route("/chain/parameters", get(chain_handlers::get_parameters))
                 .route("/chain/rpc-url", get(chain_handlers::get_rpc_url))
                 .route("/chain/token", get(chain_handlers::get_tokens))
+                .route(
+                    "/chain/token/:address/holders",
+                    get(chain_handlers::get_token_holders),
+                )
+                .route(
+                    "/chain/token/:address/holders/history",
+                    get(chain_handlers::get_token_holders_history),
+                )
+                .route("/chain/fees", get(gas_handlers::get_chain_fees))
                 .route(
                     "/chain/token-supply",
                     get(chain_handlers::get_token_supply),
@@ -148,6 +206,10 @@ impl ApplicationServer {
                     "/chain/circulating-supply",
                     get(chain_handlers::get_circulating_supply),
                 )
+                .route(
+                    "/chain/supply-history",
+                    get(chain_handlers::get_supply_history),
+                )
                 .route(
                     "/chain/block/latest",
                     get(chain_handlers::get_last_processed_block),
@@ -161,6 +223,10 @@ impl ApplicationServer {
                     "/ibc/rate-limits",
                     get(ibc_handler::get_ibc_rate_limits),
                 )
+                .route(
+                    "/ibc/rate-limits/history",
+                    get(ibc_handler::get_ibc_rate_limits_history),
+                )
                 .route(
                     "/ibc/token-flows",
                     get(ibc_handler::get_ibc_token_flows),
@@ -169,6 +235,17 @@ impl ApplicationServer {
                     "/ibc/token-throughput/:token",
                     get(ibc_handler::get_ibc_token_throughput),
                 )
+                .route("/ibc/packets", get(ibc_handler::get_ibc_packets))
+                .route(
+                    "/ibc/packet/:channel/:sequence",
+                    get(ibc_handler::get_ibc_packet),
+                )
+                .route("/ibc/channels", get(ibc_handler::get_ibc_channels))
+                .route("/ibc/denom", get(ibc_handler::get_ibc_denom_by_trace))
+                .route(
+                    "/ibc/denom/:address",
+                    get(ibc_handler::get_ibc_denom_by_address),
+                )
                 .route(
                     "/pgf/payments",
                     get(pgf_service::get_pgf_continuous_payments),
@@ -177,6 +254,19 @@ impl ApplicationServer {
                     "/pgf/paymenents/:proposal_id",
                     get(pgf_service::get_pgf_payment_by_proposal_id),
                 )
+                .route("/pgf/stewards", get(pgf_service::get_pgf_stewards))
+                .route(
+                    "/pgf/stewards/:address",
+                    get(pgf_service::get_pgf_steward_by_address),
+                )
+                .route(
+                    "/pgf/recipients/:address/payments",
+                    get(pgf_service::get_pgf_receipient_payments),
+                )
+                .route(
+                    "/pgf/treasury",
+                    get(pgf_service::get_pgf_treasury_summary),
+                )
                 .route(
                     "/crawlers/timestamps",
                     get(crawler_state_handlers::get_crawlers_timestamps),
@@ -199,6 +289,10 @@ impl ApplicationServer {
                     "/masp/aggregates",
                     get(masp_handlers::get_masp_aggregates),
                 )
+                .route("/masp/flows", get(masp_handlers::get_masp_flows))
+                .route("/masp/tvl", get(masp_handlers::get_masp_tvl))
+                .route("/masp/rewards", get(masp_handlers::get_masp_rewards))
+                .route("/masp/stats", get(masp_handlers::get_masp_stats))
                 .route(
                     "/metrics",
                     get(|| async move { metric_handle.render() }),
@@ -209,7 +303,10 @@ impl ApplicationServer {
         let cors = CorsLayer::new()
             .allow_origin("*".parse::<HeaderValue>().unwrap())
             .allow_methods(Any)
-            .allow_headers(Any);
+            .allow_headers(Any)
+            .expose_headers([HeaderName::from_static(
+                PROPOSAL_CODE_HASH_HEADER,
+            )]);
 
         let router = Router::new()
             .nest("/api/v1", routes)
@@ -23,6 +23,16 @@ pub struct AppConfig {
     #[clap(long, env)]
     pub tendermint_url: String,
 
+    /// Utilization percentage of an IBC rate limit above which a token is
+    /// reported as near its limit
+    #[clap(long, env, default_value = "80")]
+    pub ibc_rate_limit_alert_threshold: u64,
+
+    /// Number of most recent blocks whose transactions are used to estimate
+    /// gas
+    #[clap(long, env, default_value = "100000")]
+    pub gas_estimate_block_window: u64,
+
     #[clap(flatten)]
     pub log: LogConfig,
 }
@@ -1 +1,4 @@
 pub const ITEM_PER_PAGE: u64 = 30;
+
+/// Header carrying the hex encoded sha256 hash of a proposal wasm code
+pub const PROPOSAL_CODE_HASH_HEADER: &str = "x-proposal-code-hash";
@@ -15,3 +15,19 @@ pub struct CirculatingSupply {
     #[validate(range(min = 0))]
     pub epoch: Option<i32>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHolders {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplyHistory {
+    #[validate(range(min = 0))]
+    pub from_epoch: Option<i32>,
+    #[validate(range(min = 0))]
+    pub to_epoch: Option<i32>,
+}
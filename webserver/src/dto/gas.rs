@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::dto::utils::TimeBucketDto;
 use crate::error::gas::GasError;
 
 #[derive(Clone, Serialize, Deserialize, Validate)]
@@ -67,3 +68,21 @@ impl GasEstimateQuery {
         }
     }
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPriceHistoryQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FeesQueryParams {
+    pub token: Option<String>,
+    #[validate(range(min = 0))]
+    pub from: Option<i64>,
+    #[validate(range(min = 0))]
+    pub to: Option<i64>,
+    pub bucket: Option<TimeBucketDto>,
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::dto::pos::OrderByDto;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ProposalStatus {
@@ -30,8 +32,35 @@ pub struct ProposalQueryParams {
     pub pattern: Option<String>,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProposalVoteSortFieldDto {
+    VotingPower,
+}
+
 #[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct ProposalVotesQueryparams {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
+    pub sort_field: Option<ProposalVoteSortFieldDto>,
+    pub sort_order: Option<OrderByDto>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimelineIntervalDto {
+    Epoch,
+    Hour,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTimelineQueryParams {
+    pub interval: Option<TimelineIntervalDto>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProposalCodeVerifyQueryParams {
+    pub hash: String,
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,3 +13,37 @@ pub struct IbcRateLimit {
 pub struct IbcTokenFlow {
     pub token_address: Option<String>,
 }
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcPacketStatusDto {
+    Sent,
+    Received,
+    Acknowledged,
+    TimedOut,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcPacketQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub channel: Option<String>,
+    pub address: Option<String>,
+    pub status: Option<IbcPacketStatusDto>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcDenomQueryParams {
+    #[validate(length(min = 1))]
+    pub trace: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcRateLimitHistoryQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub token: Option<String>,
+}
@@ -1,8 +1,47 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::dto::utils::TimeBucketDto;
+
 #[derive(Clone, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct MaspAggregatesQueryParams {
     pub token: Option<String>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspFlowsQueryParams {
+    pub token: Option<String>,
+    #[validate(range(min = 0))]
+    pub from: Option<i64>,
+    #[validate(range(min = 0))]
+    pub to: Option<i64>,
+    pub bucket: Option<TimeBucketDto>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspTvlQueryParams {
+    pub token: Option<String>,
+    #[validate(range(min = 0))]
+    pub from_epoch: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspRewardsQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspStatsQueryParams {
+    #[validate(range(min = 0))]
+    pub from: Option<i64>,
+    #[validate(range(min = 0))]
+    pub to: Option<i64>,
+    pub bucket: Option<TimeBucketDto>,
+}
@@ -7,3 +7,4 @@ pub mod masp;
 pub mod pgf;
 pub mod pos;
 pub mod transaction;
+pub mod utils;
@@ -89,8 +89,41 @@ pub struct WithdrawsDto {
     pub epoch: Option<u64>,
 }
 
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct ValidatorEventsDto {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Validate)]
 pub struct RewardsDto {
     #[validate(range(min = 1, max = 10000))]
     pub epoch: Option<u64>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DecentralizationQueryParams {
+    #[validate(range(min = 0))]
+    pub from_epoch: Option<i32>,
+    #[validate(range(min = 0))]
+    pub to_epoch: Option<i32>,
+}
+
+pub const DEFAULT_REWARDS_PROJECTION_EPOCHS: u64 = 10;
+pub const MAX_REWARDS_PROJECTION_EPOCHS: u64 = 1000;
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardsProjectionQueryParams {
+    pub validator: String,
+    pub amount: String,
+    #[validate(range(min = 1, max = "MAX_REWARDS_PROJECTION_EPOCHS"))]
+    pub epochs: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct ValidatorSetChangesQueryParams {
+    #[validate(range(min = 0))]
+    pub epoch: i32,
+}
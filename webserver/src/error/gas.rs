@@ -8,6 +8,8 @@ use crate::response::api::ApiErrorResponse;
 pub enum GasError {
     #[error("Invalid query parameters")]
     InvalidQueryParams,
+    #[error("Invalid time range: {0}")]
+    InvalidRange(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -18,6 +20,7 @@ impl IntoResponse for GasError {
     fn into_response(self) -> Response {
         let status_code = match self {
             GasError::InvalidQueryParams => StatusCode::BAD_GATEWAY,
+            GasError::InvalidRange(_) => StatusCode::BAD_REQUEST,
             GasError::Unknown(_) | GasError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -12,6 +12,10 @@ pub enum GovernanceError {
     NotFound(u64),
     #[error("Proposal {0} has no associated data")]
     DataNotFound(u64),
+    #[error("Proposal {0} has no associated code")]
+    CodeNotFound(u64),
+    #[error("Invalid code hash: {0}")]
+    InvalidCodeHash(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -24,6 +28,8 @@ impl IntoResponse for GovernanceError {
             GovernanceError::TooShortPattern(_) => StatusCode::BAD_REQUEST,
             GovernanceError::NotFound(_) => StatusCode::NOT_FOUND,
             GovernanceError::DataNotFound(_) => StatusCode::NOT_FOUND,
+            GovernanceError::CodeNotFound(_) => StatusCode::NOT_FOUND,
+            GovernanceError::InvalidCodeHash(_) => StatusCode::BAD_REQUEST,
             GovernanceError::Unknown(_) | GovernanceError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -8,6 +8,10 @@ use crate::response::api::ApiErrorResponse;
 pub enum IbcError {
     #[error("Revealed public key {0} not found")]
     NotFound(u64),
+    #[error("Ibc packet {1} on channel {0} not found")]
+    PacketNotFound(String, u64),
+    #[error("Ibc denom {0} not found")]
+    DenomNotFound(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -17,7 +21,9 @@ pub enum IbcError {
 impl IntoResponse for IbcError {
     fn into_response(self) -> axum::response::Response {
         let status_code = match self {
-            IbcError::NotFound(_) => StatusCode::NOT_FOUND,
+            IbcError::NotFound(_)
+            | IbcError::PacketNotFound(_, _)
+            | IbcError::DenomNotFound(_) => StatusCode::NOT_FOUND,
             IbcError::Unknown(_) | IbcError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -5,6 +5,8 @@ use thiserror::Error;
 use crate::response::api::ApiErrorResponse;
 #[derive(Error, Debug)]
 pub enum MaspError {
+    #[error("Invalid time range: {0}")]
+    InvalidRange(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -14,6 +16,7 @@ pub enum MaspError {
 impl IntoResponse for MaspError {
     fn into_response(self) -> axum::response::Response {
         let status_code = match self {
+            MaspError::InvalidRange(_) => StatusCode::BAD_REQUEST,
             MaspError::Unknown(_) | MaspError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -6,6 +6,8 @@ use crate::response::api::ApiErrorResponse;
 
 #[derive(Error, Debug)]
 pub enum PgfError {
+    #[error("Steward {0} not found")]
+    StewardNotFound(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -15,6 +17,7 @@ pub enum PgfError {
 impl IntoResponse for PgfError {
     fn into_response(self) -> Response {
         let status_code = match self {
+            PgfError::StewardNotFound(_) => StatusCode::NOT_FOUND,
             PgfError::Unknown(_) | PgfError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
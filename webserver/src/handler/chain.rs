@@ -2,7 +2,7 @@ use std::convert::Infallible;
 use std::time::Duration;
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum::http::HeaderMap;
 use axum::response::Sse;
 use axum::response::sse::{Event, KeepAlive};
@@ -11,14 +11,17 @@ use futures::Stream;
 use tokio_stream::StreamExt;
 
 use crate::dto::chain::{
-    CirculatingSupply as CirculatingSupplyDto, TokenSupply as TokenSupplyDto,
+    CirculatingSupply as CirculatingSupplyDto,
+    SupplyHistory as SupplyHistoryDto, TokenHolders as TokenHoldersDto,
+    TokenSupply as TokenSupplyDto,
 };
 use crate::error::api::ApiError;
 use crate::response::chain::{
     CirculatingSupply as CirculatingSupplyRsp, LastProcessedBlock,
-    LastProcessedEpoch, Parameters, RpcUrl, Token,
-    TokenSupply as TokenSupplyRsp,
+    LastProcessedEpoch, Parameters, RpcUrl, SupplyHistoryEntry, Token,
+    TokenHolder, TokenHolderCount, TokenSupply as TokenSupplyRsp,
 };
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
 #[derive(serde::Serialize)]
@@ -79,7 +82,10 @@ pub async fn get_tokens(
     State(state): State<CommonState>,
 ) -> Result<Json<Vec<Token>>, ApiError> {
     let tokens = state.chain_service.find_tokens().await?;
-    let res = tokens.into_iter().map(Token::from).collect();
+    let res = tokens
+        .into_iter()
+        .map(|(token, metadata)| Token::from_token(token, metadata))
+        .collect();
 
     Ok(Json(res))
 }
@@ -127,3 +133,43 @@ pub async fn get_circulating_supply(
         .await?;
     Ok(Json(supply))
 }
+
+pub async fn get_supply_history(
+    Query(query): Query<SupplyHistoryDto>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<SupplyHistoryEntry>>, ApiError> {
+    let history = state
+        .chain_service
+        .get_supply_history(query.from_epoch, query.to_epoch)
+        .await?;
+    Ok(Json(history))
+}
+
+pub async fn get_token_holders(
+    Query(query): Query<TokenHoldersDto>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<TokenHolder>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (holders, total_pages, total_holders) = state
+        .chain_service
+        .find_token_holders(address, page)
+        .await?;
+
+    let response =
+        PaginatedResponse::new(holders, page, total_pages, total_holders);
+    Ok(Json(response))
+}
+
+pub async fn get_token_holders_history(
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<TokenHolderCount>>, ApiError> {
+    let history = state
+        .chain_service
+        .find_token_holders_history(address)
+        .await?;
+
+    Ok(Json(history))
+}
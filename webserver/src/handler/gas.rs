@@ -3,9 +3,15 @@ use axum::extract::{Path, Query, State};
 use axum::http::HeaderMap;
 use axum_macros::debug_handler;
 
-use crate::dto::gas::GasEstimateQuery;
+use crate::dto::gas::{
+    FeesQueryParams, GasEstimateQuery, GasPriceHistoryQueryParams,
+};
+use crate::dto::utils::TimeBucketDto;
 use crate::error::api::ApiError;
-use crate::response::gas::{GasEstimate, GasPrice};
+use crate::response::gas::{
+    AccountFees, ChainFees, GasEstimate, GasPrice, GasPriceChange,
+};
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
 #[debug_handler]
@@ -19,6 +25,26 @@ pub async fn get_gas_price_by_token(
     Ok(Json(gas_price))
 }
 
+#[debug_handler]
+pub async fn get_gas_price_history_by_token(
+    _headers: HeaderMap,
+    Path(token): Path<String>,
+    Query(query): Query<GasPriceHistoryQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<GasPriceChange>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (history, total_pages, total_items) = state
+        .gas_service
+        .get_gas_price_history_by_token(token, page)
+        .await?;
+
+    let response =
+        PaginatedResponse::new(history, page, total_pages, total_items);
+
+    Ok(Json(response))
+}
+
 #[debug_handler]
 pub async fn get_all_gas_prices(
     _headers: HeaderMap,
@@ -56,8 +82,39 @@ pub async fn get_gas_estimate(
             query.reveal_pk.unwrap_or(0),
             query.signatures.unwrap_or(2),
             query.tx_size.unwrap_or(0),
+            state.config.gas_estimate_block_window,
         )
         .await?;
 
     Ok(Json(gas))
 }
+
+#[debug_handler]
+pub async fn get_chain_fees(
+    _headers: HeaderMap,
+    Query(query): Query<FeesQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<ChainFees>>, ApiError> {
+    let fees = state
+        .gas_service
+        .get_chain_fees(
+            query.token,
+            query.from,
+            query.to,
+            query.bucket.unwrap_or(TimeBucketDto::Day),
+        )
+        .await?;
+
+    Ok(Json(fees))
+}
+
+#[debug_handler]
+pub async fn get_account_fees(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<AccountFees>>, ApiError> {
+    let fees = state.gas_service.get_account_fees(address).await?;
+
+    Ok(Json(fees))
+}
@@ -1,12 +1,20 @@
 use axum::Json;
 use axum::extract::{Path, Query, State};
-use axum::http::HeaderMap;
+use axum::http::{HeaderMap, HeaderName, header};
+use axum::response::IntoResponse;
 use axum_macros::debug_handler;
 
-use crate::dto::governance::{ProposalQueryParams, ProposalVotesQueryparams};
+use crate::constant::PROPOSAL_CODE_HASH_HEADER;
+use crate::dto::governance::{
+    ProposalCodeVerifyQueryParams, ProposalQueryParams,
+    ProposalTimelineQueryParams, ProposalVotesQueryparams, TimelineIntervalDto,
+};
 use crate::error::api::ApiError;
 use crate::error::governance::GovernanceError;
-use crate::response::governance::{Proposal, ProposalVote};
+use crate::response::governance::{
+    GovernanceParticipation, Proposal, ProposalCode, ProposalCodeVerification,
+    ProposalVote, VoteTimelinePoint,
+};
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
@@ -83,6 +91,52 @@ pub async fn get_proposal_data_by_proposal_id(
     }
 }
 
+#[debug_handler]
+pub async fn get_proposal_code_by_proposal_id(
+    _headers: HeaderMap,
+    Path(proposal_id): Path<u64>,
+    State(state): State<CommonState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (code, hash) = state
+        .gov_service
+        .find_proposal_code_bytes(proposal_id)
+        .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/wasm".to_string()),
+            (HeaderName::from_static(PROPOSAL_CODE_HASH_HEADER), hash),
+        ],
+        code,
+    ))
+}
+
+#[debug_handler]
+pub async fn get_proposal_code_hex_by_proposal_id(
+    _headers: HeaderMap,
+    Path(proposal_id): Path<u64>,
+    State(state): State<CommonState>,
+) -> Result<Json<ProposalCode>, ApiError> {
+    let code = state.gov_service.find_proposal_code(proposal_id).await?;
+
+    Ok(Json(code))
+}
+
+#[debug_handler]
+pub async fn verify_proposal_code(
+    _headers: HeaderMap,
+    Path(proposal_id): Path<u64>,
+    Query(query): Query<ProposalCodeVerifyQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<ProposalCodeVerification>, ApiError> {
+    let verification = state
+        .gov_service
+        .verify_proposal_code(proposal_id, query.hash)
+        .await?;
+
+    Ok(Json(verification))
+}
+
 #[debug_handler]
 pub async fn get_governance_proposal_votes(
     _headers: HeaderMap,
@@ -93,7 +147,12 @@ pub async fn get_governance_proposal_votes(
     let page = query.page.unwrap_or(1);
     let (proposal_votes, total_pages, total_votes) = state
         .gov_service
-        .find_governance_proposal_votes(proposal_id, page)
+        .find_governance_proposal_votes(
+            proposal_id,
+            page,
+            query.sort_field,
+            query.sort_order,
+        )
         .await?;
 
     Ok(Json(PaginatedResponse::new(
@@ -104,6 +163,22 @@ pub async fn get_governance_proposal_votes(
     )))
 }
 
+#[debug_handler]
+pub async fn get_governance_proposal_timeline(
+    _headers: HeaderMap,
+    Path(proposal_id): Path<u64>,
+    Query(query): Query<ProposalTimelineQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<VoteTimelinePoint>>, ApiError> {
+    let interval = query.interval.unwrap_or(TimelineIntervalDto::Epoch);
+    let timeline = state
+        .gov_service
+        .find_governance_proposal_timeline(proposal_id, interval)
+        .await?;
+
+    Ok(Json(timeline))
+}
+
 #[debug_handler]
 pub async fn get_governance_proposal_votes_by_address(
     _headers: HeaderMap,
@@ -131,3 +206,24 @@ pub async fn get_governance_proposal_votes_by_voter(
 
     Ok(Json(proposal_votes))
 }
+
+#[debug_handler]
+pub async fn get_governance_participation(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<GovernanceParticipation>, ApiError> {
+    let participation = state
+        .gov_service
+        .find_governance_participations(vec![address.clone()])
+        .await?
+        .pop()
+        .ok_or_else(|| {
+            GovernanceError::Unknown(format!(
+                "No participation computed for {}",
+                address
+            ))
+        })?;
+
+    Ok(Json(participation))
+}
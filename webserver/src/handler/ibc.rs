@@ -6,12 +6,15 @@ use axum_macros::debug_handler;
 use bigdecimal::BigDecimal;
 
 use crate::dto::ibc::{
-    IbcRateLimit as IbcRateLimitDto, IbcTokenFlow as IbcTokenFlowDto,
+    IbcDenomQueryParams, IbcPacketQueryParams, IbcRateLimit as IbcRateLimitDto,
+    IbcRateLimitHistoryQueryParams, IbcTokenFlow as IbcTokenFlowDto,
 };
 use crate::error::api::ApiError;
 use crate::response::ibc::{
-    IbcAck, IbcRateLimit, IbcTokenFlow, IbcTokenThroughput,
+    IbcAck, IbcChannel, IbcDenomTrace, IbcPacket, IbcRateLimit,
+    IbcRateLimitUtilization, IbcTokenFlow, IbcTokenThroughput,
 };
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
 #[debug_handler]
@@ -41,6 +44,29 @@ pub async fn get_ibc_rate_limits(
     Ok(Json(rate_limits))
 }
 
+#[debug_handler]
+pub async fn get_ibc_rate_limits_history(
+    _headers: HeaderMap,
+    Query(query): Query<IbcRateLimitHistoryQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<IbcRateLimitUtilization>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (history, total_pages, total_items) = state
+        .ibc_service
+        .find_rate_limit_history(
+            page,
+            query.token,
+            state.config.ibc_rate_limit_alert_threshold,
+        )
+        .await?;
+
+    let response =
+        PaginatedResponse::new(history, page, total_pages, total_items);
+
+    Ok(Json(response))
+}
+
 #[debug_handler]
 pub async fn get_ibc_token_flows(
     Query(query): Query<IbcTokenFlowDto>,
@@ -64,3 +90,71 @@ pub async fn get_ibc_token_throughput(
 
     Ok(Json(throughput))
 }
+
+#[debug_handler]
+pub async fn get_ibc_packets(
+    _headers: HeaderMap,
+    Query(query): Query<IbcPacketQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<IbcPacket>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (packets, total_pages, total_items) = state
+        .ibc_service
+        .find_ibc_packets(page, query.channel, query.address, query.status)
+        .await?;
+
+    let response =
+        PaginatedResponse::new(packets, page, total_pages, total_items);
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_ibc_packet(
+    _headers: HeaderMap,
+    Path((channel, sequence)): Path<(String, u64)>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<IbcPacket>>, ApiError> {
+    let packets = state
+        .ibc_service
+        .find_ibc_packets_by_channel_and_sequence(channel, sequence)
+        .await?;
+
+    Ok(Json(packets))
+}
+
+#[debug_handler]
+pub async fn get_ibc_channels(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<IbcChannel>>, ApiError> {
+    let channels = state.ibc_service.get_ibc_channels().await?;
+
+    Ok(Json(channels))
+}
+
+#[debug_handler]
+pub async fn get_ibc_denom_by_address(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<IbcDenomTrace>, ApiError> {
+    let denom = state.ibc_service.get_ibc_denom_by_address(address).await?;
+
+    Ok(Json(denom))
+}
+
+#[debug_handler]
+pub async fn get_ibc_denom_by_trace(
+    _headers: HeaderMap,
+    Query(query): Query<IbcDenomQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<IbcDenomTrace>, ApiError> {
+    let denom = state
+        .ibc_service
+        .get_ibc_denom_by_trace(query.trace)
+        .await?;
+
+    Ok(Json(denom))
+}
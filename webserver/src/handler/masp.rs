@@ -4,9 +4,15 @@ use axum::http::HeaderMap;
 use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
-use crate::dto::masp::MaspAggregatesQueryParams;
+use crate::dto::masp::{
+    MaspAggregatesQueryParams, MaspFlowsQueryParams, MaspRewardsQueryParams,
+    MaspStatsQueryParams, MaspTvlQueryParams,
+};
 use crate::error::api::ApiError;
-use crate::response::masp::MaspPoolAggregateResponse;
+use crate::response::masp::{
+    MaspFlow, MaspPoolAggregateResponse, MaspReward, MaspStats, MaspTvl,
+};
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
 #[debug_handler]
@@ -22,3 +28,73 @@ pub async fn get_masp_aggregates(
 
     Ok(Json(masp_aggregates))
 }
+
+#[debug_handler]
+pub async fn get_masp_flows(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspFlowsQueryParams>,
+) -> Result<Json<Vec<MaspFlow>>, ApiError> {
+    let flows = state
+        .masp_service
+        .find_masp_flows(
+            query.token,
+            query.from,
+            query.to,
+            query.bucket.unwrap_or(TimeBucketDto::Day),
+        )
+        .await?;
+
+    Ok(Json(flows))
+}
+
+#[debug_handler]
+pub async fn get_masp_tvl(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspTvlQueryParams>,
+) -> Result<Json<Vec<MaspTvl>>, ApiError> {
+    let tvl = state
+        .masp_service
+        .find_masp_tvl(query.token, query.from_epoch)
+        .await?;
+
+    Ok(Json(tvl))
+}
+
+#[debug_handler]
+pub async fn get_masp_rewards(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspRewardsQueryParams>,
+) -> Result<Json<PaginatedResponse<Vec<MaspReward>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (rewards, total_pages, total_items) = state
+        .masp_service
+        .find_masp_rewards(page, query.token)
+        .await?;
+
+    let response =
+        PaginatedResponse::new(rewards, page, total_pages, total_items);
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_masp_stats(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspStatsQueryParams>,
+) -> Result<Json<Vec<MaspStats>>, ApiError> {
+    let stats = state
+        .masp_service
+        .find_masp_stats(
+            query.from,
+            query.to,
+            query.bucket.unwrap_or(TimeBucketDto::Day),
+        )
+        .await?;
+
+    Ok(Json(stats))
+}
@@ -6,7 +6,10 @@ use axum_macros::debug_handler;
 
 use crate::dto::pgf::PgfQueryParams;
 use crate::error::api::ApiError;
-use crate::response::pgf::PgfPayment;
+use crate::response::pgf::{
+    PgfDisbursement, PgfPayment, PgfSteward, PgfStewardDetails,
+    PgfTreasuryEpoch,
+};
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
@@ -40,3 +43,64 @@ pub async fn get_pgf_payment_by_proposal_id(
 
     Ok(Json(pgf_payment))
 }
+
+#[debug_handler]
+pub async fn get_pgf_stewards(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<PgfSteward>>, ApiError> {
+    let stewards = state.pgf_service.get_pgf_stewards().await?;
+
+    Ok(Json(stewards))
+}
+
+#[debug_handler]
+pub async fn get_pgf_steward_by_address(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<PgfStewardDetails>, ApiError> {
+    let steward = state
+        .pgf_service
+        .find_pgf_steward_by_address(address)
+        .await?;
+
+    Ok(Json(steward))
+}
+
+#[debug_handler]
+pub async fn get_pgf_receipient_payments(
+    _headers: HeaderMap,
+    Query(query): Query<PgfQueryParams>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<PgfDisbursement>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (disbursements, total_pages, total_items) = state
+        .pgf_service
+        .find_pgf_disbursements_by_receipient(address, page)
+        .await?;
+
+    let response =
+        PaginatedResponse::new(disbursements, page, total_pages, total_items);
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_pgf_treasury_summary(
+    _headers: HeaderMap,
+    Query(query): Query<PgfQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<PgfTreasuryEpoch>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (summary, total_pages, total_items) =
+        state.pgf_service.get_pgf_treasury_summary(page).await?;
+
+    let response =
+        PaginatedResponse::new(summary, page, total_pages, total_items);
+
+    Ok(Json(response))
+}
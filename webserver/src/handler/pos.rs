@@ -5,13 +5,17 @@ use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
 use crate::dto::pos::{
-    AllValidatorsQueryParams, BondsDto, RewardsDto, UnbondsDto,
-    ValidatorQueryParams, ValidatorStateDto, WithdrawsDto,
+    AllValidatorsQueryParams, BondsDto, DEFAULT_REWARDS_PROJECTION_EPOCHS,
+    DecentralizationQueryParams, MAX_REWARDS_PROJECTION_EPOCHS, RewardsDto,
+    RewardsProjectionQueryParams, UnbondsDto, ValidatorEventsDto,
+    ValidatorQueryParams, ValidatorSetChangesQueryParams, ValidatorStateDto,
+    WithdrawsDto,
 };
 use crate::error::api::ApiError;
 use crate::response::pos::{
-    Bond, MergedBond, Reward, TotalVotingPower, Unbond, ValidatorWithId,
-    Withdraw,
+    Bond, DecentralizationMetrics, MergedBond, Reward, RewardsProjection,
+    TotalVotingPower, Unbond, ValidatorEvent, ValidatorSetChange,
+    ValidatorWithId, Withdraw,
 };
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
@@ -46,6 +50,37 @@ pub async fn get_all_validators(
     Ok(Json(validators))
 }
 
+#[debug_handler]
+pub async fn get_validator(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<ValidatorWithId>, ApiError> {
+    let validator = state.pos_service.get_validator_by_address(address).await?;
+
+    Ok(Json(validator))
+}
+
+#[debug_handler]
+pub async fn get_validator_events(
+    _headers: HeaderMap,
+    query: Query<ValidatorEventsDto>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<ValidatorEvent>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (events, total_pages, total_events) = state
+        .pos_service
+        .get_validator_events_by_address(address, page)
+        .await?;
+
+    let response =
+        PaginatedResponse::new(events, page, total_pages, total_events);
+
+    Ok(Json(response))
+}
+
 #[debug_handler]
 pub async fn get_bonds(
     _headers: HeaderMap,
@@ -185,3 +220,47 @@ pub async fn get_total_voting_power(
         total_voting_power: total_voting_power.to_string(),
     }))
 }
+
+#[debug_handler]
+pub async fn get_decentralization_metrics(
+    _headers: HeaderMap,
+    Query(query): Query<DecentralizationQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<DecentralizationMetrics>>, ApiError> {
+    let metrics = state
+        .pos_service
+        .get_decentralization_metrics(query.from_epoch, query.to_epoch)
+        .await?;
+    Ok(Json(metrics))
+}
+
+#[debug_handler]
+pub async fn get_validator_set_changes(
+    _headers: HeaderMap,
+    Query(query): Query<ValidatorSetChangesQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<ValidatorSetChange>>, ApiError> {
+    let changes = state
+        .pos_service
+        .get_validator_set_changes(query.epoch)
+        .await?;
+    Ok(Json(changes))
+}
+
+#[debug_handler]
+pub async fn get_rewards_projection(
+    _headers: HeaderMap,
+    Query(query): Query<RewardsProjectionQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<RewardsProjection>, ApiError> {
+    let epochs = query
+        .epochs
+        .unwrap_or(DEFAULT_REWARDS_PROJECTION_EPOCHS)
+        .min(MAX_REWARDS_PROJECTION_EPOCHS);
+
+    let projection = state
+        .pos_service
+        .get_rewards_projection(query.validator, query.amount, epochs)
+        .await?;
+    Ok(Json(projection))
+}
@@ -1,8 +1,10 @@
 use axum::async_trait;
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use diesel::{
+    ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, SelectableHelper,
+};
 use orm::balances::BalanceDb;
-use orm::schema::token;
-use orm::token::TokenDb;
+use orm::schema::{ibc_token, token, token_metadata};
+use orm::token::{IbcTokenDb, TokenDb, TokenMetadataDb, TokenWithMetadataDb};
 use orm::views::balances;
 
 use crate::appstate::AppState;
@@ -16,7 +18,7 @@ pub struct BalanceRepo {
 pub trait BalanceRepoTrait {
     fn new(app_state: AppState) -> Self;
 
-    async fn get_all_token(&self) -> Result<Vec<TokenDb>, String>;
+    async fn get_all_token(&self) -> Result<Vec<TokenWithMetadataDb>, String>;
 
     async fn get_address_balances(
         &self,
@@ -47,13 +49,24 @@ impl BalanceRepoTrait for BalanceRepo {
         .map_err(|e| e.to_string())
     }
 
-    async fn get_all_token(&self) -> Result<Vec<TokenDb>, String> {
+    async fn get_all_token(&self) -> Result<Vec<TokenWithMetadataDb>, String> {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
             token::table
+                .left_join(
+                    ibc_token::table.on(token::address.eq(ibc_token::address)),
+                )
+                .left_join(
+                    token_metadata::table
+                        .on(token::address.eq(token_metadata::address)),
+                )
                 .distinct()
-                .select(TokenDb::as_select())
+                .select((
+                    TokenDb::as_select(),
+                    Option::<IbcTokenDb>::as_select(),
+                    Option::<TokenMetadataDb>::as_select(),
+                ))
                 .get_results(conn)
         })
         .await
@@ -1,17 +1,23 @@
 use axum::async_trait;
+use bigdecimal::{BigDecimal, Zero};
 use diesel::dsl::max;
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, JoinOnDsl, OptionalExtension,
     QueryDsl, RunQueryDsl, SelectableHelper,
 };
+use orm::balances::BalanceDb;
 use orm::crawler_state::{ChainCrawlerStateDb, CrawlerNameDb};
 use orm::parameters::ParametersDb;
 use orm::schema::{
-    chain_parameters, crawler_state, ibc_token, token, token_supplies_per_epoch,
+    balance_changes, blocks, chain_parameters, crawler_state, ibc_token, token,
+    token_holders_per_epoch, token_metadata, token_supplies_per_epoch,
 };
-use orm::token::{IbcTokenDb, TokenDb};
+use orm::token::{IbcTokenDb, TokenDb, TokenMetadataDb, TokenWithMetadataDb};
+use orm::token_holders_per_epoch::TokenHoldersDb;
 use orm::token_supplies_per_epoch::TokenSuppliesDb;
+use orm::views::balances;
 
+use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
 
 #[derive(Clone)]
@@ -31,15 +37,49 @@ pub trait ChainRepositoryTrait {
 
     async fn get_state(&self) -> Result<ChainCrawlerStateDb, String>;
 
-    async fn find_tokens(
-        &self,
-    ) -> Result<Vec<(TokenDb, Option<IbcTokenDb>)>, String>;
+    async fn find_tokens(&self) -> Result<Vec<TokenWithMetadataDb>, String>;
 
     async fn get_token_supply(
         &self,
         address: String,
         epoch: Option<i32>,
     ) -> Result<Option<TokenSuppliesDb>, String>;
+
+    async fn find_latest_token_supply(
+        &self,
+        address: String,
+    ) -> Result<Option<TokenSuppliesDb>, String>;
+
+    async fn find_token_holders(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<BalanceDb>, String>;
+
+    async fn find_token_supplies(
+        &self,
+        address: String,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<TokenSuppliesDb>, String>;
+
+    async fn find_previous_token_supply(
+        &self,
+        address: String,
+        epoch: i32,
+    ) -> Result<Option<TokenSuppliesDb>, String>;
+
+    async fn find_balance_changes_by_epoch(
+        &self,
+        owners: Vec<String>,
+        token: String,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<(Option<i32>, String, BigDecimal)>, String>;
+
+    async fn find_token_holders_history(
+        &self,
+        address: String,
+    ) -> Result<Vec<TokenHoldersDb>, String>;
 }
 
 #[async_trait]
@@ -110,9 +150,7 @@ impl ChainRepositoryTrait for ChainRepository {
         .map_err(|e| e.to_string())
     }
 
-    async fn find_tokens(
-        &self,
-    ) -> Result<Vec<(TokenDb, Option<IbcTokenDb>)>, String> {
+    async fn find_tokens(&self) -> Result<Vec<TokenWithMetadataDb>, String> {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
@@ -120,11 +158,16 @@ impl ChainRepositoryTrait for ChainRepository {
                 .left_join(
                     ibc_token::table.on(token::address.eq(ibc_token::address)),
                 )
+                .left_join(
+                    token_metadata::table
+                        .on(token::address.eq(token_metadata::address)),
+                )
                 .select((
                     TokenDb::as_select(),
                     Option::<IbcTokenDb>::as_select(),
+                    Option::<TokenMetadataDb>::as_select(),
                 ))
-                .load::<(TokenDb, Option<IbcTokenDb>)>(conn)
+                .load::<TokenWithMetadataDb>(conn)
         })
         .await
         .map_err(|e| e.to_string())?
@@ -173,4 +216,152 @@ impl ChainRepositoryTrait for ChainRepository {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_latest_token_supply(
+        &self,
+        address: String,
+    ) -> Result<Option<TokenSuppliesDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            token_supplies_per_epoch::table
+                .filter(token_supplies_per_epoch::dsl::address.eq(address))
+                .order(token_supplies_per_epoch::dsl::epoch.desc())
+                .select(TokenSuppliesDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_token_holders(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<BalanceDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            balances::table
+                .filter(balances::dsl::token.eq(address))
+                .filter(balances::dsl::raw_amount.gt(BigDecimal::zero()))
+                .order((
+                    balances::dsl::raw_amount.desc(),
+                    balances::dsl::owner.asc(),
+                ))
+                .select(BalanceDb::as_select())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_token_holders_history(
+        &self,
+        address: String,
+    ) -> Result<Vec<TokenHoldersDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            token_holders_per_epoch::table
+                .filter(token_holders_per_epoch::dsl::address.eq(address))
+                .order(token_holders_per_epoch::dsl::epoch.asc())
+                .select(TokenHoldersDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_token_supplies(
+        &self,
+        address: String,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<TokenSuppliesDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = token_supplies_per_epoch::table
+                .filter(token_supplies_per_epoch::dsl::address.eq(address))
+                .into_boxed();
+
+            if let Some(from_epoch) = from_epoch {
+                query = query.filter(
+                    token_supplies_per_epoch::dsl::epoch.ge(from_epoch),
+                );
+            }
+            if let Some(to_epoch) = to_epoch {
+                query = query
+                    .filter(token_supplies_per_epoch::dsl::epoch.le(to_epoch));
+            }
+
+            query
+                .order(token_supplies_per_epoch::dsl::epoch.asc())
+                .select(TokenSuppliesDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_previous_token_supply(
+        &self,
+        address: String,
+        epoch: i32,
+    ) -> Result<Option<TokenSuppliesDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            token_supplies_per_epoch::table
+                .filter(token_supplies_per_epoch::dsl::address.eq(address))
+                .filter(token_supplies_per_epoch::dsl::epoch.lt(epoch))
+                .order(token_supplies_per_epoch::dsl::epoch.desc())
+                .select(TokenSuppliesDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_balance_changes_by_epoch(
+        &self,
+        owners: Vec<String>,
+        token: String,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<(Option<i32>, String, BigDecimal)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = balance_changes::table
+                .inner_join(blocks::table)
+                .filter(balance_changes::dsl::owner.eq_any(owners))
+                .filter(balance_changes::dsl::token.eq(token))
+                .into_boxed();
+
+            if let Some(to_epoch) = to_epoch {
+                query = query.filter(blocks::dsl::epoch.le(to_epoch));
+            }
+
+            query
+                .order(balance_changes::dsl::height.asc())
+                .select((
+                    blocks::dsl::epoch,
+                    balance_changes::dsl::owner,
+                    balance_changes::dsl::raw_amount,
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
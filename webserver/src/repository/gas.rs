@@ -1,16 +1,39 @@
 use axum::async_trait;
 use bigdecimal::BigDecimal;
-use diesel::dsl::{avg, count, max, min};
-use diesel::sql_types::{BigInt, Integer, Nullable, Numeric};
+use diesel::dsl::{avg, count, max, min, sql};
+use diesel::sql_types::{BigInt, Double, Integer, Nullable, Numeric};
 use diesel::{
     ExpressionMethods, IntoSql, JoinOnDsl, QueryDsl, RunQueryDsl,
     SelectableHelper,
 };
-use orm::gas::GasPriceDb;
-use orm::schema::{gas_estimations, gas_price, wrapper_transactions};
+use orm::gas::{GasPriceDb, GasPriceHistoryDb};
+use orm::schema::{
+    fee_rollups, gas_estimations, gas_price, gas_price_history,
+    wrapper_transactions,
+};
+use orm::transactions::TransactionKindDb;
 
+use super::utils::{Paginate, PaginatedResponseDb, TimeBucketDb};
 use crate::appstate::AppState;
 
+/// min, max, avg, p50, p75, p90, p99 and count of the gas used
+pub type GasEstimateDb = (
+    Option<i32>,
+    Option<i32>,
+    Option<BigDecimal>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+    i64,
+);
+
+/// Token, bucket, transaction kind, summed fees and number of transactions
+pub type FeeBucketRollupDb = (String, i64, TransactionKindDb, BigDecimal, i64);
+
+/// Token, transaction kind, summed fees and number of transactions
+pub type FeeRollupTotalDb = (String, TransactionKindDb, BigDecimal, i64);
+
 #[derive(Clone)]
 pub struct GasRepository {
     pub(crate) app_state: AppState,
@@ -27,6 +50,12 @@ pub trait GasRepositoryTrait {
 
     async fn find_all_gas_prices(&self) -> Result<Vec<GasPriceDb>, String>;
 
+    async fn find_gas_price_history_by_token(
+        &self,
+        token: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<GasPriceHistoryDb>, String>;
+
     #[allow(clippy::too_many_arguments)]
     async fn find_gas_estimates(
         &self,
@@ -44,9 +73,23 @@ pub trait GasRepositoryTrait {
         ibc_transparent_transfer: u64,
         withdraw: u64,
         reveal_pk: u64,
-        signatures: u64,
-        tx_size: u64,
-    ) -> Result<(Option<i32>, Option<i32>, Option<BigDecimal>, i64), String>;
+        signatures: (i32, i32),
+        tx_size: (i32, i32),
+        block_window: u64,
+    ) -> Result<GasEstimateDb, String>;
+
+    async fn find_fees(
+        &self,
+        token: Option<String>,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<FeeBucketRollupDb>, String>;
+
+    async fn find_fees_by_payer(
+        &self,
+        address: String,
+    ) -> Result<Vec<FeeRollupTotalDb>, String>;
 }
 
 #[async_trait]
@@ -85,6 +128,26 @@ impl GasRepositoryTrait for GasRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_gas_price_history_by_token(
+        &self,
+        token: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<GasPriceHistoryDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            gas_price_history::table
+                .filter(gas_price_history::token.eq(token))
+                .select(GasPriceHistoryDb::as_select())
+                .order(gas_price_history::epoch.desc())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn find_gas_estimates(
         &self,
@@ -102,36 +165,20 @@ impl GasRepositoryTrait for GasRepository {
         ibc_transparent_transfer: u64,
         withdraw: u64,
         reveal_pk: u64,
-        signatures: u64,
-        tx_size: u64,
-    ) -> Result<(Option<i32>, Option<i32>, Option<BigDecimal>, i64), String>
-    {
-        const TX_SIZE_WINDOW_UPPERBOUND_PCT: f64 = 0.10;
-        const TX_SIGNATURES_WINDOW_UPPERBOUND: u64 = 5;
-
-        let (signature_lower_bound, signature_upper_bound) = if signatures == 0
-        {
-            (2_i32, 5_i32)
-        } else {
-            (
-                signatures as i32,
-                (signatures + TX_SIGNATURES_WINDOW_UPPERBOUND) as i32,
-            )
-        };
-
-        let (tx_size_lower_bound, tx_size_upper_bound) = if tx_size == 0 {
-            (0_i32, 100000_i32)
-        } else {
-            (
-                tx_size as i32,
-                (tx_size as f64 * (1f64 + TX_SIZE_WINDOW_UPPERBOUND_PCT)).ceil()
-                    as i32,
-            )
-        };
-
+        signatures: (i32, i32),
+        tx_size: (i32, i32),
+        block_window: u64,
+    ) -> Result<GasEstimateDb, String> {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
+            let tip = wrapper_transactions::table
+                .select(max(wrapper_transactions::dsl::block_height))
+                .first::<Option<i32>>(conn)?
+                .unwrap_or(0);
+            let min_block_height =
+                (tip as i64 - block_window as i64).max(0) as i32;
+
             gas_estimations::table
                 .filter(gas_estimations::dsl::bond.eq(bond as i32))
                 .filter(
@@ -159,20 +206,38 @@ impl GasRepositoryTrait for GasRepository {
                         .eq(unshielding_transfer as i32),
                 )
                 .filter(gas_estimations::dsl::vote_proposal.eq(vote as i32))
-                .filter(gas_estimations::dsl::ibc_shielding_transfer.eq(ibc_shielding_transfer as i32))
-                .filter(gas_estimations::dsl::ibc_unshielding_transfer.eq(ibc_unshielding_transfer as i32))
-                .filter(gas_estimations::dsl::ibc_msg_transfer.eq(ibc_transparent_transfer as i32))
+                .filter(
+                    gas_estimations::dsl::ibc_shielding_transfer
+                        .eq(ibc_shielding_transfer as i32),
+                )
+                .filter(
+                    gas_estimations::dsl::ibc_unshielding_transfer
+                        .eq(ibc_unshielding_transfer as i32),
+                )
+                .filter(
+                    gas_estimations::dsl::ibc_msg_transfer
+                        .eq(ibc_transparent_transfer as i32),
+                )
                 .filter(gas_estimations::dsl::withdraw.eq(withdraw as i32))
                 .filter(gas_estimations::dsl::reveal_pk.eq(reveal_pk as i32))
-                // For the signatures and the tx size we look for similar indexed txs in a certain range
-                .filter(gas_estimations::dsl::signatures.between(signature_lower_bound, signature_upper_bound))
-                .filter(gas_estimations::dsl::tx_size.between(tx_size_lower_bound, tx_size_upper_bound))
+                // For the signatures and the tx size we look for similar
+                // indexed txs in a certain range
+                .filter(
+                    gas_estimations::dsl::signatures
+                        .between(signatures.0, signatures.1),
+                )
+                .filter(
+                    gas_estimations::dsl::tx_size.between(tx_size.0, tx_size.1),
+                )
                 .inner_join(
                     wrapper_transactions::table
                         .on(gas_estimations::dsl::wrapper_id
                             .eq(wrapper_transactions::dsl::id)),
                 )
-                .limit(100)
+                .filter(
+                    wrapper_transactions::dsl::block_height
+                        .ge(min_block_height),
+                )
                 .select((
                     min(wrapper_transactions::dsl::gas_used)
                         .into_sql::<Nullable<Integer>>(),
@@ -180,12 +245,95 @@ impl GasRepositoryTrait for GasRepository {
                         .into_sql::<Nullable<Integer>>(),
                     avg(wrapper_transactions::dsl::gas_used)
                         .into_sql::<Nullable<Numeric>>(),
+                    sql::<Nullable<Double>>(
+                        "percentile_cont(0.5) WITHIN GROUP (ORDER BY \
+                         wrapper_transactions.gas_used)",
+                    ),
+                    sql::<Nullable<Double>>(
+                        "percentile_cont(0.75) WITHIN GROUP (ORDER BY \
+                         wrapper_transactions.gas_used)",
+                    ),
+                    sql::<Nullable<Double>>(
+                        "percentile_cont(0.9) WITHIN GROUP (ORDER BY \
+                         wrapper_transactions.gas_used)",
+                    ),
+                    sql::<Nullable<Double>>(
+                        "percentile_cont(0.99) WITHIN GROUP (ORDER BY \
+                         wrapper_transactions.gas_used)",
+                    ),
                     count(wrapper_transactions::dsl::gas_used)
                         .into_sql::<BigInt>(),
                 ))
-                .get_result::<(Option<i32>, Option<i32>, Option<BigDecimal>, i64)>(
-                    conn,
-                )
+                .get_result::<GasEstimateDb>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_fees(
+        &self,
+        token: Option<String>,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<FeeBucketRollupDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let bucket_expression = bucket
+                .bucket_expression("fee_rollups.hour", "fee_rollups.epoch");
+
+            let mut query = fee_rollups::table
+                .filter(fee_rollups::dsl::hour.ge(from))
+                .filter(fee_rollups::dsl::hour.le(to))
+                .group_by((
+                    fee_rollups::dsl::token,
+                    sql::<BigInt>(&bucket_expression),
+                    fee_rollups::dsl::kind,
+                ))
+                .select((
+                    fee_rollups::dsl::token,
+                    sql::<BigInt>(&bucket_expression),
+                    fee_rollups::dsl::kind,
+                    sql::<Numeric>("SUM(fee_rollups.amount)"),
+                    sql::<BigInt>("SUM(fee_rollups.transactions)"),
+                ))
+                .order((
+                    sql::<BigInt>(&bucket_expression).asc(),
+                    fee_rollups::dsl::token.asc(),
+                ))
+                .into_boxed();
+
+            if let Some(token) = token {
+                query = query.filter(fee_rollups::dsl::token.eq(token));
+            }
+
+            query.load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_fees_by_payer(
+        &self,
+        address: String,
+    ) -> Result<Vec<FeeRollupTotalDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            fee_rollups::table
+                .filter(fee_rollups::dsl::fee_payer.eq(address))
+                .group_by((fee_rollups::dsl::token, fee_rollups::dsl::kind))
+                .select((
+                    fee_rollups::dsl::token,
+                    fee_rollups::dsl::kind,
+                    sql::<Numeric>("SUM(fee_rollups.amount)"),
+                    sql::<BigInt>("SUM(fee_rollups.transactions)"),
+                ))
+                .order(fee_rollups::dsl::token.asc())
+                .load(conn)
         })
         .await
         .map_err(|e| e.to_string())?
@@ -1,15 +1,24 @@
 use axum::async_trait;
-use diesel::dsl::IntoBoxed;
+use bigdecimal::BigDecimal;
+use diesel::dsl::{IntoBoxed, max, min, sql};
 use diesel::pg::Pg;
+use diesel::sql_types::{Bool, Float4, Text};
 use diesel::{
-    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods,
-    QueryDsl, RunQueryDsl, SelectableHelper,
+    BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
+    NullableExpressionMethods, PgSortExpressionMethods,
+    PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
 };
 use orm::governance_proposal::{
     GovernanceProposalDb, GovernanceProposalKindDb, GovernanceProposalResultDb,
 };
-use orm::governance_votes::GovernanceProposalVoteDb;
-use orm::schema::{governance_proposals, governance_votes};
+use orm::governance_votes::{
+    GovernanceProposalVoteDb, GovernanceVoteHistoryDb, GovernanceVoteKindDb,
+};
+use orm::helpers::OrderByDb;
+use orm::schema::{
+    balance_changes, blocks, governance_proposals, governance_vote_history,
+    governance_votes,
+};
 
 use crate::appstate::AppState;
 use crate::repository::utils::{Paginate, PaginatedResponseDb};
@@ -47,6 +56,7 @@ pub trait GovernanceRepoTrait {
         &self,
         proposal_id: i32,
         page: i64,
+        voting_power_order: Option<OrderByDb>,
     ) -> Result<PaginatedResponseDb<GovernanceProposalVoteDb>, String>;
 
     async fn find_governance_proposal_votes_by_address(
@@ -59,6 +69,41 @@ pub trait GovernanceRepoTrait {
         &self,
         voter_address: String,
     ) -> Result<Vec<GovernanceProposalVoteDb>, String>;
+
+    async fn find_governance_vote_history(
+        &self,
+        proposal_id: i32,
+    ) -> Result<
+        Vec<(
+            GovernanceVoteHistoryDb,
+            Option<chrono::NaiveDateTime>,
+            Option<i32>,
+            Option<BigDecimal>,
+        )>,
+        String,
+    >;
+
+    async fn find_votable_governance_proposals_end_epochs(
+        &self,
+    ) -> Result<Vec<i32>, String>;
+
+    async fn find_first_seen_epoch_by_addresses(
+        &self,
+        addresses: Vec<String>,
+    ) -> Result<Vec<(String, Option<i32>)>, String>;
+
+    async fn find_governance_votes_with_result_by_voters(
+        &self,
+        voter_addresses: Vec<String>,
+    ) -> Result<
+        Vec<(String, GovernanceVoteKindDb, GovernanceProposalResultDb)>,
+        String,
+    >;
+
+    async fn find_last_vote_timestamp_by_voters(
+        &self,
+        voter_addresses: Vec<String>,
+    ) -> Result<Vec<(String, Option<chrono::NaiveDateTime>)>, String>;
 }
 
 #[async_trait]
@@ -80,7 +125,7 @@ impl GovernanceRepoTrait for GovernanceRepo {
         conn.interact(move |conn| {
             query
                 .select(GovernanceProposalDb::as_select())
-                .order(governance_proposals::dsl::id.desc())
+                .then_order_by(governance_proposals::dsl::id.desc())
                 .paginate(page)
                 .load_and_count_pages(conn)
         })
@@ -101,7 +146,7 @@ impl GovernanceRepoTrait for GovernanceRepo {
         conn.interact(move |conn| {
             query
                 .select(GovernanceProposalDb::as_select())
-                .order(governance_proposals::dsl::id.desc())
+                .then_order_by(governance_proposals::dsl::id.desc())
                 .load(conn)
         })
         .await
@@ -130,12 +175,29 @@ impl GovernanceRepoTrait for GovernanceRepo {
         &self,
         proposal_id: i32,
         page: i64,
+        voting_power_order: Option<OrderByDb>,
     ) -> Result<PaginatedResponseDb<GovernanceProposalVoteDb>, String> {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
-            governance_votes::table
+            let mut query = governance_votes::table
                 .filter(governance_votes::dsl::proposal_id.eq(proposal_id))
+                .into_boxed();
+
+            // Votes whose voting power is not computed yet always come last
+            query = match voting_power_order {
+                Some(OrderByDb::Asc) => query.order((
+                    governance_votes::dsl::voting_power.asc().nulls_last(),
+                    governance_votes::dsl::id.asc(),
+                )),
+                Some(OrderByDb::Desc) => query.order((
+                    governance_votes::dsl::voting_power.desc().nulls_last(),
+                    governance_votes::dsl::id.asc(),
+                )),
+                None => query.order(governance_votes::dsl::id.asc()),
+            };
+
+            query
                 .select(GovernanceProposalVoteDb::as_select())
                 .paginate(page)
                 .load_and_count_pages(conn)
@@ -181,6 +243,143 @@ impl GovernanceRepoTrait for GovernanceRepo {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_governance_vote_history(
+        &self,
+        proposal_id: i32,
+    ) -> Result<
+        Vec<(
+            GovernanceVoteHistoryDb,
+            Option<chrono::NaiveDateTime>,
+            Option<i32>,
+            Option<BigDecimal>,
+        )>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_vote_history::table
+                .inner_join(blocks::table)
+                .left_join(
+                    governance_votes::table.on(
+                        governance_votes::dsl::proposal_id
+                            .eq(governance_vote_history::dsl::proposal_id)
+                            .and(governance_votes::dsl::voter_address.eq(
+                                governance_vote_history::dsl::voter_address,
+                            )),
+                    ),
+                )
+                .filter(
+                    governance_vote_history::dsl::proposal_id.eq(proposal_id),
+                )
+                .order((
+                    governance_vote_history::dsl::block_height.asc(),
+                    governance_vote_history::dsl::id.asc(),
+                ))
+                .select((
+                    GovernanceVoteHistoryDb::as_select(),
+                    blocks::dsl::timestamp,
+                    blocks::dsl::epoch,
+                    governance_votes::dsl::voting_power.nullable(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_votable_governance_proposals_end_epochs(
+        &self,
+    ) -> Result<Vec<i32>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_proposals::table
+                .filter(
+                    governance_proposals::dsl::result
+                        .ne(GovernanceProposalResultDb::Pending),
+                )
+                .select(governance_proposals::dsl::end_epoch)
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_first_seen_epoch_by_addresses(
+        &self,
+        addresses: Vec<String>,
+    ) -> Result<Vec<(String, Option<i32>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            balance_changes::table
+                .inner_join(blocks::table)
+                .filter(balance_changes::dsl::owner.eq_any(addresses))
+                .group_by(balance_changes::dsl::owner)
+                .select((balance_changes::dsl::owner, min(blocks::dsl::epoch)))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_governance_votes_with_result_by_voters(
+        &self,
+        voter_addresses: Vec<String>,
+    ) -> Result<
+        Vec<(String, GovernanceVoteKindDb, GovernanceProposalResultDb)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_votes::table
+                .inner_join(governance_proposals::table)
+                .filter(
+                    governance_votes::dsl::voter_address
+                        .eq_any(voter_addresses),
+                )
+                .select((
+                    governance_votes::dsl::voter_address,
+                    governance_votes::dsl::kind,
+                    governance_proposals::dsl::result,
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_last_vote_timestamp_by_voters(
+        &self,
+        voter_addresses: Vec<String>,
+    ) -> Result<Vec<(String, Option<chrono::NaiveDateTime>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_vote_history::table
+                .inner_join(blocks::table)
+                .filter(
+                    governance_vote_history::dsl::voter_address
+                        .eq_any(voter_addresses),
+                )
+                .group_by(governance_vote_history::dsl::voter_address)
+                .select((
+                    governance_vote_history::dsl::voter_address,
+                    max(blocks::dsl::timestamp),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
 
 #[allow(clippy::needless_lifetimes)]
@@ -202,11 +401,29 @@ impl<'a> GovernanceRepo {
             query = query.filter(governance_proposals::dsl::kind.eq(kind));
         }
 
+        // Full-text search over the structured content, ranked by relevance.
+        // Substring matches on the raw content are kept for proposals without
+        // the standard fields.
         if let Some(pattern) = pattern {
-            query = query.filter(
-                governance_proposals::dsl::content
-                    .ilike(format!("%{}%", pattern)),
-            );
+            query = query
+                .filter(
+                    sql::<Bool>(
+                        "search_vector @@ websearch_to_tsquery('english', ",
+                    )
+                    .bind::<Text, _>(pattern.clone())
+                    .sql(")")
+                    .or(governance_proposals::dsl::content
+                        .ilike(format!("%{}%", pattern))),
+                )
+                .order(
+                    sql::<Float4>(
+                        "ts_rank(search_vector, \
+                         websearch_to_tsquery('english', ",
+                    )
+                    .bind::<Text, _>(pattern)
+                    .sql("))")
+                    .desc(),
+                );
         }
 
         query
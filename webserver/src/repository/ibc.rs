@@ -1,12 +1,24 @@
 use axum::async_trait;
 use bigdecimal::BigDecimal;
+use diesel::dsl::{count_star, sql, sum};
+use diesel::sql_types::{Integer, Nullable, Text};
 use diesel::{
-    ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl,
+    BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
+    NullableExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
     SelectableHelper,
 };
-use orm::ibc::IbcAckDb;
-use orm::schema::{ibc_ack, ibc_rate_limits, ibc_token_flows};
+use orm::ibc::{
+    IbcAckDb, IbcAckStatusDb, IbcChannelDb, IbcClientDb, IbcConnectionDb,
+    IbcPacketDb, IbcPacketDirectionDb, IbcPacketStatusDb, IbcRateLimitsDb,
+    IbcTokenFlowsDb,
+};
+use orm::schema::{
+    blocks, ibc_ack, ibc_channels, ibc_clients, ibc_connections, ibc_packets,
+    ibc_rate_limits, ibc_token, ibc_token_flows,
+};
+use orm::token::IbcTokenDb;
 
+use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
 
 #[derive(Clone)]
@@ -38,6 +50,63 @@ pub trait IbcRepositoryTrait {
         &self,
         token_address: String,
     ) -> Result<(String, String), String>;
+
+    async fn find_rate_limit_history(
+        &self,
+        page: i64,
+        token: Option<String>,
+    ) -> Result<PaginatedResponseDb<(IbcTokenFlowsDb, IbcRateLimitsDb)>, String>;
+
+    async fn find_ibc_packets(
+        &self,
+        page: i64,
+        channel: Option<String>,
+        address: Option<String>,
+        status: Option<IbcPacketStatusDb>,
+    ) -> Result<
+        PaginatedResponseDb<(IbcPacketDb, Option<IbcAckStatusDb>)>,
+        String,
+    >;
+
+    async fn find_ibc_packets_by_channel_and_sequence(
+        &self,
+        channel: String,
+        sequence: i64,
+    ) -> Result<Vec<(IbcPacketDb, Option<IbcAckStatusDb>)>, String>;
+
+    async fn get_ibc_channels(
+        &self,
+    ) -> Result<
+        Vec<(IbcChannelDb, Option<IbcConnectionDb>, Option<IbcClientDb>)>,
+        String,
+    >;
+
+    async fn get_ibc_channels_transfer_volume(
+        &self,
+    ) -> Result<
+        Vec<(String, Option<String>, i64, Option<BigDecimal>, Option<i32>)>,
+        String,
+    >;
+
+    async fn find_blocks_timestamp(
+        &self,
+        heights: Vec<i32>,
+    ) -> Result<Vec<(i32, Option<chrono::NaiveDateTime>)>, String>;
+
+    async fn find_ibc_token_by_address(
+        &self,
+        address: String,
+    ) -> Result<Option<IbcTokenDb>, String>;
+
+    async fn find_ibc_token_by_trace(
+        &self,
+        trace: String,
+    ) -> Result<Option<IbcTokenDb>, String>;
+
+    async fn find_ibc_channels_counterparty_chain_id(
+        &self,
+        channel_ids: Vec<String>,
+    ) -> Result<Vec<(String, Option<String>)>, String>;
 }
 
 #[async_trait]
@@ -221,4 +290,301 @@ impl IbcRepositoryTrait for IbcRepository {
         .await
         .map_err(|e| e.to_string())?
     }
+
+    async fn find_rate_limit_history(
+        &self,
+        page: i64,
+        token: Option<String>,
+    ) -> Result<PaginatedResponseDb<(IbcTokenFlowsDb, IbcRateLimitsDb)>, String>
+    {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = ibc_token_flows::table
+                .inner_join(
+                    ibc_rate_limits::table.on(ibc_rate_limits::dsl::address
+                        .eq(ibc_token_flows::dsl::address)
+                        .and(
+                            ibc_rate_limits::dsl::epoch
+                                .eq(ibc_token_flows::dsl::epoch),
+                        )),
+                )
+                .into_boxed();
+
+            if let Some(token) = token {
+                query = query.filter(ibc_token_flows::dsl::address.eq(token));
+            }
+
+            query
+                .select((
+                    IbcTokenFlowsDb::as_select(),
+                    IbcRateLimitsDb::as_select(),
+                ))
+                .order((
+                    ibc_token_flows::dsl::epoch.desc(),
+                    ibc_token_flows::dsl::address.asc(),
+                ))
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ibc_packets(
+        &self,
+        page: i64,
+        channel: Option<String>,
+        address: Option<String>,
+        status: Option<IbcPacketStatusDb>,
+    ) -> Result<
+        PaginatedResponseDb<(IbcPacketDb, Option<IbcAckStatusDb>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = ibc_packets::table
+                .left_join(
+                    ibc_ack::table
+                        .on(ibc_ack::dsl::id.eq(ibc_packets::dsl::id)),
+                )
+                .into_boxed();
+
+            // Outgoing packets go through their source channel, incoming
+            // ones through their destination channel
+            if let Some(channel) = channel {
+                query = query.filter(
+                    ibc_packets::dsl::direction
+                        .eq(IbcPacketDirectionDb::Outgoing)
+                        .and(
+                            ibc_packets::dsl::source_channel
+                                .eq(channel.clone()),
+                        )
+                        .or(ibc_packets::dsl::direction
+                            .eq(IbcPacketDirectionDb::Incoming)
+                            .and(ibc_packets::dsl::dest_channel.eq(channel))),
+                );
+            }
+
+            if let Some(address) = address {
+                query = query.filter(
+                    ibc_packets::dsl::sender
+                        .eq(address.clone())
+                        .or(ibc_packets::dsl::receiver.eq(address)),
+                );
+            }
+
+            if let Some(status) = status {
+                query = query.filter(ibc_packets::dsl::status.eq(status));
+            }
+
+            query
+                .select((
+                    IbcPacketDb::as_select(),
+                    ibc_ack::dsl::status.nullable(),
+                ))
+                .order((
+                    sql::<Nullable<Integer>>(
+                        "COALESCE(sent_at_height, received_at_height, \
+                         acknowledged_at_height, timed_out_at_height)",
+                    )
+                    .desc(),
+                    ibc_packets::dsl::sequence.desc(),
+                ))
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ibc_packets_by_channel_and_sequence(
+        &self,
+        channel: String,
+        sequence: i64,
+    ) -> Result<Vec<(IbcPacketDb, Option<IbcAckStatusDb>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_packets::table
+                .left_join(
+                    ibc_ack::table
+                        .on(ibc_ack::dsl::id.eq(ibc_packets::dsl::id)),
+                )
+                .filter(
+                    ibc_packets::dsl::direction
+                        .eq(IbcPacketDirectionDb::Outgoing)
+                        .and(
+                            ibc_packets::dsl::source_channel
+                                .eq(channel.clone()),
+                        )
+                        .or(ibc_packets::dsl::direction
+                            .eq(IbcPacketDirectionDb::Incoming)
+                            .and(ibc_packets::dsl::dest_channel.eq(channel))),
+                )
+                .filter(ibc_packets::dsl::sequence.eq(sequence))
+                .select((
+                    IbcPacketDb::as_select(),
+                    ibc_ack::dsl::status.nullable(),
+                ))
+                .order(ibc_packets::dsl::direction.asc())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_ibc_channels(
+        &self,
+    ) -> Result<
+        Vec<(IbcChannelDb, Option<IbcConnectionDb>, Option<IbcClientDb>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_channels::table
+                .left_join(
+                    ibc_connections::table
+                        .on(ibc_connections::dsl::connection_id
+                            .eq(ibc_channels::dsl::connection_id)),
+                )
+                .left_join(
+                    ibc_clients::table.on(ibc_clients::dsl::client_id
+                        .eq(ibc_connections::dsl::client_id)),
+                )
+                .select((
+                    IbcChannelDb::as_select(),
+                    Option::<IbcConnectionDb>::as_select(),
+                    Option::<IbcClientDb>::as_select(),
+                ))
+                .order(ibc_channels::dsl::channel_id.asc())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_ibc_channels_transfer_volume(
+        &self,
+    ) -> Result<
+        Vec<(String, Option<String>, i64, Option<BigDecimal>, Option<i32>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            // Outgoing packets go through their source channel, incoming
+            // ones through their destination channel
+            let channel = sql::<Text>(
+                "CASE WHEN direction = 'outgoing' THEN source_channel ELSE \
+                 dest_channel END",
+            );
+
+            ibc_packets::table
+                .filter(ibc_packets::dsl::denom.is_not_null())
+                .group_by((channel.clone(), ibc_packets::dsl::denom))
+                .select((
+                    channel,
+                    ibc_packets::dsl::denom,
+                    count_star(),
+                    sum(ibc_packets::dsl::amount),
+                    sql::<Nullable<Integer>>(
+                        "MAX(GREATEST(sent_at_height, received_at_height, \
+                         acknowledged_at_height, timed_out_at_height))",
+                    ),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_blocks_timestamp(
+        &self,
+        heights: Vec<i32>,
+    ) -> Result<Vec<(i32, Option<chrono::NaiveDateTime>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            blocks::table
+                .filter(blocks::dsl::height.eq_any(heights))
+                .select((blocks::dsl::height, blocks::dsl::timestamp))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ibc_token_by_address(
+        &self,
+        address: String,
+    ) -> Result<Option<IbcTokenDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_token::table
+                .filter(ibc_token::dsl::address.eq(address))
+                .select(IbcTokenDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ibc_token_by_trace(
+        &self,
+        trace: String,
+    ) -> Result<Option<IbcTokenDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_token::table
+                .filter(ibc_token::dsl::ibc_trace.eq(trace))
+                .select(IbcTokenDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ibc_channels_counterparty_chain_id(
+        &self,
+        channel_ids: Vec<String>,
+    ) -> Result<Vec<(String, Option<String>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_channels::table
+                .left_join(
+                    ibc_connections::table
+                        .on(ibc_connections::dsl::connection_id
+                            .eq(ibc_channels::dsl::connection_id)),
+                )
+                .left_join(
+                    ibc_clients::table.on(ibc_clients::dsl::client_id
+                        .eq(ibc_connections::dsl::client_id)),
+                )
+                .filter(ibc_channels::dsl::channel_id.eq_any(channel_ids))
+                .select((
+                    ibc_channels::dsl::channel_id,
+                    ibc_clients::dsl::counterparty_chain_id.nullable(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
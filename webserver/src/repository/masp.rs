@@ -1,8 +1,16 @@
 use axum::async_trait;
+use bigdecimal::BigDecimal;
+use diesel::dsl::sql;
+use diesel::sql_types::{BigInt, Bool, Numeric};
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
-use orm::masp::MaspPoolDb;
-use orm::schema::masp_pool_aggregate;
+use orm::masp::{MaspPoolDirectionDb, MaspRewardDb};
+use orm::schema::{
+    balance_changes, blocks, inner_transactions, masp_pool, masp_rewards,
+    wrapper_transactions,
+};
+use orm::transactions::{TransactionKindDb, TransactionResultDb};
 
+use super::utils::{Paginate, PaginatedResponseDb, TimeBucketDb};
 use crate::appstate::AppState;
 
 #[derive(Clone)]
@@ -10,30 +18,109 @@ pub struct MaspRepository {
     pub(crate) app_state: AppState,
 }
 
+/// Token, direction and the summed amounts over the last day, 7 days, 30
+/// days and all time
+pub type MaspPoolAggregateDb = (
+    String,
+    MaspPoolDirectionDb,
+    BigDecimal,
+    BigDecimal,
+    BigDecimal,
+    BigDecimal,
+);
+
 #[async_trait]
 pub trait MaspRepositoryTrait {
     fn new(app_state: AppState) -> Self;
 
-    async fn find_all_aggregates(&self) -> Result<Vec<MaspPoolDb>, String>;
+    async fn find_all_aggregates(
+        &self,
+    ) -> Result<Vec<MaspPoolAggregateDb>, String>;
 
     async fn find_all_aggregates_by_token(
         &self,
         token: String,
-    ) -> Result<Vec<MaspPoolDb>, String>;
+    ) -> Result<Vec<MaspPoolAggregateDb>, String>;
+
+    async fn find_masp_flows(
+        &self,
+        token: Option<String>,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<(String, i64, BigDecimal, BigDecimal)>, String>;
+
+    async fn find_masp_net_flows_per_epoch(
+        &self,
+        token: Option<String>,
+    ) -> Result<Vec<(String, Option<i32>, BigDecimal)>, String>;
+
+    async fn find_balance_changes_with_epoch(
+        &self,
+        owner: String,
+        token: Option<String>,
+    ) -> Result<Vec<(String, Option<i32>, BigDecimal)>, String>;
+
+    async fn find_masp_rewards(
+        &self,
+        page: i64,
+        token: Option<String>,
+    ) -> Result<PaginatedResponseDb<MaspRewardDb>, String>;
+
+    async fn find_shielded_transfer_stats(
+        &self,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<(i64, i64, i64, i64, i64, i64)>, String>;
+
+    async fn find_masp_fee_payment_stats(
+        &self,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<(i64, i64, i64)>, String>;
 }
 
+// Windows are relative to the time of the query, so they don't depend on
+// new entries being inserted to stay up to date
+const ONE_DAY_SUM: &str = "COALESCE(SUM(raw_amount) FILTER (WHERE timestamp \
+                           >= now() - INTERVAL '1 day'), 0)";
+const SEVEN_DAYS_SUM: &str = "COALESCE(SUM(raw_amount) FILTER (WHERE \
+                              timestamp >= now() - INTERVAL '7 days'), 0)";
+const THIRTY_DAYS_SUM: &str = "COALESCE(SUM(raw_amount) FILTER (WHERE \
+                               timestamp >= now() - INTERVAL '30 days'), 0)";
+const ALL_TIME_SUM: &str = "COALESCE(SUM(raw_amount), 0)";
+const INFLOW_SUM: &str = "COALESCE(SUM(masp_pool.raw_amount) FILTER (WHERE \
+                          masp_pool.direction = 'in'), 0)";
+const OUTFLOW_SUM: &str = "COALESCE(SUM(masp_pool.raw_amount) FILTER (WHERE \
+                           masp_pool.direction = 'out'), 0)";
+
 #[async_trait]
 impl MaspRepositoryTrait for MaspRepository {
     fn new(app_state: AppState) -> Self {
         Self { app_state }
     }
 
-    async fn find_all_aggregates(&self) -> Result<Vec<MaspPoolDb>, String> {
+    async fn find_all_aggregates(
+        &self,
+    ) -> Result<Vec<MaspPoolAggregateDb>, String> {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
-            masp_pool_aggregate::table
-                .select(MaspPoolDb::as_select())
+            masp_pool::table
+                .group_by((
+                    masp_pool::dsl::token_address,
+                    masp_pool::dsl::direction,
+                ))
+                .select((
+                    masp_pool::dsl::token_address,
+                    masp_pool::dsl::direction,
+                    sql::<Numeric>(ONE_DAY_SUM),
+                    sql::<Numeric>(SEVEN_DAYS_SUM),
+                    sql::<Numeric>(THIRTY_DAYS_SUM),
+                    sql::<Numeric>(ALL_TIME_SUM),
+                ))
                 .load(conn)
         })
         .await
@@ -44,13 +131,298 @@ impl MaspRepositoryTrait for MaspRepository {
     async fn find_all_aggregates_by_token(
         &self,
         token: String,
-    ) -> Result<Vec<MaspPoolDb>, String> {
+    ) -> Result<Vec<MaspPoolAggregateDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            masp_pool::table
+                .filter(masp_pool::dsl::token_address.eq(token))
+                .group_by((
+                    masp_pool::dsl::token_address,
+                    masp_pool::dsl::direction,
+                ))
+                .select((
+                    masp_pool::dsl::token_address,
+                    masp_pool::dsl::direction,
+                    sql::<Numeric>(ONE_DAY_SUM),
+                    sql::<Numeric>(SEVEN_DAYS_SUM),
+                    sql::<Numeric>(THIRTY_DAYS_SUM),
+                    sql::<Numeric>(ALL_TIME_SUM),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_masp_flows(
+        &self,
+        token: Option<String>,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<(String, i64, BigDecimal, BigDecimal)>, String> {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
-            masp_pool_aggregate::table
-                .filter(masp_pool_aggregate::dsl::token_address.eq(token))
-                .select(MaspPoolDb::as_select())
+            let bucket_expression =
+                bucket.bucket_expression("masp_pool.timestamp", "blocks.epoch");
+
+            // Only epoch buckets need the block of each entry, hour and day
+            // buckets are computed from masp_pool alone
+            if let TimeBucketDb::Epoch = bucket {
+                let mut query = masp_pool::table
+                    .inner_join(inner_transactions::table.inner_join(
+                        wrapper_transactions::table.inner_join(blocks::table),
+                    ))
+                    .filter(masp_pool::dsl::timestamp.ge(from))
+                    .filter(masp_pool::dsl::timestamp.le(to))
+                    .filter(blocks::dsl::epoch.is_not_null())
+                    .group_by((
+                        masp_pool::dsl::token_address,
+                        sql::<BigInt>(&bucket_expression),
+                    ))
+                    .select((
+                        masp_pool::dsl::token_address,
+                        sql::<BigInt>(&bucket_expression),
+                        sql::<Numeric>(INFLOW_SUM),
+                        sql::<Numeric>(OUTFLOW_SUM),
+                    ))
+                    .order((
+                        sql::<BigInt>(&bucket_expression).asc(),
+                        masp_pool::dsl::token_address.asc(),
+                    ))
+                    .into_boxed();
+
+                if let Some(token) = token {
+                    query =
+                        query.filter(masp_pool::dsl::token_address.eq(token));
+                }
+
+                query.load(conn)
+            } else {
+                let mut query = masp_pool::table
+                    .filter(masp_pool::dsl::timestamp.ge(from))
+                    .filter(masp_pool::dsl::timestamp.le(to))
+                    .group_by((
+                        masp_pool::dsl::token_address,
+                        sql::<BigInt>(&bucket_expression),
+                    ))
+                    .select((
+                        masp_pool::dsl::token_address,
+                        sql::<BigInt>(&bucket_expression),
+                        sql::<Numeric>(INFLOW_SUM),
+                        sql::<Numeric>(OUTFLOW_SUM),
+                    ))
+                    .order((
+                        sql::<BigInt>(&bucket_expression).asc(),
+                        masp_pool::dsl::token_address.asc(),
+                    ))
+                    .into_boxed();
+
+                if let Some(token) = token {
+                    query =
+                        query.filter(masp_pool::dsl::token_address.eq(token));
+                }
+
+                query.load(conn)
+            }
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_masp_net_flows_per_epoch(
+        &self,
+        token: Option<String>,
+    ) -> Result<Vec<(String, Option<i32>, BigDecimal)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = masp_pool::table
+                .inner_join(inner_transactions::table.inner_join(
+                    wrapper_transactions::table.inner_join(blocks::table),
+                ))
+                .group_by((masp_pool::dsl::token_address, blocks::dsl::epoch))
+                .select((
+                    masp_pool::dsl::token_address,
+                    blocks::dsl::epoch,
+                    sql::<Numeric>(
+                        "COALESCE(SUM(CASE WHEN masp_pool.direction = 'in' \
+                         THEN masp_pool.raw_amount ELSE -masp_pool.raw_amount \
+                         END), 0)",
+                    ),
+                ))
+                .order((
+                    masp_pool::dsl::token_address.asc(),
+                    blocks::dsl::epoch.asc(),
+                ))
+                .into_boxed();
+
+            if let Some(token) = token {
+                query = query.filter(masp_pool::dsl::token_address.eq(token));
+            }
+
+            query.load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_balance_changes_with_epoch(
+        &self,
+        owner: String,
+        token: Option<String>,
+    ) -> Result<Vec<(String, Option<i32>, BigDecimal)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = balance_changes::table
+                .inner_join(blocks::table)
+                .filter(balance_changes::dsl::owner.eq(owner))
+                .select((
+                    balance_changes::dsl::token,
+                    blocks::dsl::epoch,
+                    balance_changes::dsl::raw_amount,
+                ))
+                .order(balance_changes::dsl::height.asc())
+                .into_boxed();
+
+            if let Some(token) = token {
+                query = query.filter(balance_changes::dsl::token.eq(token));
+            }
+
+            query.load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_masp_rewards(
+        &self,
+        page: i64,
+        token: Option<String>,
+    ) -> Result<PaginatedResponseDb<MaspRewardDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = masp_rewards::table
+                .select(MaspRewardDb::as_select())
+                .order((
+                    masp_rewards::dsl::epoch.desc(),
+                    masp_rewards::dsl::token.asc(),
+                ))
+                .into_boxed();
+
+            if let Some(token) = token {
+                query = query.filter(masp_rewards::dsl::token.eq(token));
+            }
+
+            query.paginate(page).load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_shielded_transfer_stats(
+        &self,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<(i64, i64, i64, i64, i64, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let bucket_expression =
+                bucket.bucket_expression("blocks.timestamp", "blocks.epoch");
+
+            inner_transactions::table
+                .inner_join(
+                    wrapper_transactions::table.inner_join(blocks::table),
+                )
+                .filter(
+                    inner_transactions::dsl::exit_code
+                        .eq(TransactionResultDb::Applied),
+                )
+                .filter(inner_transactions::dsl::kind.eq_any(vec![
+                    TransactionKindDb::ShieldedTransfer,
+                    TransactionKindDb::ShieldingTransfer,
+                    TransactionKindDb::IbcShieldingTransfer,
+                    TransactionKindDb::UnshieldingTransfer,
+                    TransactionKindDb::IbcUnshieldingTransfer,
+                    TransactionKindDb::MixedTransfer,
+                ]))
+                .filter(blocks::dsl::timestamp.ge(from))
+                .filter(blocks::dsl::timestamp.le(to))
+                .filter(sql::<Bool>(&format!(
+                    "{} IS NOT NULL",
+                    bucket_expression
+                )))
+                .group_by(sql::<BigInt>(&bucket_expression))
+                .select((
+                    sql::<BigInt>(&bucket_expression),
+                    sql::<BigInt>(
+                        "COUNT(*) FILTER (WHERE inner_transactions.kind = \
+                         'shielded_transfer')",
+                    ),
+                    sql::<BigInt>(
+                        "COUNT(*) FILTER (WHERE inner_transactions.kind IN \
+                         ('shielding_transfer', 'ibc_shielding_transfer'))",
+                    ),
+                    sql::<BigInt>(
+                        "COUNT(*) FILTER (WHERE inner_transactions.kind IN \
+                         ('unshielding_transfer', 'ibc_unshielding_transfer'))",
+                    ),
+                    sql::<BigInt>(
+                        "COUNT(*) FILTER (WHERE inner_transactions.kind = \
+                         'mixed_transfer')",
+                    ),
+                    sql::<BigInt>(
+                        "COALESCE(SUM(inner_transactions.notes), 0)::BIGINT",
+                    ),
+                ))
+                .order(sql::<BigInt>(&bucket_expression).asc())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_masp_fee_payment_stats(
+        &self,
+        from: chrono::NaiveDateTime,
+        to: chrono::NaiveDateTime,
+        bucket: TimeBucketDb,
+    ) -> Result<Vec<(i64, i64, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let bucket_expression =
+                bucket.bucket_expression("blocks.timestamp", "blocks.epoch");
+
+            wrapper_transactions::table
+                .inner_join(blocks::table)
+                .filter(blocks::dsl::timestamp.ge(from))
+                .filter(blocks::dsl::timestamp.le(to))
+                .filter(sql::<Bool>(&format!(
+                    "{} IS NOT NULL",
+                    bucket_expression
+                )))
+                .group_by(sql::<BigInt>(&bucket_expression))
+                .select((
+                    sql::<BigInt>(&bucket_expression),
+                    sql::<BigInt>("COUNT(*)"),
+                    sql::<BigInt>(
+                        "COUNT(wrapper_transactions.masp_fee_payment)",
+                    ),
+                ))
+                .order(sql::<BigInt>(&bucket_expression).asc())
                 .load(conn)
         })
         .await
@@ -1,7 +1,18 @@
 use axum::async_trait;
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
-use orm::pgf::PublicGoodFundingPaymentDb;
-use orm::schema::public_good_funding;
+use bigdecimal::BigDecimal;
+use diesel::dsl::sum;
+use diesel::{
+    ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use orm::pgf::{
+    PgfDisbursementDb, PgfStewardDb, PgfStewardEventDb,
+    PublicGoodFundingPaymentDb,
+};
+use orm::schema::{
+    blocks, pgf_disbursements, pgf_steward_events, pgf_stewards,
+    public_good_funding,
+};
 
 use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
@@ -24,6 +35,29 @@ pub trait PgfRepoTrait {
         &self,
         proposal_id: i32,
     ) -> Result<Option<PublicGoodFundingPaymentDb>, String>;
+
+    async fn get_pgf_stewards(&self) -> Result<Vec<PgfStewardDb>, String>;
+
+    async fn find_pgf_steward_by_address(
+        &self,
+        address: String,
+    ) -> Result<Option<PgfStewardDb>, String>;
+
+    async fn find_pgf_steward_events_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(PgfStewardEventDb, Option<chrono::NaiveDateTime>)>, String>;
+
+    async fn find_pgf_disbursements_by_receipient(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PgfDisbursementDb>, String>;
+
+    async fn get_pgf_treasury_summary(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(i32, String, Option<BigDecimal>)>, String>;
 }
 
 #[async_trait]
@@ -66,4 +100,108 @@ impl PgfRepoTrait for PgfRepo {
         .await
         .map_err(|e| e.to_string())
     }
+
+    async fn get_pgf_stewards(&self) -> Result<Vec<PgfStewardDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_stewards::table
+                .select(PgfStewardDb::as_select())
+                .order(pgf_stewards::dsl::address.asc())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_pgf_steward_by_address(
+        &self,
+        address: String,
+    ) -> Result<Option<PgfStewardDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_stewards::table
+                .find(address)
+                .select(PgfStewardDb::as_select())
+                .first(conn)
+                .ok()
+        })
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_pgf_steward_events_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(PgfStewardEventDb, Option<chrono::NaiveDateTime>)>, String>
+    {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_steward_events::table
+                .left_join(blocks::table)
+                .filter(pgf_steward_events::dsl::address.eq(address))
+                .select((
+                    PgfStewardEventDb::as_select(),
+                    blocks::dsl::timestamp.nullable(),
+                ))
+                .order(pgf_steward_events::dsl::id.asc())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_pgf_disbursements_by_receipient(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PgfDisbursementDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_disbursements::table
+                .filter(pgf_disbursements::dsl::receipient.eq(address))
+                .select(PgfDisbursementDb::as_select())
+                .order(pgf_disbursements::dsl::epoch.desc())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_pgf_treasury_summary(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(i32, String, Option<BigDecimal>)>, String>
+    {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_disbursements::table
+                .group_by((
+                    pgf_disbursements::dsl::epoch,
+                    pgf_disbursements::dsl::token,
+                ))
+                .select((
+                    pgf_disbursements::dsl::epoch,
+                    pgf_disbursements::dsl::token,
+                    sum(pgf_disbursements::dsl::amount),
+                ))
+                .order((
+                    pgf_disbursements::dsl::epoch.desc(),
+                    pgf_disbursements::dsl::token.asc(),
+                ))
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
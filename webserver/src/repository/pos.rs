@@ -8,14 +8,18 @@ use diesel::{
 };
 use orm::bond::BondDb;
 use orm::crawler_state::{CrawlerNameDb, EpochCrawlerStateDb};
+use orm::decentralization::DecentralizationMetricsDb;
 use orm::helpers::OrderByDb;
 use orm::pos_rewards::PoSRewardDb;
 use orm::schema::{
-    bonds, crawler_state, pos_rewards, redelegation, unbonds, validators,
+    blocks, bonds, crawler_state, decentralization_metrics, pos_rewards,
+    redelegation, unbonds, validator_events, validator_set_changes, validators,
 };
 use orm::unbond::UnbondDb;
+use orm::validator_events::ValidatorEventDb;
 use orm::validators::{
-    ValidatorDb, ValidatorSortByDb, ValidatorStateDb, validator_sort_by,
+    ValidatorDb, ValidatorSetChangeDb, ValidatorSortByDb, ValidatorStateDb,
+    validator_sort_by,
 };
 
 use super::utils::{Paginate, PaginatedResponseDb};
@@ -112,8 +116,39 @@ pub trait PosRepositoryTrait {
         epoch: u64,
     ) -> Result<Vec<PoSRewardDb>, String>;
 
+    async fn find_validator_events_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(ValidatorEventDb, Option<chrono::NaiveDateTime>)>,
+        String,
+    >;
+
     async fn get_total_voting_power(&self) -> Result<Option<i64>, String>;
 
+    async fn find_decentralization_metrics(
+        &self,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<DecentralizationMetricsDb>, String>;
+
+    async fn find_validator_set_changes(
+        &self,
+        epoch: i32,
+    ) -> Result<Vec<ValidatorSetChangeDb>, String>;
+
+    async fn find_validator_rewards_since_epoch(
+        &self,
+        validator_id: i32,
+        from_epoch: i32,
+    ) -> Result<Vec<(String, i32, BigDecimal)>, String>;
+
+    async fn get_validator_bonded_amount(
+        &self,
+        validator_id: i32,
+    ) -> Result<Option<BigDecimal>, String>;
+
     async fn get_state(&self) -> Result<EpochCrawlerStateDb, String>;
 }
 
@@ -427,6 +462,36 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_validator_events_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(ValidatorEventDb, Option<chrono::NaiveDateTime>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validator_events::table
+                .inner_join(blocks::table)
+                .filter(validator_events::dsl::address.eq(address))
+                .order((
+                    validator_events::dsl::block_height.desc(),
+                    validator_events::dsl::id.desc(),
+                ))
+                .select((ValidatorEventDb::as_select(), blocks::timestamp))
+                .paginate(page)
+                .load_and_count_pages::<(
+                    ValidatorEventDb,
+                    Option<chrono::NaiveDateTime>,
+                )>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn get_total_voting_power(&self) -> Result<Option<i64>, String> {
         let conn = self.app_state.get_db_connection().await;
 
@@ -440,6 +505,94 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_decentralization_metrics(
+        &self,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<DecentralizationMetricsDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = decentralization_metrics::table.into_boxed();
+
+            if let Some(from_epoch) = from_epoch {
+                query = query.filter(
+                    decentralization_metrics::dsl::epoch.ge(from_epoch),
+                );
+            }
+            if let Some(to_epoch) = to_epoch {
+                query = query
+                    .filter(decentralization_metrics::dsl::epoch.le(to_epoch));
+            }
+
+            query
+                .order(decentralization_metrics::dsl::epoch.asc())
+                .select(DecentralizationMetricsDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_validator_set_changes(
+        &self,
+        epoch: i32,
+    ) -> Result<Vec<ValidatorSetChangeDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validator_set_changes::table
+                .filter(validator_set_changes::dsl::epoch.eq(epoch))
+                .order(validator_set_changes::dsl::address.asc())
+                .select(ValidatorSetChangeDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_validator_rewards_since_epoch(
+        &self,
+        validator_id: i32,
+        from_epoch: i32,
+    ) -> Result<Vec<(String, i32, BigDecimal)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pos_rewards::table
+                .filter(pos_rewards::dsl::validator_id.eq(validator_id))
+                .filter(pos_rewards::dsl::epoch.ge(from_epoch))
+                .select((
+                    pos_rewards::dsl::owner,
+                    pos_rewards::dsl::epoch,
+                    pos_rewards::dsl::raw_amount,
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_validator_bonded_amount(
+        &self,
+        validator_id: i32,
+    ) -> Result<Option<BigDecimal>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            bonds::table
+                .filter(bonds::dsl::validator_id.eq(validator_id))
+                .select(sum(bonds::dsl::raw_amount))
+                .first(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn get_state(&self) -> Result<EpochCrawlerStateDb, String> {
         let conn = self.app_state.get_db_connection().await;
 
@@ -80,3 +80,32 @@ where
         Ok(())
     }
 }
+
+#[derive(Clone, Copy)]
+pub enum TimeBucketDb {
+    Hour,
+    Day,
+    Epoch,
+}
+
+impl TimeBucketDb {
+    /// Bucket of a row, keyed by the unix timestamp of its start for hour
+    /// and day buckets or by the epoch
+    pub fn bucket_expression(
+        &self,
+        timestamp_column: &str,
+        epoch_column: &str,
+    ) -> String {
+        match self {
+            TimeBucketDb::Hour => format!(
+                "EXTRACT(EPOCH FROM date_trunc('hour', {}))::BIGINT",
+                timestamp_column
+            ),
+            TimeBucketDb::Day => format!(
+                "EXTRACT(EPOCH FROM date_trunc('day', {}))::BIGINT",
+                timestamp_column
+            ),
+            TimeBucketDb::Epoch => format!("{}::BIGINT", epoch_column),
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use orm::balances::BalanceDb;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -6,13 +5,7 @@ use serde::{Deserialize, Serialize};
 pub struct AddressBalance {
     pub token_address: String,
     pub min_denom_amount: String,
-}
-
-impl From<BalanceDb> for AddressBalance {
-    fn from(value: BalanceDb) -> Self {
-        Self {
-            token_address: value.token,
-            min_denom_amount: value.raw_amount.to_string(),
-        }
-    }
+    pub denominated_amount: String,
+    pub symbol: String,
+    pub decimals: u32,
 }
@@ -1,7 +1,9 @@
 use orm::parameters::ParametersDb;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as SerdeJSONValue;
-use shared::token::Token as SharedToken;
+use shared::token::{
+    Token as SharedToken, TokenMetadata as SharedTokenMetadata,
+};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -71,10 +73,32 @@ pub struct LastProcessedEpoch {
     pub epoch: String,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenMetadata {
+    pub symbol: String,
+    pub decimals: u32,
+    pub name: Option<String>,
+    pub logo_url: Option<String>,
+}
+
+impl From<SharedTokenMetadata> for TokenMetadata {
+    fn from(value: SharedTokenMetadata) -> Self {
+        Self {
+            symbol: value.symbol,
+            decimals: value.decimals,
+            name: value.name,
+            logo_url: value.logo_url,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NativeToken {
     pub address: String,
+    #[serde(flatten)]
+    pub metadata: TokenMetadata,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -82,6 +106,8 @@ pub struct NativeToken {
 pub struct IbcToken {
     pub address: String,
     pub trace: String,
+    #[serde(flatten)]
+    pub metadata: TokenMetadata,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -92,15 +118,20 @@ pub enum Token {
     Ibc(IbcToken),
 }
 
-impl From<SharedToken> for Token {
-    fn from(value: SharedToken) -> Self {
-        match value {
+impl Token {
+    pub fn from_token(
+        token: SharedToken,
+        metadata: SharedTokenMetadata,
+    ) -> Self {
+        match token {
             SharedToken::Native(token) => Token::Native(NativeToken {
                 address: token.to_string(),
+                metadata: metadata.into(),
             }),
             SharedToken::Ibc(token) => Token::Ibc(IbcToken {
                 address: token.address.to_string(),
                 trace: token.trace.unwrap_or_default().to_string(),
+                metadata: metadata.into(),
             }),
         }
     }
@@ -119,3 +150,35 @@ pub struct TokenSupply {
 pub struct CirculatingSupply {
     pub circulating_supply: String,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHolder {
+    pub address: String,
+    pub min_denom_amount: String,
+    /// Percentage of the latest known total supply held by this address
+    pub share: Option<String>,
+    pub label: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHolderCount {
+    pub epoch: String,
+    pub holders: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplyHistoryEntry {
+    pub epoch: String,
+    pub total_supply: String,
+    pub effective_supply: Option<String>,
+    pub circulating_supply: Option<String>,
+    pub bonded_supply: Option<String>,
+    /// Amount minted per epoch since the previous known supply
+    pub minted: Option<String>,
+    /// Yearly inflation rate implied by the amount minted in the epoch
+    pub inflation_rate: Option<String>,
+    pub staked_ratio: Option<String>,
+}
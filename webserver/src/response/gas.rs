@@ -1,6 +1,8 @@
-use orm::gas::GasPriceDb;
+use orm::gas::{GasPriceDb, GasPriceHistoryDb};
 use serde::{Deserialize, Serialize};
 
+use crate::response::transaction::TransactionKind;
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GasPrice {
@@ -17,11 +19,62 @@ impl From<GasPriceDb> for GasPrice {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPriceChange {
+    pub token: String,
+    pub min_denom_amount: String,
+    pub epoch: String,
+    pub timestamp: String,
+}
+
+impl From<GasPriceHistoryDb> for GasPriceChange {
+    fn from(value: GasPriceHistoryDb) -> Self {
+        Self {
+            token: value.token,
+            min_denom_amount: value.amount.to_string(),
+            epoch: value.epoch.to_string(),
+            timestamp: value.timestamp.and_utc().timestamp().to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GasEstimate {
     pub min: u64,
     pub max: u64,
     pub avg: u64,
+    pub p50: u64,
+    pub p75: u64,
+    pub p90: u64,
+    pub p99: u64,
     pub total_estimates: u64,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeByKind {
+    pub kind: TransactionKind,
+    pub amount: String,
+    pub transactions: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainFees {
+    pub token_address: String,
+    pub bucket: String,
+    pub total: String,
+    pub transactions: String,
+    pub by_kind: Vec<FeeByKind>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountFees {
+    pub token_address: String,
+    pub total: String,
+    pub transactions: String,
+    pub by_kind: Vec<FeeByKind>,
+}
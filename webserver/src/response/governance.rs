@@ -7,8 +7,9 @@ use orm::governance_proposal::{
 };
 use orm::governance_votes::{GovernanceProposalVoteDb, GovernanceVoteKindDb};
 use serde::{Deserialize, Serialize};
-use sha256::digest;
-use subtle_encoding::hex;
+use shared::vote::{
+    GovernanceParticipation as GovernanceParticipationStats, VotesTally,
+};
 
 use super::utils::{epoch_progress, time_between_epochs};
 
@@ -89,11 +90,25 @@ impl Display for ProposalStatus {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalContent {
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub r#abstract: Option<String>,
+    pub motivation: Option<String>,
+    pub details: Option<String>,
+    pub requires: Option<String>,
+    pub created: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Proposal {
     pub id: String,
     pub content: String,
+    pub structured_content: ProposalContent,
     pub r#type: ProposalType,
     pub tally_type: TallyType,
     pub data: Option<String>,
@@ -117,6 +132,88 @@ pub struct ProposalVote {
     pub proposal_id: u64,
     pub vote: VoteType,
     pub voter_address: String,
+    pub voting_power: Option<String>,
+    pub is_validator: Option<bool>,
+    pub voting_power_epoch: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalCode {
+    pub proposal_id: String,
+    pub code: String,
+    pub hash: String,
+    pub size: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalCodeVerification {
+    pub proposal_id: String,
+    pub hash: String,
+    pub submitted_hash: String,
+    pub matches: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteTimelinePoint {
+    pub bucket: String,
+    pub yay_voting_power: String,
+    pub nay_voting_power: String,
+    pub abstain_voting_power: String,
+    pub yay_voters: String,
+    pub nay_voters: String,
+    pub abstain_voters: String,
+}
+
+impl VoteTimelinePoint {
+    pub fn new(bucket: i64, tally: VotesTally) -> Self {
+        Self {
+            bucket: bucket.to_string(),
+            yay_voting_power: tally.yay_power.to_string(),
+            nay_voting_power: tally.nay_power.to_string(),
+            abstain_voting_power: tally.abstain_power.to_string(),
+            yay_voters: tally.yay_voters.to_string(),
+            nay_voters: tally.nay_voters.to_string(),
+            abstain_voters: tally.abstain_voters.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceParticipation {
+    pub address: String,
+    pub eligible_proposals: String,
+    pub voted_proposals: String,
+    pub participation_rate: Option<String>,
+    pub decided_votes: String,
+    pub aligned_votes: String,
+    pub alignment_rate: Option<String>,
+    pub last_vote_timestamp: Option<String>,
+}
+
+impl GovernanceParticipation {
+    pub fn new(
+        address: String,
+        stats: GovernanceParticipationStats,
+        last_vote_timestamp: Option<chrono::NaiveDateTime>,
+    ) -> Self {
+        Self {
+            address,
+            eligible_proposals: stats.eligible_proposals.to_string(),
+            voted_proposals: stats.voted_proposals.to_string(),
+            participation_rate: stats
+                .participation_rate()
+                .map(|rate| rate.to_string()),
+            decided_votes: stats.decided_votes.to_string(),
+            aligned_votes: stats.aligned_votes.to_string(),
+            alignment_rate: stats.alignment_rate().map(|rate| rate.to_string()),
+            last_vote_timestamp: last_vote_timestamp
+                .map(|timestamp| timestamp.and_utc().timestamp().to_string()),
+        }
+    }
 }
 
 impl Proposal {
@@ -166,6 +263,16 @@ impl Proposal {
         Self {
             id: value.id.to_string(),
             content: value.content,
+            structured_content: ProposalContent {
+                title: value.title,
+                authors: value.authors,
+                discussions_to: value.discussions_to,
+                r#abstract: value.r#abstract,
+                motivation: value.motivation,
+                details: value.details,
+                requires: value.requires,
+                created: value.created,
+            },
             r#type: match value.kind {
                 GovernanceProposalKindDb::PgfSteward => {
                     ProposalType::PgfSteward
@@ -190,13 +297,7 @@ impl Proposal {
                 }
             },
             data: match value.kind {
-                GovernanceProposalKindDb::DefaultWithWasm => {
-                    value.data.map(|data| {
-                        let hex_decoded_bytes =
-                            hex::decode(data).unwrap_or_default();
-                        digest(hex_decoded_bytes)
-                    })
-                }
+                GovernanceProposalKindDb::DefaultWithWasm => value.code_hash,
                 _ => value.data,
             },
             author: value.author,
@@ -243,6 +344,11 @@ impl From<GovernanceProposalVoteDb> for ProposalVote {
                 GovernanceVoteKindDb::Unknown => VoteType::Unknown,
             },
             voter_address: value.voter_address,
+            voting_power: value.voting_power.map(|power| power.to_string()),
+            is_validator: value.is_validator,
+            voting_power_epoch: value
+                .voting_power_epoch
+                .map(|epoch| epoch.to_string()),
         }
     }
 }
@@ -1,4 +1,14 @@
+use bigdecimal::BigDecimal;
+use orm::ibc::{
+    IbcAckStatusDb, IbcChannelDb, IbcChannelStateDb, IbcClientDb,
+    IbcConnectionDb, IbcPacketDb, IbcPacketDirectionDb, IbcPacketStatusDb,
+    IbcRateLimitsDb, IbcTokenFlowsDb,
+};
+use orm::token::IbcTokenDb;
 use serde::{Deserialize, Serialize};
+use shared::ibc::{
+    IbcDenomTrace as SharedIbcDenomTrace, ibc_rate_limit_utilization,
+};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -9,6 +19,17 @@ pub enum IbcAckStatus {
     Unknown,
 }
 
+impl From<IbcAckStatusDb> for IbcAckStatus {
+    fn from(value: IbcAckStatusDb) -> Self {
+        match value {
+            IbcAckStatusDb::Unknown => Self::Unknown,
+            IbcAckStatusDb::Timeout => Self::Timeout,
+            IbcAckStatusDb::Fail => Self::Fail,
+            IbcAckStatusDb::Success => Self::Success,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IbcAck {
@@ -37,3 +58,283 @@ pub struct IbcTokenThroughput {
     pub throughput: String,
     pub limit: String,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcRateLimitUtilization {
+    pub token_address: String,
+    pub epoch: String,
+    pub deposit: String,
+    pub withdraw: String,
+    pub throughput_limit: String,
+    pub utilization: Option<String>,
+    pub near_limit: bool,
+}
+
+impl IbcRateLimitUtilization {
+    pub fn from(
+        flows: IbcTokenFlowsDb,
+        rate_limit: IbcRateLimitsDb,
+        alert_threshold: u64,
+    ) -> Self {
+        let utilization = ibc_rate_limit_utilization(
+            &flows.deposit,
+            &flows.withdraw,
+            &rate_limit.throughput_limit,
+        );
+        let near_limit = utilization.as_ref().is_some_and(|utilization| {
+            *utilization >= BigDecimal::from(alert_threshold)
+        });
+
+        Self {
+            token_address: flows.address,
+            epoch: flows.epoch.to_string(),
+            deposit: flows.deposit.to_string(),
+            withdraw: flows.withdraw.to_string(),
+            throughput_limit: rate_limit.throughput_limit.to_string(),
+            utilization: utilization.map(|utilization| utilization.to_string()),
+            near_limit,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcPacketDirection {
+    Outgoing,
+    Incoming,
+}
+
+impl From<IbcPacketDirectionDb> for IbcPacketDirection {
+    fn from(value: IbcPacketDirectionDb) -> Self {
+        match value {
+            IbcPacketDirectionDb::Outgoing => Self::Outgoing,
+            IbcPacketDirectionDb::Incoming => Self::Incoming,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcPacketStatus {
+    Sent,
+    Received,
+    Acknowledged,
+    TimedOut,
+}
+
+impl From<IbcPacketStatusDb> for IbcPacketStatus {
+    fn from(value: IbcPacketStatusDb) -> Self {
+        match value {
+            IbcPacketStatusDb::Sent => Self::Sent,
+            IbcPacketStatusDb::Received => Self::Received,
+            IbcPacketStatusDb::Acknowledged => Self::Acknowledged,
+            IbcPacketStatusDb::TimedOut => Self::TimedOut,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcPacket {
+    pub direction: IbcPacketDirection,
+    pub status: IbcPacketStatus,
+    pub ack_status: Option<IbcAckStatus>,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: String,
+    pub denom: Option<String>,
+    pub amount: Option<String>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub timeout_height: String,
+    pub timeout_timestamp: String,
+    pub sent_at_height: Option<String>,
+    pub received_at_height: Option<String>,
+    pub acknowledged_at_height: Option<String>,
+    pub timed_out_at_height: Option<String>,
+}
+
+impl IbcPacket {
+    pub fn from(
+        packet: IbcPacketDb,
+        ack_status: Option<IbcAckStatusDb>,
+    ) -> Self {
+        Self {
+            direction: packet.direction.into(),
+            status: packet.status.into(),
+            ack_status: ack_status.map(IbcAckStatus::from),
+            source_port: packet.source_port,
+            source_channel: packet.source_channel,
+            dest_port: packet.dest_port,
+            dest_channel: packet.dest_channel,
+            sequence: packet.sequence.to_string(),
+            denom: packet.denom,
+            amount: packet.amount.map(|amount| amount.to_string()),
+            sender: packet.sender,
+            receiver: packet.receiver,
+            memo: packet.memo,
+            timeout_height: packet.timeout_height,
+            timeout_timestamp: packet.timeout_timestamp.to_string(),
+            sent_at_height: packet
+                .sent_at_height
+                .map(|height| height.to_string()),
+            received_at_height: packet
+                .received_at_height
+                .map(|height| height.to_string()),
+            acknowledged_at_height: packet
+                .acknowledged_at_height
+                .map(|height| height.to_string()),
+            timed_out_at_height: packet
+                .timed_out_at_height
+                .map(|height| height.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcChannelState {
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<IbcChannelStateDb> for IbcChannelState {
+    fn from(value: IbcChannelStateDb) -> Self {
+        match value {
+            IbcChannelStateDb::Init => Self::Init,
+            IbcChannelStateDb::TryOpen => Self::TryOpen,
+            IbcChannelStateDb::Open => Self::Open,
+            IbcChannelStateDb::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannelVolume {
+    pub denom: String,
+    pub transfers: String,
+    pub amount: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannel {
+    pub channel_id: String,
+    pub port_id: String,
+    pub state: IbcChannelState,
+    pub counterparty_channel_id: Option<String>,
+    pub counterparty_port_id: String,
+    pub connection_id: String,
+    pub counterparty_connection_id: Option<String>,
+    pub client_id: Option<String>,
+    pub counterparty_client_id: Option<String>,
+    pub client_type: Option<String>,
+    pub counterparty_chain_id: Option<String>,
+    pub volume: Vec<IbcChannelVolume>,
+    pub last_activity_height: Option<String>,
+    pub last_activity_timestamp: Option<String>,
+}
+
+impl IbcChannel {
+    pub fn from(
+        channel: IbcChannelDb,
+        connection: Option<IbcConnectionDb>,
+        client: Option<IbcClientDb>,
+        volume: Vec<IbcChannelVolume>,
+        last_activity: Option<(i32, Option<chrono::NaiveDateTime>)>,
+    ) -> Self {
+        Self {
+            channel_id: channel.channel_id,
+            port_id: channel.port_id,
+            state: channel.state.into(),
+            counterparty_channel_id: channel.counterparty_channel_id,
+            counterparty_port_id: channel.counterparty_port_id,
+            connection_id: channel.connection_id,
+            counterparty_connection_id: connection.as_ref().and_then(
+                |connection| connection.counterparty_connection_id.clone(),
+            ),
+            client_id: connection
+                .as_ref()
+                .map(|connection| connection.client_id.clone()),
+            counterparty_client_id: connection
+                .map(|connection| connection.counterparty_client_id),
+            client_type: client
+                .as_ref()
+                .map(|client| client.client_type.clone()),
+            counterparty_chain_id: client
+                .and_then(|client| client.counterparty_chain_id),
+            volume,
+            last_activity_height: last_activity
+                .map(|(height, _)| height.to_string()),
+            last_activity_timestamp: last_activity
+                .and_then(|(_, timestamp)| timestamp)
+                .map(|timestamp| timestamp.and_utc().timestamp().to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcDenomHop {
+    pub port_id: String,
+    pub channel_id: String,
+    pub counterparty_chain_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcDenomTrace {
+    pub address: String,
+    pub trace: String,
+    pub base_denom: String,
+    pub path: Vec<IbcDenomHop>,
+    pub origin_chain_id: Option<String>,
+}
+
+impl IbcDenomTrace {
+    /// Only the first hop refers to a channel on this chain, so it is the only
+    /// one whose counterparty can be resolved from the indexed channels
+    pub fn from(
+        token: IbcTokenDb,
+        counterparty_chain_id: Option<String>,
+    ) -> Self {
+        let denom_trace = SharedIbcDenomTrace::parse(&token.ibc_trace);
+
+        let path = denom_trace
+            .hops
+            .into_iter()
+            .enumerate()
+            .map(|(index, hop)| IbcDenomHop {
+                port_id: hop.port_id,
+                channel_id: hop.channel_id,
+                counterparty_chain_id: if index == 0 {
+                    counterparty_chain_id.clone()
+                } else {
+                    None
+                },
+            })
+            .collect::<Vec<_>>();
+
+        // With a single hop the counterparty is also where the token
+        // originates from
+        let origin_chain_id = match path.as_slice() {
+            [hop] => hop.counterparty_chain_id.clone(),
+            _ => None,
+        };
+
+        Self {
+            address: token.address,
+            trace: token.ibc_trace,
+            base_denom: denom_trace.base_denom,
+            path,
+            origin_chain_id,
+        }
+    }
+}
@@ -1,7 +1,7 @@
-use orm::masp::{
-    MaspPoolAggregateKindDb, MaspPoolAggregateWindowDb, MaspPoolDb,
-};
+use bigdecimal::Zero;
+use orm::masp::MaspRewardDb;
 use serde::{Deserialize, Serialize};
+use shared::masp::MaspTvl as SharedMaspTvl;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -28,33 +28,89 @@ pub struct MaspPoolAggregateResponse {
     pub total_amount: String,
 }
 
-impl From<MaspPoolDb> for MaspPoolAggregateResponse {
-    fn from(value: MaspPoolDb) -> Self {
-        MaspPoolAggregateResponse {
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspFlow {
+    pub token_address: String,
+    pub bucket: String,
+    pub inflow: String,
+    pub outflow: String,
+    pub net: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspTvl {
+    pub token_address: String,
+    pub epoch: String,
+    pub tvl: String,
+    pub masp_balance: Option<String>,
+    pub difference: Option<String>,
+    pub reconciled: bool,
+}
+
+impl From<SharedMaspTvl> for MaspTvl {
+    fn from(value: SharedMaspTvl) -> Self {
+        let difference = value
+            .masp_balance
+            .as_ref()
+            .map(|masp_balance| masp_balance - &value.tvl);
+
+        Self {
             token_address: value.token_address,
-            time_window: match value.time_window {
-                MaspPoolAggregateWindowDb::OneDay => {
-                    MaspPoolAggregateWindow::OneDay
-                }
-                MaspPoolAggregateWindowDb::SevenDays => {
-                    MaspPoolAggregateWindow::SevenDays
-                }
-                MaspPoolAggregateWindowDb::ThirtyDays => {
-                    MaspPoolAggregateWindow::ThirtyDays
-                }
-                MaspPoolAggregateWindowDb::AllTime => {
-                    MaspPoolAggregateWindow::AllTime
-                }
-            },
-            kind: match value.kind {
-                MaspPoolAggregateKindDb::Inflows => {
-                    MaspPoolAggregateKind::Inflows
-                }
-                MaspPoolAggregateKindDb::Outflows => {
-                    MaspPoolAggregateKind::Outflows
-                }
-            },
-            total_amount: value.total_amount.to_string(),
+            epoch: value.epoch.to_string(),
+            tvl: value.tvl.to_string(),
+            masp_balance: value
+                .masp_balance
+                .map(|masp_balance| masp_balance.to_string()),
+            reconciled: difference
+                .as_ref()
+                .is_some_and(|difference| difference.is_zero()),
+            difference: difference.map(|difference| difference.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspReward {
+    pub token_address: String,
+    pub epoch: String,
+    pub reward_rate: String,
+    pub max_reward_rate: String,
+    pub kp_gain: String,
+    pub kd_gain: String,
+    pub locked_amount_target: String,
+    pub last_inflation: String,
+    pub last_locked_amount: String,
+}
+
+impl From<MaspRewardDb> for MaspReward {
+    fn from(value: MaspRewardDb) -> Self {
+        Self {
+            token_address: value.token,
+            epoch: value.epoch.to_string(),
+            reward_rate: value.reward_rate.to_string(),
+            max_reward_rate: value.max_reward_rate.to_string(),
+            kp_gain: value.kp_gain.to_string(),
+            kd_gain: value.kd_gain.to_string(),
+            locked_amount_target: value.locked_amount_target.to_string(),
+            last_inflation: value.last_inflation.to_string(),
+            last_locked_amount: value.last_locked_amount.to_string(),
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspStats {
+    pub bucket: String,
+    pub shielded_transfers: String,
+    pub shielding_transfers: String,
+    pub unshielding_transfers: String,
+    pub mixed_transfers: String,
+    pub notes: String,
+    pub wrappers: String,
+    pub masp_fee_payments: String,
+    pub masp_fee_payment_share: String,
+}
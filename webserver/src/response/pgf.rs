@@ -1,4 +1,10 @@
-use orm::pgf::{PaymentKindDb, PaymentRecurrenceDb};
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+use orm::pgf::{
+    PaymentKindDb, PaymentRecurrenceDb, PgfDisbursementDb, PgfStewardDb,
+    PgfStewardEventDb, PgfStewardEventKindDb,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -42,3 +48,129 @@ pub struct PgfPayment {
     pub receipient: String,
     pub amount: String,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PgfStewardEventKind {
+    Added,
+    Removed,
+    Resigned,
+    CommissionUpdate,
+}
+
+impl From<PgfStewardEventKindDb> for PgfStewardEventKind {
+    fn from(value: PgfStewardEventKindDb) -> Self {
+        match value {
+            PgfStewardEventKindDb::Added => Self::Added,
+            PgfStewardEventKindDb::Removed => Self::Removed,
+            PgfStewardEventKindDb::Resigned => Self::Resigned,
+            PgfStewardEventKindDb::CommissionUpdate => Self::CommissionUpdate,
+        }
+    }
+}
+
+/// Reward distribution of a steward, from receiver address to share
+pub type PgfStewardCommission = BTreeMap<String, String>;
+
+fn parse_commission(commission: &str) -> PgfStewardCommission {
+    serde_json::from_str(commission).unwrap_or_default()
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfSteward {
+    pub address: String,
+    pub commission: PgfStewardCommission,
+}
+
+impl From<PgfStewardDb> for PgfSteward {
+    fn from(value: PgfStewardDb) -> Self {
+        Self {
+            commission: parse_commission(&value.commission),
+            address: value.address,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfStewardEvent {
+    pub kind: PgfStewardEventKind,
+    pub proposal_id: Option<String>,
+    pub block_height: Option<String>,
+    pub timestamp: Option<String>,
+    pub inner_tx_id: Option<String>,
+    pub commission: Option<PgfStewardCommission>,
+}
+
+impl PgfStewardEvent {
+    pub fn from(
+        event: PgfStewardEventDb,
+        timestamp: Option<chrono::NaiveDateTime>,
+    ) -> Self {
+        Self {
+            kind: event.kind.into(),
+            proposal_id: event.proposal_id.map(|id| id.to_string()),
+            block_height: event.block_height.map(|height| height.to_string()),
+            timestamp: timestamp
+                .map(|timestamp| timestamp.and_utc().timestamp().to_string()),
+            inner_tx_id: event.inner_tx_id,
+            commission: event
+                .commission
+                .map(|commission| parse_commission(&commission)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfStewardDetails {
+    pub address: String,
+    pub is_active: bool,
+    pub commission: Option<PgfStewardCommission>,
+    pub history: Vec<PgfStewardEvent>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfDisbursement {
+    pub receipient: String,
+    pub token: String,
+    pub epoch: String,
+    pub block_height: String,
+    pub amount: String,
+}
+
+impl From<PgfDisbursementDb> for PgfDisbursement {
+    fn from(value: PgfDisbursementDb) -> Self {
+        Self {
+            receipient: value.receipient,
+            token: value.token,
+            epoch: value.epoch.to_string(),
+            block_height: value.block_height.to_string(),
+            amount: value.amount.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfTreasuryEpoch {
+    pub epoch: String,
+    pub token: String,
+    pub total_amount: String,
+}
+
+impl PgfTreasuryEpoch {
+    pub fn from(
+        epoch: i32,
+        token: String,
+        total_amount: Option<BigDecimal>,
+    ) -> Self {
+        Self {
+            epoch: epoch.to_string(),
+            token,
+            total_amount: total_amount.unwrap_or_default().to_string(),
+        }
+    }
+}
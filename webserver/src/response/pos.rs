@@ -1,11 +1,14 @@
 use bigdecimal::BigDecimal;
 use orm::bond::BondDb;
 use orm::crawler_state::{ChainCrawlerStateDb, EpochCrawlerStateDb};
+use orm::decentralization::DecentralizationMetricsDb;
 use orm::pos_rewards::PoSRewardDb;
 use orm::unbond::UnbondDb;
-use orm::validators::{ValidatorDb, ValidatorStateDb};
+use orm::validator_events::{ValidatorEventDb, ValidatorEventKindDb};
+use orm::validators::{ValidatorDb, ValidatorSetChangeDb, ValidatorStateDb};
 use serde::{Deserialize, Serialize};
 
+use super::governance::GovernanceParticipation;
 use super::utils::{epoch_progress, time_between_epochs};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -116,6 +119,90 @@ pub struct TotalVotingPower {
     pub total_voting_power: String,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ValidatorEventKind {
+    BecomeValidator,
+    Deactivation,
+    Reactivation,
+    Unjail,
+    MetadataChange,
+    CommissionChange,
+    ConsensusKeyChange,
+}
+
+impl From<ValidatorEventKindDb> for ValidatorEventKind {
+    fn from(value: ValidatorEventKindDb) -> Self {
+        match value {
+            ValidatorEventKindDb::BecomeValidator => Self::BecomeValidator,
+            ValidatorEventKindDb::Deactivation => Self::Deactivation,
+            ValidatorEventKindDb::Reactivation => Self::Reactivation,
+            ValidatorEventKindDb::Unjail => Self::Unjail,
+            ValidatorEventKindDb::MetadataChange => Self::MetadataChange,
+            ValidatorEventKindDb::CommissionChange => Self::CommissionChange,
+            ValidatorEventKindDb::ConsensusKeyChange => {
+                Self::ConsensusKeyChange
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorEvent {
+    pub kind: ValidatorEventKind,
+    pub block_height: String,
+    pub timestamp: Option<String>,
+    pub inner_tx_id: String,
+    pub data: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectedReward {
+    pub epoch: String,
+    pub min_denom_amount: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardsProjection {
+    pub validator: ValidatorWithId,
+    pub min_denom_amount: String,
+    pub current_epoch: String,
+    pub first_reward_epoch: String,
+    pub withdraw_epoch: String,
+    pub apr: String,
+    pub commission: String,
+    pub nominal_reward_rate: String,
+    pub realized_reward_rate: Option<String>,
+    pub total_min_denom_amount: String,
+    pub rewards: Vec<ProjectedReward>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorSetChange {
+    pub address: String,
+    pub epoch: String,
+    pub previous_state: Option<ValidatorState>,
+    /// Missing when the validator is no longer part of the set
+    pub current_state: Option<ValidatorState>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecentralizationMetrics {
+    pub epoch: String,
+    pub consensus_validators: String,
+    pub total_voting_power: String,
+    pub nakamoto_coefficient_one_third: String,
+    pub nakamoto_coefficient_two_thirds: String,
+    pub gini_coefficient: String,
+    pub top_n: String,
+    pub top_n_share: String,
+}
+
 impl From<ValidatorDb> for Validator {
     fn from(value: ValidatorDb) -> Self {
         Self {
@@ -141,6 +228,7 @@ pub struct ValidatorWithId {
     pub validator: Validator,
     pub validator_id: String,
     pub rank: Option<i32>,
+    pub governance_participation: Option<GovernanceParticipation>,
 }
 
 impl ValidatorWithId {
@@ -149,8 +237,17 @@ impl ValidatorWithId {
             validator_id: db_validator.id.to_string(),
             validator: Validator::from(db_validator),
             rank,
+            governance_participation: None,
         }
     }
+
+    pub fn with_governance_participation(
+        mut self,
+        governance_participation: GovernanceParticipation,
+    ) -> Self {
+        self.governance_participation = Some(governance_participation);
+        self
+    }
 }
 
 impl From<(&BondDb, &EpochCrawlerStateDb)> for BondStatus {
@@ -298,3 +395,48 @@ impl Reward {
         }
     }
 }
+
+impl From<DecentralizationMetricsDb> for DecentralizationMetrics {
+    fn from(value: DecentralizationMetricsDb) -> Self {
+        Self {
+            epoch: value.epoch.to_string(),
+            consensus_validators: value.consensus_validators.to_string(),
+            total_voting_power: value.total_voting_power.to_string(),
+            nakamoto_coefficient_one_third: value
+                .nakamoto_coefficient_one_third
+                .to_string(),
+            nakamoto_coefficient_two_thirds: value
+                .nakamoto_coefficient_two_thirds
+                .to_string(),
+            gini_coefficient: value.gini_coefficient.to_string(),
+            top_n: value.top_n.to_string(),
+            top_n_share: value.top_n_share.to_string(),
+        }
+    }
+}
+
+impl ValidatorEvent {
+    pub fn from(
+        db_event: ValidatorEventDb,
+        timestamp: Option<chrono::NaiveDateTime>,
+    ) -> Self {
+        Self {
+            kind: db_event.kind.into(),
+            block_height: db_event.block_height.to_string(),
+            timestamp: timestamp.map(|t| t.and_utc().timestamp().to_string()),
+            inner_tx_id: db_event.inner_tx_id,
+            data: db_event.data,
+        }
+    }
+}
+
+impl From<ValidatorSetChangeDb> for ValidatorSetChange {
+    fn from(value: ValidatorSetChangeDb) -> Self {
+        Self {
+            address: value.address,
+            epoch: value.epoch.to_string(),
+            previous_state: value.previous_state.map(ValidatorState::from),
+            current_state: value.current_state.map(ValidatorState::from),
+        }
+    }
+}
@@ -39,6 +39,8 @@ pub enum TransactionKind {
     UnjailValidator,
     InitAccount,
     ChangeConsensusKey,
+    UpdateStewardCommission,
+    ResignSteward,
     Unknown,
 }
 
@@ -121,6 +123,10 @@ impl From<TransactionKindDb> for TransactionKind {
             TransactionKindDb::Unknown => Self::Unknown,
             TransactionKindDb::IbcMsgTransfer => Self::IbcMsgTransfer,
             TransactionKindDb::ChangeConsensusKey => Self::ChangeConsensusKey,
+            TransactionKindDb::UpdateStewardCommission => {
+                Self::UpdateStewardCommission
+            }
+            TransactionKindDb::ResignSteward => Self::ResignSteward,
             TransactionKindDb::IbcTransparentTransfer => {
                 Self::IbcTransparentTransfer
             }
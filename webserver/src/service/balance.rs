@@ -1,9 +1,11 @@
+use bigdecimal::{BigDecimal, Zero};
 use shared::balance::Amount;
 
 use crate::appstate::AppState;
 use crate::error::balance::BalanceError;
 use crate::repository::balance::{BalanceRepo, BalanceRepoTrait};
 use crate::response::balance::AddressBalance;
+use crate::service::utils::resolve_token_metadata;
 
 #[derive(Clone)]
 pub struct BalanceService {
@@ -36,14 +38,24 @@ impl BalanceService {
         // TODO: temporary solution as we only store NAM balances
         let denominated_balances = tokens
             .into_iter()
-            .map(|token| AddressBalance {
-                token_address: token.address.clone(),
-                min_denom_amount: balances
+            .map(|(token, ibc_token, metadata)| {
+                let metadata =
+                    resolve_token_metadata(&token.address, ibc_token, metadata);
+                let raw_amount = balances
                     .iter()
                     .find(|&balance| balance.token.eq(&token.address))
-                    .cloned()
-                    .map(|balance| Amount::from(balance.raw_amount).to_string())
-                    .unwrap_or_else(|| Amount::zero().to_string()),
+                    .map(|balance| balance.raw_amount.clone())
+                    .unwrap_or_else(BigDecimal::zero);
+
+                AddressBalance {
+                    token_address: token.address,
+                    denominated_amount: metadata
+                        .denominate(&raw_amount)
+                        .to_plain_string(),
+                    min_denom_amount: Amount::from(raw_amount).to_string(),
+                    symbol: metadata.symbol,
+                    decimals: metadata.decimals,
+                }
             })
             .collect();
 
@@ -1,12 +1,34 @@
-use bigdecimal::BigDecimal;
+use std::collections::{BTreeMap, HashMap};
+
+use bigdecimal::{BigDecimal, Zero};
+use shared::balance::{annual_inflation_rate, minted_per_epoch, staked_ratio};
 use shared::id::Id;
-use shared::token::{IbcToken, Token};
+use shared::token::{IbcToken, Token, TokenMetadata};
+use shared::utils::internal_address_label;
 
 use crate::appstate::AppState;
 use crate::error::chain::ChainError;
 use crate::repository::balance::{BalanceRepo, BalanceRepoTrait};
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
-use crate::response::chain::{CirculatingSupply, Parameters, TokenSupply};
+use crate::response::chain::{
+    CirculatingSupply, Parameters, SupplyHistoryEntry, TokenHolder,
+    TokenHolderCount, TokenSupply,
+};
+use crate::service::utils::resolve_token_metadata;
+
+// Native token address and addresses to exclude from circulating supply
+const NATIVE_TOKEN_ADDRESS: &str =
+    "tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7";
+const CIRCULATING_SUPPLY_EXCLUDED_ADDRESSES: [&str; 5] = [
+    "tnam1qxdzup2hcvhswcgw5kerd5lfkf04t64y3scgqm5v",
+    "tnam1qxt7uxhj9r00mfm4u870e7ghz6j20jrdz58gm5kj",
+    "tnam1qyez9fd9nkaxfj4u2f2k0vavr8mm69azcgds45rr",
+    "tnam1qqp69rzwsgnqdm0d4qfhw4qa4s6v3tlzm5069f4j",
+    "tnam1qrucghh3hw2zq8xtqzdj44nh5nrmnkn0usqng8yq",
+];
+
+// Number of decimals kept for the ratios of the supply history
+const SUPPLY_RATIO_SCALE: i64 = 8;
 
 #[derive(Clone)]
 pub struct ChainService {
@@ -47,7 +69,9 @@ impl ChainService {
             .map_err(ChainError::Database)
     }
 
-    pub async fn find_tokens(&self) -> Result<Vec<Token>, ChainError> {
+    pub async fn find_tokens(
+        &self,
+    ) -> Result<Vec<(Token, TokenMetadata)>, ChainError> {
         let tokens_db = self
             .chain_repo
             .find_tokens()
@@ -56,14 +80,23 @@ impl ChainService {
 
         let tokens = tokens_db
             .into_iter()
-            .map(|(token, ibc_token)| match ibc_token {
-                Some(ibc_token) => Token::Ibc(IbcToken {
-                    address: Id::Account(ibc_token.address),
-                    trace: Some(Id::IbcTrace(ibc_token.ibc_trace)),
-                }),
-                None => Token::Native(Id::Account(token.address)),
+            .map(|(token, ibc_token, metadata)| {
+                let metadata = resolve_token_metadata(
+                    &token.address,
+                    ibc_token.clone(),
+                    metadata,
+                );
+                let token = match ibc_token {
+                    Some(ibc_token) => Token::Ibc(IbcToken {
+                        address: Id::Account(ibc_token.address),
+                        trace: Some(Id::IbcTrace(ibc_token.ibc_trace)),
+                    }),
+                    None => Token::Native(Id::Account(token.address)),
+                };
+
+                (token, metadata)
             })
-            .collect::<Vec<Token>>();
+            .collect::<Vec<_>>();
 
         Ok(tokens)
     }
@@ -90,16 +123,8 @@ impl ChainService {
         &self,
         epoch: Option<i32>,
     ) -> Result<CirculatingSupply, ChainError> {
-        // Native token address and addresses to exclude from circulating supply
-        let native_token_address =
-            "tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7";
-        let excluded_addresses = vec![
-            "tnam1qxdzup2hcvhswcgw5kerd5lfkf04t64y3scgqm5v",
-            "tnam1qxt7uxhj9r00mfm4u870e7ghz6j20jrdz58gm5kj",
-            "tnam1qyez9fd9nkaxfj4u2f2k0vavr8mm69azcgds45rr",
-            "tnam1qqp69rzwsgnqdm0d4qfhw4qa4s6v3tlzm5069f4j",
-            "tnam1qrucghh3hw2zq8xtqzdj44nh5nrmnkn0usqng8yq",
-        ];
+        let native_token_address = NATIVE_TOKEN_ADDRESS;
+        let excluded_addresses = CIRCULATING_SUPPLY_EXCLUDED_ADDRESSES;
 
         // Get total supply of native token
         let total_supply_result = self
@@ -146,4 +171,185 @@ impl ChainService {
             circulating_supply: circulating_amount.to_string(),
         })
     }
+
+    pub async fn find_token_holders(
+        &self,
+        address: String,
+        page: u64,
+    ) -> Result<(Vec<TokenHolder>, u64, u64), ChainError> {
+        let total_supply = self
+            .chain_repo
+            .find_latest_token_supply(address.clone())
+            .await
+            .map_err(ChainError::Database)?
+            .map(|supply| supply.total)
+            .filter(|total| !total.is_zero());
+
+        let (balances, total_pages, total_items) = self
+            .chain_repo
+            .find_token_holders(address, page as i64)
+            .await
+            .map_err(ChainError::Database)?;
+
+        let holders = balances
+            .into_iter()
+            .map(|balance| {
+                let share = total_supply.as_ref().map(|total| {
+                    (&balance.raw_amount * BigDecimal::from(100) / total)
+                        .round(4)
+                        .to_string()
+                });
+
+                TokenHolder {
+                    label: internal_address_label(&balance.owner)
+                        .map(String::from),
+                    address: balance.owner,
+                    min_denom_amount: balance.raw_amount.to_string(),
+                    share,
+                }
+            })
+            .collect();
+
+        Ok((holders, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn find_token_holders_history(
+        &self,
+        address: String,
+    ) -> Result<Vec<TokenHolderCount>, ChainError> {
+        let history = self
+            .chain_repo
+            .find_token_holders_history(address)
+            .await
+            .map_err(ChainError::Database)?;
+
+        Ok(history
+            .into_iter()
+            .map(|entry| TokenHolderCount {
+                epoch: entry.epoch.to_string(),
+                holders: entry.holders.to_string(),
+            })
+            .collect())
+    }
+
+    pub async fn get_supply_history(
+        &self,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<SupplyHistoryEntry>, ChainError> {
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(ChainError::Database)?;
+        let native_token_address = parameters.native_token_address.clone();
+
+        let supplies = self
+            .chain_repo
+            .find_token_supplies(
+                native_token_address.clone(),
+                from_epoch,
+                to_epoch,
+            )
+            .await
+            .map_err(ChainError::Database)?;
+
+        let Some(first_supply) = supplies.first() else {
+            return Ok(vec![]);
+        };
+
+        // The supply preceding the range is needed to derive the amount minted
+        // in its first epoch
+        let previous_supply = self
+            .chain_repo
+            .find_previous_token_supply(
+                native_token_address.clone(),
+                first_supply.epoch,
+            )
+            .await
+            .map_err(ChainError::Database)?;
+
+        let balance_changes = self
+            .chain_repo
+            .find_balance_changes_by_epoch(
+                CIRCULATING_SUPPLY_EXCLUDED_ADDRESSES
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect(),
+                native_token_address.clone(),
+                to_epoch,
+            )
+            .await
+            .map_err(ChainError::Database)?;
+
+        // Total amount held by the excluded addresses at the end of each epoch
+        // in which one of their balances changed
+        let mut balances = HashMap::new();
+        let mut locked_by_epoch = BTreeMap::new();
+        for (epoch, owner, raw_amount) in balance_changes {
+            let Some(epoch) = epoch else {
+                continue;
+            };
+            balances.insert(owner, raw_amount);
+            locked_by_epoch
+                .insert(epoch, balances.values().sum::<BigDecimal>());
+        }
+
+        let epochs_per_year = parameters.epochs_per_year as u64;
+        let mut previous =
+            previous_supply.map(|supply| (supply.epoch, supply.total));
+
+        let history = supplies
+            .into_iter()
+            .map(|supply| {
+                let minted = previous.as_ref().and_then(|(epoch, total)| {
+                    minted_per_epoch(
+                        (*epoch, total),
+                        (supply.epoch, &supply.total),
+                    )
+                });
+                let inflation_rate = minted
+                    .as_ref()
+                    .zip(previous.as_ref())
+                    .and_then(|(minted, (_, previous_total))| {
+                        annual_inflation_rate(
+                            minted,
+                            previous_total,
+                            epochs_per_year,
+                        )
+                    });
+                let bonded_ratio = supply
+                    .bonded
+                    .as_ref()
+                    .and_then(|bonded| staked_ratio(bonded, &supply.total));
+
+                let locked = locked_by_epoch
+                    .range(..=supply.epoch)
+                    .next_back()
+                    .map(|(_, locked)| locked.clone())
+                    .unwrap_or_else(BigDecimal::zero);
+                let circulating = supply
+                    .effective
+                    .as_ref()
+                    .map(|effective| effective - locked);
+
+                previous = Some((supply.epoch, supply.total.clone()));
+
+                SupplyHistoryEntry {
+                    epoch: supply.epoch.to_string(),
+                    total_supply: supply.total.to_string(),
+                    effective_supply: supply.effective.map(|s| s.to_string()),
+                    circulating_supply: circulating.map(|s| s.to_string()),
+                    bonded_supply: supply.bonded.map(|s| s.to_string()),
+                    minted: minted.map(|m| m.round(0).to_string()),
+                    inflation_rate: inflation_rate
+                        .map(|r| r.round(SUPPLY_RATIO_SCALE).to_string()),
+                    staked_ratio: bonded_ratio
+                        .map(|r| r.round(SUPPLY_RATIO_SCALE).to_string()),
+                }
+            })
+            .collect();
+
+        Ok(history)
+    }
 }
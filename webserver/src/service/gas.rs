@@ -1,10 +1,20 @@
-use bigdecimal::ToPrimitive;
+use std::collections::BTreeMap;
+
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use shared::gas::tx_size_bucket_range;
 
 use crate::appstate::AppState;
+use crate::dto::utils::TimeBucketDto;
 use crate::error::gas::GasError;
 use crate::repository::gas::{GasRepository, GasRepositoryTrait};
-use crate::response::gas::{GasEstimate, GasPrice};
+use crate::response::gas::{
+    AccountFees, ChainFees, FeeByKind, GasEstimate, GasPrice, GasPriceChange,
+};
 use crate::response::transaction::TransactionKind;
+use crate::service::utils::{to_bucket_db, to_time_range};
+
+const TX_SIGNATURES_WINDOW_UPPERBOUND: u64 = 5;
+const TX_SIZE_NEARBY_BUCKETS: u64 = 2;
 
 #[derive(Clone)]
 pub struct GasService {
@@ -39,6 +49,113 @@ impl GasService {
             .map(|r| r.iter().cloned().map(GasPrice::from).collect())
     }
 
+    pub async fn get_gas_price_history_by_token(
+        &self,
+        token: String,
+        page: u64,
+    ) -> Result<(Vec<GasPriceChange>, u64, u64), GasError> {
+        let (history, total_pages, total_items) = self
+            .gas_repo
+            .find_gas_price_history_by_token(token, page as i64)
+            .await
+            .map_err(GasError::Database)?;
+
+        Ok((
+            history.into_iter().map(GasPriceChange::from).collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn get_chain_fees(
+        &self,
+        token: Option<String>,
+        from: Option<i64>,
+        to: Option<i64>,
+        bucket: TimeBucketDto,
+    ) -> Result<Vec<ChainFees>, GasError> {
+        let (from, to) =
+            to_time_range(from, to).map_err(GasError::InvalidRange)?;
+        let bucket = to_bucket_db(bucket);
+
+        let rollups = self
+            .gas_repo
+            .find_fees(token, from, to, bucket)
+            .await
+            .map_err(GasError::Database)?;
+
+        let mut fees: BTreeMap<
+            (i64, String),
+            (BigDecimal, i64, Vec<FeeByKind>),
+        > = BTreeMap::new();
+        for (token, bucket, kind, amount, transactions) in rollups {
+            let entry = fees.entry((bucket, token)).or_insert((
+                BigDecimal::zero(),
+                0,
+                vec![],
+            ));
+            entry.0 += &amount;
+            entry.1 += transactions;
+            entry.2.push(FeeByKind {
+                kind: kind.into(),
+                amount: amount.to_string(),
+                transactions: transactions.to_string(),
+            });
+        }
+
+        Ok(fees
+            .into_iter()
+            .map(
+                |((bucket, token_address), (total, transactions, by_kind))| {
+                    ChainFees {
+                        token_address,
+                        bucket: bucket.to_string(),
+                        total: total.to_string(),
+                        transactions: transactions.to_string(),
+                        by_kind,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    pub async fn get_account_fees(
+        &self,
+        address: String,
+    ) -> Result<Vec<AccountFees>, GasError> {
+        let rollups = self
+            .gas_repo
+            .find_fees_by_payer(address)
+            .await
+            .map_err(GasError::Database)?;
+
+        let mut fees: BTreeMap<String, (BigDecimal, i64, Vec<FeeByKind>)> =
+            BTreeMap::new();
+        for (token, kind, amount, transactions) in rollups {
+            let entry =
+                fees.entry(token).or_insert((BigDecimal::zero(), 0, vec![]));
+            entry.0 += &amount;
+            entry.1 += transactions;
+            entry.2.push(FeeByKind {
+                kind: kind.into(),
+                amount: amount.to_string(),
+                transactions: transactions.to_string(),
+            });
+        }
+
+        Ok(fees
+            .into_iter()
+            .map(|(token_address, (total, transactions, by_kind))| {
+                AccountFees {
+                    token_address,
+                    total: total.to_string(),
+                    transactions: transactions.to_string(),
+                    by_kind,
+                }
+            })
+            .collect())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn estimate_gas(
         &self,
@@ -58,103 +175,146 @@ impl GasService {
         reveal_pk: u64,
         signatures: u64,
         tx_size: u64,
+        block_window: u64,
     ) -> Result<GasEstimate, GasError> {
-        let (min, max, avg, count) = self
-            .gas_repo
-            .find_gas_estimates(
-                bond,
-                redelegate,
-                claim_rewards,
-                unbond,
-                transparent_transfer,
-                shielded_transfer,
-                shielding_transfer,
-                unshielding_transfer,
-                vote,
-                ibc_shielding_transfer,
-                ibc_unshielding_transfer,
-                ibc_transparent_transfer,
-                withdraw,
-                reveal_pk,
-                signatures,
-                tx_size,
-            )
-            .await
-            .map_err(GasError::Database)
-            .map(|(min, max, avg, count)| {
-                let min = min.map(|gas| gas as u64);
-                let max = max.map(|gas| gas as u64);
-                let avg = avg.map(|gas| gas.to_f64().unwrap() as u64);
-                let count = count as u64;
-                (min, max, avg, count)
-            })?;
-
-        if let (Some(min), Some(max), Some(avg), count) = (min, max, avg, count)
+        // Look for transactions with the same shape first, then widen the
+        // size window to nearby buckets and finally ignore the size entirely
+        // before falling back to the default gas table
+        // Counts and sizes are stored as i32, so larger bounds are clamped
+        // instead of wrapping around
+        let clamp = |bound: u64| i32::try_from(bound).unwrap_or(i32::MAX);
+        let (signature_lower_bound, signature_upper_bound) = if signatures == 0
         {
-            Ok(GasEstimate {
-                min,
-                max,
-                avg,
-                total_estimates: count,
-            })
+            (2, 5)
         } else {
-            let mut estimate = 0;
-            estimate += bond
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::Bond);
-            estimate += claim_rewards
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::ClaimRewards);
-            estimate += unbond
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::Unbond);
-            estimate += transparent_transfer
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::TransparentTransfer);
-            estimate += shielded_transfer
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::ShieldedTransfer);
-            estimate += shielding_transfer
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::ShieldingTransfer);
-            estimate += unshielding_transfer
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::UnshieldingTransfer);
-            estimate += vote
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::VoteProposal);
-            estimate += ibc_shielding_transfer
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::IbcShieldingTransfer);
-            estimate += ibc_unshielding_transfer
-                * self.default_gas_table.get_gas_by_tx_kind(
-                    TransactionKind::IbcUnshieldingTransfer,
-                );
-            estimate += withdraw
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::Withdraw);
-            estimate += reveal_pk
-                * self
-                    .default_gas_table
-                    .get_gas_by_tx_kind(TransactionKind::RevealPk);
-
-            Ok(GasEstimate {
-                min: estimate,
-                max: estimate,
-                avg: estimate,
-                total_estimates: 0,
-            })
+            (
+                clamp(signatures),
+                clamp(
+                    signatures.saturating_add(TX_SIGNATURES_WINDOW_UPPERBOUND),
+                ),
+            )
+        };
+        let tx_size_ranges = if tx_size == 0 {
+            vec![(0, i32::MAX)]
+        } else {
+            let (exact_lower, exact_upper) = tx_size_bucket_range(tx_size, 0);
+            let (nearby_lower, nearby_upper) =
+                tx_size_bucket_range(tx_size, TX_SIZE_NEARBY_BUCKETS);
+            vec![
+                (clamp(exact_lower), clamp(exact_upper)),
+                (clamp(nearby_lower), clamp(nearby_upper)),
+                (0, i32::MAX),
+            ]
+        };
+
+        for tx_size_range in tx_size_ranges {
+            let (min, max, avg, p50, p75, p90, p99, count) = self
+                .gas_repo
+                .find_gas_estimates(
+                    bond,
+                    redelegate,
+                    claim_rewards,
+                    unbond,
+                    transparent_transfer,
+                    shielded_transfer,
+                    shielding_transfer,
+                    unshielding_transfer,
+                    vote,
+                    ibc_shielding_transfer,
+                    ibc_unshielding_transfer,
+                    ibc_transparent_transfer,
+                    withdraw,
+                    reveal_pk,
+                    (signature_lower_bound, signature_upper_bound),
+                    tx_size_range,
+                    block_window,
+                )
+                .await
+                .map_err(GasError::Database)?;
+
+            if let (
+                Some(min),
+                Some(max),
+                Some(avg),
+                Some(p50),
+                Some(p75),
+                Some(p90),
+                Some(p99),
+            ) = (min, max, avg, p50, p75, p90, p99)
+            {
+                return Ok(GasEstimate {
+                    min: min as u64,
+                    max: max as u64,
+                    avg: avg.to_f64().unwrap() as u64,
+                    p50: p50.ceil() as u64,
+                    p75: p75.ceil() as u64,
+                    p90: p90.ceil() as u64,
+                    p99: p99.ceil() as u64,
+                    total_estimates: count as u64,
+                });
+            }
         }
+
+        let mut estimate = 0;
+        estimate += bond
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::Bond);
+        estimate += claim_rewards
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::ClaimRewards);
+        estimate += unbond
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::Unbond);
+        estimate += transparent_transfer
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::TransparentTransfer);
+        estimate += shielded_transfer
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::ShieldedTransfer);
+        estimate += shielding_transfer
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::ShieldingTransfer);
+        estimate += unshielding_transfer
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::UnshieldingTransfer);
+        estimate += vote
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::VoteProposal);
+        estimate += ibc_shielding_transfer
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::IbcShieldingTransfer);
+        estimate += ibc_unshielding_transfer
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::IbcUnshieldingTransfer);
+        estimate += withdraw
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::Withdraw);
+        estimate += reveal_pk
+            * self
+                .default_gas_table
+                .get_gas_by_tx_kind(TransactionKind::RevealPk);
+
+        Ok(GasEstimate {
+            min: estimate,
+            max: estimate,
+            avg: estimate,
+            p50: estimate,
+            p75: estimate,
+            p90: estimate,
+            p99: estimate,
+            total_estimates: 0,
+        })
     }
 }
 
@@ -1,13 +1,27 @@
+use std::collections::HashMap;
+
+use bigdecimal::{BigDecimal, Zero};
 use orm::governance_proposal::{
     GovernanceProposalKindDb, GovernanceProposalResultDb,
 };
+use orm::helpers::OrderByDb;
+use shared::vote::{
+    GovernanceParticipation as GovernanceParticipationStats,
+    cumulative_votes_timeline,
+};
 
 use crate::appstate::AppState;
-use crate::dto::governance::{ProposalKind, ProposalStatus};
+use crate::dto::governance::{
+    ProposalKind, ProposalStatus, ProposalVoteSortFieldDto, TimelineIntervalDto,
+};
+use crate::dto::pos::OrderByDto;
 use crate::error::governance::GovernanceError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 use crate::repository::governance::{GovernanceRepo, GovernanceRepoTrait};
-use crate::response::governance::{Proposal, ProposalVote};
+use crate::response::governance::{
+    GovernanceParticipation, Proposal, ProposalCode, ProposalCodeVerification,
+    ProposalVote, VoteTimelinePoint,
+};
 
 #[derive(Clone)]
 pub struct GovernanceService {
@@ -81,6 +95,70 @@ impl GovernanceService {
         Ok(db_proposal.map(|proposal| proposal.data))
     }
 
+    pub async fn find_proposal_code(
+        &self,
+        proposal_id: u64,
+    ) -> Result<ProposalCode, GovernanceError> {
+        let db_proposal = self
+            .governance_repo
+            .find_governance_proposals_by_id(proposal_id as i32)
+            .await
+            .map_err(GovernanceError::Database)?
+            .ok_or(GovernanceError::NotFound(proposal_id))?;
+
+        match (
+            db_proposal.data,
+            db_proposal.code_hash,
+            db_proposal.code_size,
+        ) {
+            (Some(code), Some(hash), Some(size)) => Ok(ProposalCode {
+                proposal_id: proposal_id.to_string(),
+                code,
+                hash,
+                size: size.to_string(),
+            }),
+            _ => Err(GovernanceError::CodeNotFound(proposal_id)),
+        }
+    }
+
+    /// Raw wasm code of a proposal along with its hash
+    pub async fn find_proposal_code_bytes(
+        &self,
+        proposal_id: u64,
+    ) -> Result<(Vec<u8>, String), GovernanceError> {
+        let code = self.find_proposal_code(proposal_id).await?;
+        let bytes = subtle_encoding::hex::decode(code.code)
+            .map_err(|e| GovernanceError::Unknown(e.to_string()))?;
+
+        Ok((bytes, code.hash))
+    }
+
+    pub async fn verify_proposal_code(
+        &self,
+        proposal_id: u64,
+        submitted_hash: String,
+    ) -> Result<ProposalCodeVerification, GovernanceError> {
+        let submitted_hash = submitted_hash
+            .trim()
+            .trim_start_matches("0x")
+            .to_lowercase();
+
+        if submitted_hash.len() != 64
+            || !submitted_hash.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            return Err(GovernanceError::InvalidCodeHash(submitted_hash));
+        }
+
+        let code = self.find_proposal_code(proposal_id).await?;
+
+        Ok(ProposalCodeVerification {
+            proposal_id: code.proposal_id,
+            matches: code.hash == submitted_hash,
+            hash: code.hash,
+            submitted_hash,
+        })
+    }
+
     pub async fn find_all_governance_proposals(
         &self,
         status: Option<ProposalStatus>,
@@ -157,6 +235,8 @@ impl GovernanceService {
         &self,
         proposal_id: u64,
         page: u64,
+        sort_field: Option<ProposalVoteSortFieldDto>,
+        sort_order: Option<OrderByDto>,
     ) -> Result<(Vec<ProposalVote>, u64, u64), GovernanceError> {
         let db_proposal = self
             .governance_repo
@@ -168,9 +248,22 @@ impl GovernanceService {
             return Err(GovernanceError::NotFound(proposal_id));
         }
 
+        let voting_power_order = sort_field.map(|field| match field {
+            ProposalVoteSortFieldDto::VotingPower => {
+                match sort_order.unwrap_or(OrderByDto::Desc) {
+                    OrderByDto::Asc => OrderByDb::Asc,
+                    OrderByDto::Desc => OrderByDb::Desc,
+                }
+            }
+        });
+
         let (db_proposal_votes, total_pages, total_items) = self
             .governance_repo
-            .find_governance_proposal_votes(proposal_id as i32, page as i64)
+            .find_governance_proposal_votes(
+                proposal_id as i32,
+                page as i64,
+                voting_power_order,
+            )
             .await
             .map_err(GovernanceError::Database)?;
 
@@ -230,6 +323,126 @@ impl GovernanceService {
             .collect())
     }
 
+    pub async fn find_governance_proposal_timeline(
+        &self,
+        proposal_id: u64,
+        interval: TimelineIntervalDto,
+    ) -> Result<Vec<VoteTimelinePoint>, GovernanceError> {
+        let db_proposal = self
+            .governance_repo
+            .find_governance_proposals_by_id(proposal_id as i32)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        if db_proposal.is_none() {
+            return Err(GovernanceError::NotFound(proposal_id));
+        }
+
+        let db_vote_history = self
+            .governance_repo
+            .find_governance_vote_history(proposal_id as i32)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        // Every vote is weighted by the voter power computed for the proposal,
+        // votes whose power is not known yet count as zero
+        let votes = db_vote_history.into_iter().map(
+            |(vote, timestamp, epoch, voting_power)| {
+                let bucket = match interval {
+                    TimelineIntervalDto::Epoch => {
+                        epoch.map(i64::from).unwrap_or_default()
+                    }
+                    TimelineIntervalDto::Hour => timestamp
+                        .map(|timestamp| {
+                            let timestamp = timestamp.and_utc().timestamp();
+                            timestamp - timestamp.rem_euclid(3600)
+                        })
+                        .unwrap_or_default(),
+                };
+
+                (
+                    bucket,
+                    vote.voter_address,
+                    vote.kind.into(),
+                    voting_power.unwrap_or_else(BigDecimal::zero),
+                )
+            },
+        );
+
+        Ok(cumulative_votes_timeline(votes)
+            .into_iter()
+            .map(|(bucket, tally)| VoteTimelinePoint::new(bucket, tally))
+            .collect())
+    }
+
+    /// Returns the participation of each address, in the same order. Every
+    /// proposal whose voting period has started and had not ended when the
+    /// address first appeared on chain counts as eligible.
+    pub async fn find_governance_participations(
+        &self,
+        addresses: Vec<String>,
+    ) -> Result<Vec<GovernanceParticipation>, GovernanceError> {
+        if addresses.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (proposals_end_epochs, first_seen_epochs, db_votes, last_votes) =
+            futures::try_join!(
+                self.governance_repo
+                    .find_votable_governance_proposals_end_epochs(),
+                self.governance_repo
+                    .find_first_seen_epoch_by_addresses(addresses.clone()),
+                self.governance_repo
+                    .find_governance_votes_with_result_by_voters(
+                        addresses.clone()
+                    ),
+                self.governance_repo
+                    .find_last_vote_timestamp_by_voters(addresses.clone()),
+            )
+            .map_err(GovernanceError::Database)?;
+
+        let mut first_seen_epochs: HashMap<_, _> =
+            first_seen_epochs.into_iter().collect();
+        let mut last_vote_timestamps: HashMap<_, _> =
+            last_votes.into_iter().collect();
+
+        let mut votes_by_voter: HashMap<String, Vec<_>> = HashMap::new();
+        for (voter_address, kind, result) in db_votes {
+            votes_by_voter
+                .entry(voter_address)
+                .or_default()
+                .push((kind.into(), result.into()));
+        }
+
+        Ok(addresses
+            .into_iter()
+            .map(|address| {
+                let first_seen_epoch =
+                    first_seen_epochs.remove(&address).flatten();
+                let eligible_proposals = proposals_end_epochs
+                    .iter()
+                    .filter(|end_epoch| {
+                        first_seen_epoch
+                            .is_none_or(|first_seen| **end_epoch >= first_seen)
+                    })
+                    .count();
+
+                let stats = GovernanceParticipationStats::compute(
+                    eligible_proposals as u64,
+                    votes_by_voter.remove(&address).unwrap_or_default(),
+                );
+                let last_vote_timestamp =
+                    last_vote_timestamps.remove(&address).flatten();
+
+                GovernanceParticipation::new(
+                    address,
+                    stats,
+                    last_vote_timestamp,
+                )
+            })
+            .collect())
+    }
+
     fn map_status(
         &self,
         status: Option<ProposalStatus>,
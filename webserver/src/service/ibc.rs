@@ -1,11 +1,18 @@
+use std::collections::HashMap;
+
 use bigdecimal::BigDecimal;
-use orm::ibc::IbcAckStatusDb;
+use orm::ibc::IbcPacketStatusDb;
+use orm::token::IbcTokenDb;
+use shared::ibc::IbcDenomTrace as SharedIbcDenomTrace;
 
 use crate::appstate::AppState;
+use crate::dto::ibc::IbcPacketStatusDto;
 use crate::error::ibc::IbcError;
 use crate::repository::ibc::{IbcRepository, IbcRepositoryTrait};
 use crate::response::ibc::{
-    IbcAck, IbcAckStatus, IbcRateLimit, IbcTokenFlow, IbcTokenThroughput,
+    IbcAck, IbcAckStatus, IbcChannel, IbcChannelVolume, IbcDenomTrace,
+    IbcPacket, IbcRateLimit, IbcRateLimitUtilization, IbcTokenFlow,
+    IbcTokenThroughput,
 };
 
 #[derive(Clone)]
@@ -30,12 +37,7 @@ impl IbcService {
             .map_err(IbcError::Database)
             .map(|ack| match ack {
                 Some(ack) => IbcAck {
-                    status: match ack.status {
-                        IbcAckStatusDb::Unknown => IbcAckStatus::Unknown,
-                        IbcAckStatusDb::Timeout => IbcAckStatus::Timeout,
-                        IbcAckStatusDb::Fail => IbcAckStatus::Fail,
-                        IbcAckStatusDb::Success => IbcAckStatus::Success,
-                    },
+                    status: IbcAckStatus::from(ack.status),
                     timeout: Some(ack.timeout),
                 },
                 None => IbcAck {
@@ -113,6 +115,211 @@ impl IbcService {
                 std::mem::transmute(throughput)
             })
     }
+
+    pub async fn find_rate_limit_history(
+        &self,
+        page: u64,
+        token: Option<String>,
+        alert_threshold: u64,
+    ) -> Result<(Vec<IbcRateLimitUtilization>, u64, u64), IbcError> {
+        let (history, total_pages, total_items) = self
+            .ibc_repo
+            .find_rate_limit_history(page as i64, token)
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            history
+                .into_iter()
+                .map(|(flows, rate_limit)| {
+                    IbcRateLimitUtilization::from(
+                        flows,
+                        rate_limit,
+                        alert_threshold,
+                    )
+                })
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn find_ibc_packets(
+        &self,
+        page: u64,
+        channel: Option<String>,
+        address: Option<String>,
+        status: Option<IbcPacketStatusDto>,
+    ) -> Result<(Vec<IbcPacket>, u64, u64), IbcError> {
+        let (packets, total_pages, total_items) = self
+            .ibc_repo
+            .find_ibc_packets(
+                page as i64,
+                channel,
+                address,
+                status.map(Self::to_ibc_packet_status_db),
+            )
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            packets
+                .into_iter()
+                .map(|(packet, ack_status)| IbcPacket::from(packet, ack_status))
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn find_ibc_packets_by_channel_and_sequence(
+        &self,
+        channel: String,
+        sequence: u64,
+    ) -> Result<Vec<IbcPacket>, IbcError> {
+        let packets = self
+            .ibc_repo
+            .find_ibc_packets_by_channel_and_sequence(
+                channel.clone(),
+                sequence as i64,
+            )
+            .await
+            .map_err(IbcError::Database)?;
+
+        if packets.is_empty() {
+            return Err(IbcError::PacketNotFound(channel, sequence));
+        }
+
+        Ok(packets
+            .into_iter()
+            .map(|(packet, ack_status)| IbcPacket::from(packet, ack_status))
+            .collect())
+    }
+
+    pub async fn get_ibc_channels(&self) -> Result<Vec<IbcChannel>, IbcError> {
+        let channels = self
+            .ibc_repo
+            .get_ibc_channels()
+            .await
+            .map_err(IbcError::Database)?;
+
+        let transfer_volume = self
+            .ibc_repo
+            .get_ibc_channels_transfer_volume()
+            .await
+            .map_err(IbcError::Database)?;
+
+        let mut volumes: HashMap<String, Vec<IbcChannelVolume>> =
+            HashMap::new();
+        let mut last_activity_heights: HashMap<String, i32> = HashMap::new();
+        for (channel, denom, transfers, amount, last_height) in transfer_volume
+        {
+            if let Some(last_height) = last_height {
+                let height = last_activity_heights
+                    .entry(channel.clone())
+                    .or_insert(last_height);
+                *height = (*height).max(last_height);
+            }
+            volumes.entry(channel).or_default().push(IbcChannelVolume {
+                denom: denom.unwrap_or_default(),
+                transfers: transfers.to_string(),
+                amount: amount.unwrap_or_default().to_string(),
+            });
+        }
+
+        let timestamps: HashMap<i32, Option<chrono::NaiveDateTime>> = self
+            .ibc_repo
+            .find_blocks_timestamp(
+                last_activity_heights.values().copied().collect(),
+            )
+            .await
+            .map_err(IbcError::Database)?
+            .into_iter()
+            .collect();
+
+        Ok(channels
+            .into_iter()
+            .map(|(channel, connection, client)| {
+                let volume =
+                    volumes.remove(&channel.channel_id).unwrap_or_default();
+                let last_activity = last_activity_heights
+                    .get(&channel.channel_id)
+                    .map(|height| {
+                        (*height, timestamps.get(height).copied().flatten())
+                    });
+
+                IbcChannel::from(
+                    channel,
+                    connection,
+                    client,
+                    volume,
+                    last_activity,
+                )
+            })
+            .collect())
+    }
+
+    pub async fn get_ibc_denom_by_address(
+        &self,
+        address: String,
+    ) -> Result<IbcDenomTrace, IbcError> {
+        let token = self
+            .ibc_repo
+            .find_ibc_token_by_address(address.clone())
+            .await
+            .map_err(IbcError::Database)?
+            .ok_or(IbcError::DenomNotFound(address))?;
+
+        self.resolve_ibc_denom(token).await
+    }
+
+    pub async fn get_ibc_denom_by_trace(
+        &self,
+        trace: String,
+    ) -> Result<IbcDenomTrace, IbcError> {
+        let token = self
+            .ibc_repo
+            .find_ibc_token_by_trace(trace.clone())
+            .await
+            .map_err(IbcError::Database)?
+            .ok_or(IbcError::DenomNotFound(trace))?;
+
+        self.resolve_ibc_denom(token).await
+    }
+
+    async fn resolve_ibc_denom(
+        &self,
+        token: IbcTokenDb,
+    ) -> Result<IbcDenomTrace, IbcError> {
+        let channel_id = SharedIbcDenomTrace::parse(&token.ibc_trace)
+            .hops
+            .into_iter()
+            .next()
+            .map(|hop| hop.channel_id);
+
+        let counterparty_chain_id = match channel_id {
+            Some(channel_id) => self
+                .ibc_repo
+                .find_ibc_channels_counterparty_chain_id(vec![channel_id])
+                .await
+                .map_err(IbcError::Database)?
+                .into_iter()
+                .next()
+                .and_then(|(_, chain_id)| chain_id),
+            None => None,
+        };
+
+        Ok(IbcDenomTrace::from(token, counterparty_chain_id))
+    }
+
+    fn to_ibc_packet_status_db(value: IbcPacketStatusDto) -> IbcPacketStatusDb {
+        match value {
+            IbcPacketStatusDto::Sent => IbcPacketStatusDb::Sent,
+            IbcPacketStatusDto::Received => IbcPacketStatusDb::Received,
+            IbcPacketStatusDto::Acknowledged => IbcPacketStatusDb::Acknowledged,
+            IbcPacketStatusDto::TimedOut => IbcPacketStatusDb::TimedOut,
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -1,7 +1,21 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+use orm::masp::MaspPoolDirectionDb;
+use shared::masp::MaspTvl as SharedMaspTvl;
+use shared::utils::MASP_ADDRESS;
+
 use crate::appstate::AppState;
+use crate::dto::utils::TimeBucketDto;
 use crate::error::masp::MaspError;
-use crate::repository::masp::{MaspRepository, MaspRepositoryTrait};
-use crate::response::masp::MaspPoolAggregateResponse;
+use crate::repository::masp::{
+    MaspPoolAggregateDb, MaspRepository, MaspRepositoryTrait,
+};
+use crate::response::masp::{
+    MaspFlow, MaspPoolAggregateKind, MaspPoolAggregateResponse,
+    MaspPoolAggregateWindow, MaspReward, MaspStats, MaspTvl,
+};
+use crate::service::utils::{to_bucket_db, to_time_range};
 
 #[derive(Clone)]
 pub struct MaspService {
@@ -24,20 +38,200 @@ impl MaspService {
                 .masp_repo
                 .find_all_aggregates_by_token(token)
                 .await
-                .map_err(MaspError::Database)?
-                .into_iter()
-                .map(MaspPoolAggregateResponse::from)
-                .collect(),
+                .map_err(MaspError::Database)?,
             None => self
                 .masp_repo
                 .find_all_aggregates()
                 .await
-                .map_err(MaspError::Database)?
-                .into_iter()
-                .map(MaspPoolAggregateResponse::from)
-                .collect(),
+                .map_err(MaspError::Database)?,
+        };
+
+        Ok(masp_aggregates
+            .into_iter()
+            .flat_map(Self::to_masp_aggregate_responses)
+            .collect())
+    }
+
+    pub async fn find_masp_flows(
+        &self,
+        token: Option<String>,
+        from: Option<i64>,
+        to: Option<i64>,
+        bucket: TimeBucketDto,
+    ) -> Result<Vec<MaspFlow>, MaspError> {
+        let (from, to) =
+            to_time_range(from, to).map_err(MaspError::InvalidRange)?;
+        let bucket = to_bucket_db(bucket);
+
+        let flows = self
+            .masp_repo
+            .find_masp_flows(token, from, to, bucket)
+            .await
+            .map_err(MaspError::Database)?;
+
+        Ok(flows
+            .into_iter()
+            .map(|(token_address, bucket, inflow, outflow)| MaspFlow {
+                token_address,
+                bucket: bucket.to_string(),
+                net: (&inflow - &outflow).to_string(),
+                inflow: inflow.to_string(),
+                outflow: outflow.to_string(),
+            })
+            .collect())
+    }
+
+    pub async fn find_masp_stats(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+        bucket: TimeBucketDto,
+    ) -> Result<Vec<MaspStats>, MaspError> {
+        let (from, to) =
+            to_time_range(from, to).map_err(MaspError::InvalidRange)?;
+        let bucket = to_bucket_db(bucket);
+
+        let transfer_stats = self
+            .masp_repo
+            .find_shielded_transfer_stats(from, to, bucket)
+            .await
+            .map_err(MaspError::Database)?;
+
+        let fee_payment_stats = self
+            .masp_repo
+            .find_masp_fee_payment_stats(from, to, bucket)
+            .await
+            .map_err(MaspError::Database)?;
+
+        // Buckets can have wrappers without any shielded transfer, so both
+        // series are merged by bucket
+        let mut stats: BTreeMap<i64, ([i64; 5], (i64, i64))> = BTreeMap::new();
+        for (bucket, shielded, shielding, unshielding, mixed, notes) in
+            transfer_stats
+        {
+            stats.entry(bucket).or_default().0 =
+                [shielded, shielding, unshielding, mixed, notes];
+        }
+        for (bucket, wrappers, masp_fee_payments) in fee_payment_stats {
+            stats.entry(bucket).or_default().1 = (wrappers, masp_fee_payments);
+        }
+
+        Ok(stats
+            .into_iter()
+            .map(
+                |(
+                    bucket,
+                    (
+                        [shielded, shielding, unshielding, mixed, notes],
+                        (wrappers, masp_fee_payments),
+                    ),
+                )| {
+                    let masp_fee_payment_share = if wrappers > 0 {
+                        (BigDecimal::from(masp_fee_payments)
+                            / BigDecimal::from(wrappers))
+                        .round(4)
+                    } else {
+                        BigDecimal::from(0)
+                    };
+
+                    MaspStats {
+                        bucket: bucket.to_string(),
+                        shielded_transfers: shielded.to_string(),
+                        shielding_transfers: shielding.to_string(),
+                        unshielding_transfers: unshielding.to_string(),
+                        mixed_transfers: mixed.to_string(),
+                        notes: notes.to_string(),
+                        wrappers: wrappers.to_string(),
+                        masp_fee_payments: masp_fee_payments.to_string(),
+                        masp_fee_payment_share: masp_fee_payment_share
+                            .to_string(),
+                    }
+                },
+            )
+            .collect())
+    }
+
+    pub async fn find_masp_tvl(
+        &self,
+        token: Option<String>,
+        from_epoch: Option<u64>,
+    ) -> Result<Vec<MaspTvl>, MaspError> {
+        let net_flows = self
+            .masp_repo
+            .find_masp_net_flows_per_epoch(token.clone())
+            .await
+            .map_err(MaspError::Database)?
+            .into_iter()
+            .filter_map(|(token, epoch, net_flow)| {
+                epoch.map(|epoch| (token, epoch as u32, net_flow))
+            })
+            .collect();
+
+        let balances = self
+            .masp_repo
+            .find_balance_changes_with_epoch(MASP_ADDRESS.to_string(), token)
+            .await
+            .map_err(MaspError::Database)?
+            .into_iter()
+            .filter_map(|(token, epoch, balance)| {
+                epoch.map(|epoch| (token, epoch as u32, balance))
+            })
+            .collect();
+
+        // The whole history is needed to cumulate the flows, so the epochs
+        // are only filtered afterwards
+        Ok(SharedMaspTvl::series(net_flows, balances)
+            .into_iter()
+            .filter(|tvl| {
+                from_epoch
+                    .is_none_or(|from_epoch| tvl.epoch as u64 >= from_epoch)
+            })
+            .map(MaspTvl::from)
+            .collect())
+    }
+
+    pub async fn find_masp_rewards(
+        &self,
+        page: u64,
+        token: Option<String>,
+    ) -> Result<(Vec<MaspReward>, u64, u64), MaspError> {
+        let (rewards, total_pages, total_items) = self
+            .masp_repo
+            .find_masp_rewards(page as i64, token)
+            .await
+            .map_err(MaspError::Database)?;
+
+        Ok((
+            rewards.into_iter().map(MaspReward::from).collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    fn to_masp_aggregate_responses(
+        aggregate: MaspPoolAggregateDb,
+    ) -> Vec<MaspPoolAggregateResponse> {
+        let (token_address, direction, one_day, seven_days, thirty_days, all) =
+            aggregate;
+
+        let kind = match direction {
+            MaspPoolDirectionDb::In => MaspPoolAggregateKind::Inflows,
+            MaspPoolDirectionDb::Out => MaspPoolAggregateKind::Outflows,
         };
 
-        Ok(masp_aggregates)
+        [
+            (MaspPoolAggregateWindow::OneDay, one_day),
+            (MaspPoolAggregateWindow::SevenDays, seven_days),
+            (MaspPoolAggregateWindow::ThirtyDays, thirty_days),
+            (MaspPoolAggregateWindow::AllTime, all),
+        ]
+        .into_iter()
+        .map(|(time_window, total_amount)| MaspPoolAggregateResponse {
+            token_address: token_address.clone(),
+            time_window,
+            kind: kind.clone(),
+            total_amount: total_amount.to_string(),
+        })
+        .collect()
     }
 }
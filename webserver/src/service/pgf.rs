@@ -1,7 +1,10 @@
 use crate::appstate::AppState;
 use crate::error::pgf::PgfError;
 use crate::repository::pgf::{PgfRepo, PgfRepoTrait};
-use crate::response::pgf::{PaymentKind, PaymentRecurrence, PgfPayment};
+use crate::response::pgf::{
+    PaymentKind, PaymentRecurrence, PgfDisbursement, PgfPayment, PgfSteward,
+    PgfStewardDetails, PgfStewardEvent, PgfTreasuryEpoch,
+};
 
 #[derive(Clone)]
 pub struct PgfService {
@@ -62,4 +65,89 @@ impl PgfService {
 
         Ok(payment)
     }
+
+    pub async fn get_pgf_stewards(&self) -> Result<Vec<PgfSteward>, PgfError> {
+        let stewards = self
+            .pgf_repo
+            .get_pgf_stewards()
+            .await
+            .map_err(PgfError::Database)?;
+
+        Ok(stewards.into_iter().map(PgfSteward::from).collect())
+    }
+
+    pub async fn find_pgf_steward_by_address(
+        &self,
+        address: String,
+    ) -> Result<PgfStewardDetails, PgfError> {
+        let steward = self
+            .pgf_repo
+            .find_pgf_steward_by_address(address.clone())
+            .await
+            .map_err(PgfError::Database)?
+            .map(PgfSteward::from);
+
+        let history = self
+            .pgf_repo
+            .find_pgf_steward_events_by_address(address.clone())
+            .await
+            .map_err(PgfError::Database)?
+            .into_iter()
+            .map(|(event, timestamp)| PgfStewardEvent::from(event, timestamp))
+            .collect::<Vec<_>>();
+
+        if steward.is_none() && history.is_empty() {
+            return Err(PgfError::StewardNotFound(address));
+        }
+
+        Ok(PgfStewardDetails {
+            address,
+            is_active: steward.is_some(),
+            commission: steward.map(|steward| steward.commission),
+            history,
+        })
+    }
+
+    pub async fn find_pgf_disbursements_by_receipient(
+        &self,
+        address: String,
+        page: u64,
+    ) -> Result<(Vec<PgfDisbursement>, u64, u64), PgfError> {
+        let (disbursements, total_pages, total_items) = self
+            .pgf_repo
+            .find_pgf_disbursements_by_receipient(address, page as i64)
+            .await
+            .map_err(PgfError::Database)?;
+
+        Ok((
+            disbursements
+                .into_iter()
+                .map(PgfDisbursement::from)
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn get_pgf_treasury_summary(
+        &self,
+        page: u64,
+    ) -> Result<(Vec<PgfTreasuryEpoch>, u64, u64), PgfError> {
+        let (summary, total_pages, total_items) = self
+            .pgf_repo
+            .get_pgf_treasury_summary(page as i64)
+            .await
+            .map_err(PgfError::Database)?;
+
+        Ok((
+            summary
+                .into_iter()
+                .map(|(epoch, token, total_amount)| {
+                    PgfTreasuryEpoch::from(epoch, token, total_amount)
+                })
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
 }
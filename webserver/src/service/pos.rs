@@ -1,7 +1,10 @@
-use bigdecimal::{BigDecimal, Zero};
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, One, Zero};
 use orm::helpers::OrderByDb;
 use orm::validators::{ValidatorSortByDb, ValidatorStateDb};
 use shared::parameters::Parameters;
+use shared::rewards::realized_reward_rate;
 
 use crate::appstate::AppState;
 use crate::dto::pos::{OrderByDto, ValidatorSortFieldDto, ValidatorStateDto};
@@ -9,21 +12,29 @@ use crate::error::pos::PoSError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 use crate::repository::pos::{PosRepository, PosRepositoryTrait};
 use crate::response::pos::{
-    Bond, BondStatus, MergedBond, MergedBondRedelegation, Reward, Unbond,
-    ValidatorWithId, Withdraw,
+    Bond, BondStatus, DecentralizationMetrics, MergedBond,
+    MergedBondRedelegation, ProjectedReward, Reward, RewardsProjection, Unbond,
+    ValidatorEvent, ValidatorSetChange, ValidatorWithId, Withdraw,
 };
+use crate::service::governance::GovernanceService;
+
+/// Number of past epochs of indexed rewards used to compute the realized
+/// reward rate of a validator
+const REALIZED_REWARDS_WINDOW: i32 = 10;
 
 #[derive(Clone)]
 pub struct PosService {
     pos_repo: PosRepository,
     chain_repo: ChainRepository,
+    gov_service: GovernanceService,
 }
 
 impl PosService {
     pub fn new(app_state: AppState) -> Self {
         Self {
             pos_repo: PosRepository::new(app_state.clone()),
-            chain_repo: ChainRepository::new(app_state),
+            chain_repo: ChainRepository::new(app_state.clone()),
+            gov_service: GovernanceService::new(app_state),
         }
     }
 
@@ -100,6 +111,43 @@ impl PosService {
         Ok(validators)
     }
 
+    pub async fn get_validator_by_address(
+        &self,
+        address: String,
+    ) -> Result<ValidatorWithId, PoSError> {
+        let db_validator = self
+            .pos_repo
+            .find_validator_by_address(address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(address.clone()))?;
+        let validators_rank = self
+            .pos_repo
+            .get_validators_rank()
+            .await
+            .map_err(PoSError::Database)?;
+        // Participation needs the whole voting history of the validator, so
+        // it is only computed when a single validator is requested
+        let governance_participation = self
+            .gov_service
+            .find_governance_participations(vec![address])
+            .await
+            .map_err(|e| PoSError::Database(e.to_string()))?
+            .pop();
+
+        let rank = validators_rank
+            .iter()
+            .position(|v_id| v_id == &db_validator.id)
+            .map(|r| (r + 1) as i32);
+        let validator = ValidatorWithId::from(db_validator, rank);
+
+        Ok(match governance_participation {
+            Some(governance_participation) => validator
+                .with_governance_participation(governance_participation),
+            None => validator,
+        })
+    }
+
     pub async fn get_bonds_by_address(
         &self,
         address: String,
@@ -378,6 +426,25 @@ impl PosService {
         Ok(rewards)
     }
 
+    pub async fn get_validator_events_by_address(
+        &self,
+        address: String,
+        page: u64,
+    ) -> Result<(Vec<ValidatorEvent>, u64, u64), PoSError> {
+        let (db_events, total_pages, total_items) = self
+            .pos_repo
+            .find_validator_events_by_address(address, page as i64)
+            .await
+            .map_err(PoSError::Database)?;
+
+        let events = db_events
+            .into_iter()
+            .map(|(event, timestamp)| ValidatorEvent::from(event, timestamp))
+            .collect();
+
+        Ok((events, total_pages as u64, total_items as u64))
+    }
+
     // TODO: maybe return object(struct) instead
     pub async fn get_total_voting_power(&self) -> Result<u64, PoSError> {
         let total_voting_power_db = self
@@ -389,6 +456,144 @@ impl PosService {
         Ok(total_voting_power_db.unwrap_or_default() as u64)
     }
 
+    pub async fn get_decentralization_metrics(
+        &self,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<DecentralizationMetrics>, PoSError> {
+        let metrics = self
+            .pos_repo
+            .find_decentralization_metrics(from_epoch, to_epoch)
+            .await
+            .map_err(PoSError::Database)?;
+
+        Ok(metrics
+            .into_iter()
+            .map(DecentralizationMetrics::from)
+            .collect())
+    }
+
+    pub async fn get_validator_set_changes(
+        &self,
+        epoch: i32,
+    ) -> Result<Vec<ValidatorSetChange>, PoSError> {
+        let changes = self
+            .pos_repo
+            .find_validator_set_changes(epoch)
+            .await
+            .map_err(PoSError::Database)?;
+
+        Ok(changes.into_iter().map(ValidatorSetChange::from).collect())
+    }
+
+    pub async fn get_rewards_projection(
+        &self,
+        validator_address: String,
+        amount: String,
+        epochs: u64,
+    ) -> Result<RewardsProjection, PoSError> {
+        let amount = BigDecimal::from_str(&amount)
+            .ok()
+            .filter(|amount| amount > &BigDecimal::zero())
+            .ok_or(PoSError::InvalidAmount(amount))?;
+
+        let validator = self
+            .pos_repo
+            .find_validator_by_address(validator_address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(validator_address))?;
+
+        let chain_state = self
+            .chain_repo
+            .get_state()
+            .await
+            .map_err(PoSError::Database)?;
+        let current_epoch = chain_state.last_processed_epoch;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(PoSError::Database)
+            .map(Parameters::from)?;
+
+        let unclaimed_rewards = self
+            .pos_repo
+            .find_validator_rewards_since_epoch(
+                validator.id,
+                current_epoch - REALIZED_REWARDS_WINDOW,
+            )
+            .await
+            .map_err(PoSError::Database)?
+            .into_iter()
+            .map(|(owner, epoch, amount)| (owner, epoch as u32, amount))
+            .collect::<Vec<_>>();
+
+        let bonded_amount = self
+            .pos_repo
+            .get_validator_bonded_amount(validator.id)
+            .await
+            .map_err(PoSError::Database)?
+            .unwrap_or_default();
+
+        let apr = BigDecimal::from_str(&parameters.apr)
+            .map_err(|e| PoSError::Unknown(e.to_string()))?;
+        let commission = BigDecimal::from_str(&validator.commission)
+            .map_err(|e| PoSError::Unknown(e.to_string()))?;
+
+        let nominal_reward_rate = &apr
+            / BigDecimal::from(parameters.epochs_per_year)
+            * (BigDecimal::one() - &commission);
+        let realized_reward_rate =
+            realized_reward_rate(&unclaimed_rewards, &bonded_amount);
+
+        // Prefer what delegators of this validator actually earned, fall back
+        // to the chain APR when there is not enough indexed history
+        let reward_rate = realized_reward_rate
+            .as_ref()
+            .unwrap_or(&nominal_reward_rate);
+        let reward_per_epoch = (&amount * reward_rate).with_scale(0);
+
+        // A new bond only contributes to the stake after the pipeline
+        let first_reward_epoch =
+            current_epoch + parameters.pipeline_length as i32;
+        let withdraw_epoch =
+            current_epoch + parameters.withdrawable_epoch_offset() as i32;
+
+        let rewards = (current_epoch + 1..=current_epoch + epochs as i32)
+            .map(|epoch| ProjectedReward {
+                epoch: epoch.to_string(),
+                min_denom_amount: if epoch >= first_reward_epoch {
+                    reward_per_epoch.to_string()
+                } else {
+                    BigDecimal::zero().to_string()
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let rewarded_epochs =
+            (current_epoch + epochs as i32 - first_reward_epoch + 1)
+                .clamp(0, epochs as i32);
+        let total_reward =
+            &reward_per_epoch * BigDecimal::from(rewarded_epochs);
+
+        Ok(RewardsProjection {
+            validator: ValidatorWithId::from(validator, None),
+            min_denom_amount: amount.to_string(),
+            current_epoch: current_epoch.to_string(),
+            first_reward_epoch: first_reward_epoch.to_string(),
+            withdraw_epoch: withdraw_epoch.to_string(),
+            apr: parameters.apr,
+            commission: commission.to_string(),
+            nominal_reward_rate: nominal_reward_rate.to_string(),
+            realized_reward_rate: realized_reward_rate
+                .map(|rate| rate.to_string()),
+            total_min_denom_amount: total_reward.to_string(),
+            rewards,
+        })
+    }
+
     fn to_validator_state_db(value: ValidatorStateDto) -> ValidatorStateDb {
         match value {
             ValidatorStateDto::Consensus => ValidatorStateDb::Consensus,
@@ -1,7 +1,66 @@
 use namada_core::token::Amount;
+use orm::token::{IbcTokenDb, TokenMetadataDb};
+use shared::token::TokenMetadata;
+
+use crate::dto::utils::TimeBucketDto;
+use crate::repository::utils::TimeBucketDb;
 
 pub fn raw_amount_to_nam(raw_amount: String) -> String {
     Amount::from_str(raw_amount, 0)
         .expect("raw_amount is not a valid string")
         .to_string_native()
 }
+
+/// Metadata of a token from the registry, or derived from the IBC trace of the
+/// token when it is not listed
+pub fn resolve_token_metadata(
+    address: &str,
+    ibc_token: Option<IbcTokenDb>,
+    metadata: Option<TokenMetadataDb>,
+) -> TokenMetadata {
+    match (metadata, ibc_token) {
+        (Some(metadata), _) => TokenMetadata::from(metadata),
+        (None, Some(ibc_token)) => {
+            TokenMetadata::ibc_default(address, &ibc_token.ibc_trace)
+        }
+        (None, None) => TokenMetadata::native_default(address),
+    }
+}
+
+/// Time range between two unix timestamps, defaulting to the whole history
+/// when `from` is missing and to now when `to` is missing
+pub fn to_time_range(
+    from: Option<i64>,
+    to: Option<i64>,
+) -> Result<(chrono::NaiveDateTime, chrono::NaiveDateTime), String> {
+    let from = from
+        .map(|from| {
+            chrono::DateTime::from_timestamp(from, 0)
+                .map(|from| from.naive_utc())
+                .ok_or(from.to_string())
+        })
+        .transpose()?
+        .unwrap_or(chrono::DateTime::UNIX_EPOCH.naive_utc());
+    let to = to
+        .map(|to| {
+            chrono::DateTime::from_timestamp(to, 0)
+                .map(|to| to.naive_utc())
+                .ok_or(to.to_string())
+        })
+        .transpose()?
+        .unwrap_or(chrono::Utc::now().naive_utc());
+
+    if from > to {
+        return Err(format!("from {} is after to {}", from, to));
+    }
+
+    Ok((from, to))
+}
+
+pub fn to_bucket_db(bucket: TimeBucketDto) -> TimeBucketDb {
+    match bucket {
+        TimeBucketDto::Hour => TimeBucketDb::Hour,
+        TimeBucketDto::Day => TimeBucketDb::Day,
+        TimeBucketDto::Epoch => TimeBucketDb::Epoch,
+    }
+}